@@ -1,11 +1,12 @@
 use alloy_primitives::{Address, U256, FixedBytes};
 use stylus_sdk::{
-    block, evm, msg,
+    evm,
     prelude::*,
     storage::{StorageAddress, StorageMap, StorageString, StorageU256, StorageVec, StorageBool},
 };
 
 use crate::types::{
+    env,
     errors::{AfroCreateError, Result, require_authorized, require_valid_input},
     events::*,
 };
@@ -67,7 +68,7 @@ impl CulturalIdentity {
     pub fn initialize(&mut self, platform_contract: Address) -> Result<()> {
         require_valid_input(self.owner.get().is_zero(), "Already initialized")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         self.owner.set(caller);
         self.platform_contract.set(platform_contract);
         self.min_verifiers_required.set(U256::from(2));
@@ -86,7 +87,7 @@ impl CulturalIdentity {
         traditions: Vec<String>,
         expertise_areas: Vec<String>,
     ) -> Result<()> {
-        let user = msg::sender();
+        let user = env::sender();
         
         // Validate that user doesn't already have a profile
         require_valid_input(
@@ -126,8 +127,8 @@ impl CulturalIdentity {
     ) -> Result<()> {
         self.require_verifier()?;
         
-        let verifier = msg::sender();
-        let current_time = U256::from(block::timestamp());
+        let verifier = env::sender();
+        let current_time = U256::from(env::timestamp());
         let expiry_date = current_time + (expiry_months * U256::from(30 * 24 * 3600)); // Approximate months to seconds
         
         let credential = CulturalCredential {
@@ -163,9 +164,9 @@ impl CulturalIdentity {
             "User has not claimed expertise in this region"
         )?;
         
-        let verifier = msg::sender();
+        let verifier = env::sender();
         profile.verification_status = 1; // Verified
-        profile.verification_date = U256::from(block::timestamp());
+        profile.verification_date = U256::from(env::timestamp());
         profile.verified_by = verifier;
         
         self.cultural_profiles.insert(user, profile);
@@ -191,7 +192,7 @@ impl CulturalIdentity {
     }
 
     pub fn revoke_credential(&mut self, user: Address, credential_index: U256) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         let credentials = self.user_credentials.get_mut(user);
         
         require_valid_input(
@@ -290,11 +291,11 @@ impl CulturalIdentity {
 // Internal helper functions
 impl CulturalIdentity {
     fn require_owner(&self) -> Result<()> {
-        require_authorized(msg::sender() == self.owner.get(), "Only owner")
+        require_authorized(env::sender() == self.owner.get(), "Only owner")
     }
 
     fn require_verifier(&self) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             self.cultural_verifiers.get(caller) || caller == self.owner.get(),
             "Not authorized verifier"
@@ -302,7 +303,7 @@ impl CulturalIdentity {
     }
 
     fn require_regional_authority(&self, region: &str) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         if caller == self.owner.get() {
             return Ok(());
         }
@@ -333,7 +334,7 @@ impl CulturalIdentity {
     fn check_and_update_verification_status(&mut self, user: Address) -> Result<()> {
         let credentials = self.user_credentials.get(user);
         let mut active_credentials = 0;
-        let current_time = U256::from(block::timestamp());
+        let current_time = U256::from(env::timestamp());
         
         for i in 0..credentials.len() {
             if let Some(credential) = credentials.get(i) {
@@ -1,11 +1,12 @@
 use alloy_primitives::{Address, FixedBytes, U256};
 use stylus_sdk::{
-    block, call, contract, evm, msg,
+    contract, evm,
     prelude::*,
     storage::{StorageAddress, StorageMap, StorageString, StorageU256, StorageVec},
 };
 
 use crate::types::{
+    env,
     errors::{AfroCreateError, Result, require_authorized, require_valid_input},
     events::*,
     interfaces::{ENSRegistry, ENSResolver},
@@ -53,7 +54,7 @@ impl ENSIntegration {
     ) -> Result<()> {
         require_valid_input(self.owner.get().is_zero(), "Already initialized")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         self.owner.set(caller);
         self.ens_registry.set(ens_registry);
         self.default_resolver.set(default_resolver);
@@ -104,7 +105,7 @@ impl ENSIntegration {
             node,
             subdomain,
             owner: creator,
-            timestamp: U256::from(block::timestamp()),
+            timestamp: U256::from(env::timestamp()),
         });
 
         Ok(node)
@@ -143,7 +144,7 @@ impl ENSIntegration {
             node,
             subdomain: project_subdomain,
             owner: creator,
-            timestamp: U256::from(block::timestamp()),
+            timestamp: U256::from(env::timestamp()),
         });
 
         Ok(node)
@@ -164,7 +165,7 @@ impl ENSIntegration {
             node,
             key,
             value,
-            timestamp: U256::from(block::timestamp()),
+            timestamp: U256::from(env::timestamp()),
         });
 
         Ok(())
@@ -315,11 +316,11 @@ impl ENSIntegration {
 // Internal helper functions
 impl ENSIntegration {
     fn require_owner(&self) -> Result<()> {
-        require_authorized(msg::sender() == self.owner.get(), "Only owner")
+        require_authorized(env::sender() == self.owner.get(), "Only owner")
     }
 
     fn require_authorized(&self) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             caller == self.owner.get() 
             || self.authorized_updaters.get(caller)
@@ -329,7 +330,7 @@ impl ENSIntegration {
     }
 
     fn require_node_owner_or_authorized(&self, node: FixedBytes<32>) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         let node_owner = self.node_owners.get(node);
         require_authorized(
             caller == node_owner 
@@ -1,13 +1,15 @@
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{Address, FixedBytes, U256};
 use stylus_sdk::{
-    block, evm, msg,
+    evm,
     prelude::*,
     storage::{StorageAddress, StorageMap, StorageString, StorageU256, StorageVec},
 };
 
 use crate::types::{
+    env,
     errors::{AfroCreateError, Result, require_authorized, require_valid_input},
     events::*,
+    interfaces::{ERC165_INTERFACE_ID, ERC721_INTERFACE_ID, ERC721_METADATA_INTERFACE_ID},
 };
 
 #[derive(SolidityType, Clone, Debug)]
@@ -65,7 +67,7 @@ impl CulturalBadgeNFT {
     ) -> Result<()> {
         require_valid_input(self.owner.get().is_zero(), "Already initialized")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         self.owner.set(caller);
         self.name.set(name);
         self.symbol.set(symbol);
@@ -100,7 +102,7 @@ impl CulturalBadgeNFT {
         require_valid_input(self.is_valid_badge_type(&badge_type), "Invalid badge type")?;
         
         let badge_id = self.next_badge_id.get();
-        let issuer = msg::sender();
+        let issuer = env::sender();
         
         let badge = CulturalBadge {
             badge_id,
@@ -108,7 +110,7 @@ impl CulturalBadgeNFT {
             cultural_region,
             expertise_level,
             issued_by: issuer,
-            issued_timestamp: U256::from(block::timestamp()),
+            issued_timestamp: U256::from(env::timestamp()),
             metadata_uri,
             is_transferable,
         };
@@ -134,6 +136,13 @@ impl CulturalBadgeNFT {
     }
 
     // ERC721 standard functions
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        // Enumerable and royalty IDs are defined in interfaces but not implemented yet
+        interface_id == ERC165_INTERFACE_ID ||
+        interface_id == ERC721_INTERFACE_ID ||
+        interface_id == ERC721_METADATA_INTERFACE_ID
+    }
+
     pub fn balance_of(&self, owner: Address) -> Result<U256> {
         require_valid_input(!owner.is_zero(), "Zero address query")?;
         Ok(self.balances.get(owner))
@@ -146,7 +155,7 @@ impl CulturalBadgeNFT {
     }
 
     pub fn transfer_from(&mut self, from: Address, to: Address, token_id: U256) -> Result<()> {
-        require_valid_input(self.is_approved_or_owner(msg::sender(), token_id)?, "Not authorized")?;
+        require_valid_input(self.is_approved_or_owner(env::sender(), token_id)?, "Not authorized")?;
         
         let badge = self.badges.get(token_id);
         require_valid_input(badge.is_transferable, "Badge is not transferable")?;
@@ -256,11 +265,11 @@ impl CulturalBadgeNFT {
 // Internal helper functions
 impl CulturalBadgeNFT {
     fn require_owner(&self) -> Result<()> {
-        require_authorized(msg::sender() == self.owner.get(), "Only owner")
+        require_authorized(env::sender() == self.owner.get(), "Only owner")
     }
 
     fn require_badge_issuer(&self) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             self.badge_issuers.get(caller) || caller == self.owner.get(),
             "Not authorized badge issuer"
@@ -1,16 +1,30 @@
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{Address, FixedBytes, U256};
 use stylus_sdk::{
-    block, evm, msg,
+    block,
+    evm,
     prelude::*,
     storage::{StorageAddress, StorageBool, StorageMap, StorageString, StorageU256, StorageVec},
 };
 
 use crate::types::{
+    env,
     errors::{AfroCreateError, Result, require_authorized, require_valid_input},
     events::*,
-    interfaces::{IERC721, IERC721Metadata},
+    interfaces::{
+        IERC721, IERC721Metadata,
+        ERC165_INTERFACE_ID, ERC721_INTERFACE_ID, ERC721_METADATA_INTERFACE_ID,
+    },
+    math::{mul_bps, split_fee},
 };
 
+// Conventional dead address; burned fees are unrecoverable
+const BURN_ADDRESS: Address = Address::new([
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xde, 0xad,
+]);
+
+const MAX_TRANSFER_FEE_BPS: u64 = 1000; // at most 10% of a secondary sale
+
 #[derive(SolidityType, Clone, Debug)]
 pub struct TokenRevenue {
     pub token_id: U256,
@@ -58,6 +72,16 @@ pub struct RevenueShareNFT {
     // Project holders tracking
     project_holders: StorageMap<U256, StorageVec<U256>>, // projectId -> tokenIds[]
     project_holder_count: StorageMap<U256, U256>,
+    project_allocated_share: StorageMap<U256, U256>, // projectId -> revenue share bps held across all tokens (max 10000)
+    project_creators: StorageMap<U256, Address>, // projectId -> creator, registered by a minter
+    unallocated_revenue: StorageMap<U256, U256>, // projectId -> revenue received before any holder existed
+    
+    // Catalogs: one share earning from a weighted basket of a creator's projects
+    catalog_count: StorageU256,
+    catalog_creator: StorageMap<U256, Address>,
+    catalog_projects: StorageMap<U256, StorageVec<U256>>,
+    catalog_weights: StorageMap<U256, StorageMap<U256, U256>>, // catalogId -> (projectId -> weight bps)
+    token_catalog: StorageMap<U256, U256>, // tokenId -> catalogId (0 = single-project token)
     
     // Revenue distribution tracking
     project_revenue_stats: StorageMap<U256, RevenueStats>,
@@ -79,7 +103,14 @@ pub struct RevenueShareNFT {
     
     // Revenue settings
     min_claim_amount: StorageU256,
+    min_claim_by_token: StorageMap<Address, U256>, // token -> minimum claim in its own decimals (0 = use global)
+    project_revenue_token: StorageMap<U256, Address>, // projectId -> revenue denomination (zero = native ETH)
     claim_fee_bps: StorageU256, // Fee for claiming revenue (basis points)
+    burn_bps: StorageU256, // Share of each claim fee burned (basis points)
+    collected_fees: StorageU256, // Retained claim fees available for withdrawal
+    collected_token_fees: StorageMap<Address, U256>, // token -> retained claim fees on token-denominated revenue
+    total_burned_fees: StorageU256,
+    transfer_fee_bps: StorageU256, // Share of a secondary sale price routed to the project's revenue pool (0 = off)
     
     // Reentrancy guard
     locked: StorageBool,
@@ -97,7 +128,7 @@ impl RevenueShareNFT {
     ) -> Result<()> {
         require_valid_input(self.owner.get().is_zero(), "Already initialized")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         self.owner.set(caller);
         self.name.set(name);
         self.symbol.set(symbol);
@@ -129,6 +160,7 @@ impl RevenueShareNFT {
         require_valid_input(!to.is_zero(), "Cannot mint to zero address")?;
         require_valid_input(funding_amount > U256::from(0), "Funding amount must be positive")?;
         require_valid_input(revenue_share_bps <= U256::from(10000), "Invalid revenue share")?;
+        self.allocate_project_share(project_id, revenue_share_bps)?;
 
         let token_id = self.next_token_id.get();
         
@@ -170,12 +202,127 @@ impl RevenueShareNFT {
         Ok(token_id)
     }
 
+    pub fn create_catalog(
+        &mut self,
+        creator: Address,
+        project_ids: Vec<U256>,
+        weights_bps: Vec<U256>,
+    ) -> Result<U256> {
+        self.require_minter()?;
+        require_valid_input(!creator.is_zero(), "Invalid creator")?;
+        require_valid_input(!project_ids.is_empty(), "Catalog needs at least one project")?;
+        require_valid_input(
+            project_ids.len() == weights_bps.len(),
+            "Projects and weights length mismatch"
+        )?;
+        for project_id in &project_ids {
+            require_authorized(
+                self.project_creators.get(*project_id) == creator,
+                "Creator does not own project"
+            )?;
+        }
+        
+        let catalog_id = self.catalog_count.get() + U256::from(1);
+        let mut total_weight = U256::from(0);
+        for (project_id, weight) in project_ids.iter().zip(weights_bps.iter()) {
+            require_valid_input(*weight > U256::from(0), "Catalog weight must be positive")?;
+            require_valid_input(
+                self.catalog_weights.get(catalog_id).get(*project_id) == U256::from(0),
+                "Duplicate catalog project"
+            )?;
+            self.catalog_weights.get_mut(catalog_id).insert(*project_id, *weight);
+            self.catalog_projects.get_mut(catalog_id).push(*project_id);
+            total_weight += *weight;
+        }
+        require_valid_input(total_weight == U256::from(10000), "Catalog weights must total 10000")?;
+        
+        self.catalog_creator.insert(catalog_id, creator);
+        self.catalog_count.set(catalog_id);
+        
+        evm::log(CatalogCreated {
+            catalog_id,
+            creator,
+            project_count: U256::from(project_ids.len()),
+        });
+        
+        Ok(catalog_id)
+    }
+
+    pub fn mint_catalog_share(
+        &mut self,
+        to: Address,
+        catalog_id: U256,
+        funding_amount: U256,
+        revenue_share_bps: U256,
+        ens_data: String,
+    ) -> Result<U256> {
+        self.require_minter()?;
+        require_valid_input(!self.catalog_creator.get(catalog_id).is_zero(), "Catalog not found")?;
+        require_valid_input(!to.is_zero(), "Cannot mint to zero address")?;
+        require_valid_input(funding_amount > U256::from(0), "Funding amount must be positive")?;
+        require_valid_input(revenue_share_bps <= U256::from(10000), "Invalid revenue share")?;
+        
+        let token_id = self.next_token_id.get();
+        
+        self.owners.insert(token_id, to);
+        let balance = self.balances.get(to);
+        self.balances.insert(to, balance + U256::from(1));
+        
+        // The share is paid out of each basket project's revenue, so it counts against their caps by weight
+        let projects = self.catalog_projects.get(catalog_id);
+        let mut basket = Vec::new();
+        for i in 0..projects.len() {
+            if let Some(project_id) = projects.get(i) {
+                basket.push(project_id);
+            }
+        }
+        for project_id in &basket {
+            let weight = self.catalog_weights.get(catalog_id).get(*project_id);
+            self.allocate_project_share(*project_id, mul_bps(revenue_share_bps, weight))?;
+        }
+        
+        self.token_catalog.insert(token_id, catalog_id);
+        self.token_funding_amount.insert(token_id, funding_amount);
+        self.token_revenue_share.insert(token_id, revenue_share_bps);
+        self.token_ens_metadata.insert(token_id, ens_data);
+        
+        // Catalog holders count toward every basket project so their revenue isn't parked as unallocated
+        for project_id in basket {
+            let holder_count = self.project_holder_count.get(project_id);
+            self.project_holder_count.insert(project_id, holder_count + U256::from(1));
+        }
+        
+        self.transfer_restrictions.insert(token_id, true);
+        self.next_token_id.set(token_id + U256::from(1));
+
+        evm::log(Transfer {
+            from: Address::ZERO,
+            to,
+            token_id,
+        });
+
+        evm::log(CatalogShareMinted {
+            token_id,
+            catalog_id,
+            recipient: to,
+            funding_amount,
+            revenue_share_bps,
+        });
+
+        Ok(token_id)
+    }
+
     pub fn calculate_claimable_revenue(&self, token_id: U256) -> Result<U256> {
         require_valid_input(self.owners.get(token_id) != Address::ZERO, "Token does not exist")?;
         
         let project_id = self.token_project.get(token_id);
         let revenue_share = self.token_revenue_share.get(token_id);
-        let total_project_revenue = self.project_total_revenue.get(project_id);
+        let catalog_id = self.token_catalog.get(token_id);
+        let total_project_revenue = if catalog_id == U256::from(0) {
+            self.project_total_revenue.get(project_id)
+        } else {
+            self.catalog_weighted_revenue(catalog_id)
+        };
         let already_claimed = self.token_claimed_revenue.get(token_id);
         
         // Calculate total entitled revenue
@@ -193,22 +340,42 @@ impl RevenueShareNFT {
         self.nonreentrant_guard()?;
         
         let holder = self.owners.get(token_id);
-        require_authorized(msg::sender() == holder, "Not token owner")?;
+        require_authorized(env::sender() == holder, "Not token owner")?;
         
         let claimable = self.calculate_claimable_revenue(token_id)?;
-        require_valid_input(claimable >= self.min_claim_amount.get(), "Below minimum claim amount")?;
+        let revenue_token = self.project_revenue_token.get(self.token_project.get(token_id));
+        require_valid_input(claimable >= self.min_claim_for(revenue_token), "Below minimum claim amount")?;
         
-        // Calculate claim fee
-        let fee = (claimable * self.claim_fee_bps.get()) / U256::from(10000);
-        let net_amount = claimable - fee;
+        // Calculate claim fee, burning the configured share of it
+        let (fee, net_amount) = split_fee(claimable, self.claim_fee_bps.get());
+        let (burned_fee, retained_fee) = split_fee(fee, self.burn_bps.get());
+        if revenue_token.is_zero() {
+            self.collected_fees.set(self.collected_fees.get() + retained_fee);
+        } else {
+            let collected = self.collected_token_fees.get(revenue_token);
+            self.collected_token_fees.insert(revenue_token, collected + retained_fee);
+        }
+        self.total_burned_fees.set(self.total_burned_fees.get() + burned_fee);
         
         // Update claimed amount
         let already_claimed = self.token_claimed_revenue.get(token_id);
         self.token_claimed_revenue.insert(token_id, already_claimed + claimable);
         
-        // Transfer revenue to holder
-        if net_amount > U256::from(0) {
-            stylus_sdk::call::transfer_eth(holder, net_amount)?;
+        // Transfer revenue to holder, in the project's revenue token when it has one
+        if revenue_token.is_zero() {
+            if net_amount > U256::from(0) {
+                env::transfer_eth(holder, net_amount)?;
+            }
+            if burned_fee > U256::from(0) {
+                env::transfer_eth(BURN_ADDRESS, burned_fee)?;
+            }
+        } else {
+            if net_amount > U256::from(0) {
+                self.push_token_payment(revenue_token, holder, net_amount)?;
+            }
+            if burned_fee > U256::from(0) {
+                self.push_token_payment(revenue_token, BURN_ADDRESS, burned_fee)?;
+            }
         }
         
         // Update claimable cache
@@ -224,46 +391,102 @@ impl RevenueShareNFT {
         Ok(net_amount)
     }
 
+    pub fn reinvest_revenue(&mut self, token_id: U256, target_project_id: U256) -> Result<U256> {
+        self.nonreentrant_guard()?;
+        
+        let holder = self.owners.get(token_id);
+        require_authorized(env::sender() == holder, "Not token owner")?;
+        
+        require_valid_input(target_project_id != U256::from(0), "Invalid target project")?;
+        let claimable = self.calculate_claimable_revenue(token_id)?;
+        require_valid_input(claimable > U256::from(0), "No claimable revenue")?;
+        // Contributions are made in native ETH, so token revenue has to be claimed instead
+        require_valid_input(
+            self.project_revenue_token.get(self.token_project.get(token_id)).is_zero(),
+            "Only native revenue can be reinvested"
+        )?;
+        
+        // Reinvested revenue never leaves the platform, so no claim fee applies
+        let already_claimed = self.token_claimed_revenue.get(token_id);
+        self.token_claimed_revenue.insert(token_id, already_claimed + claimable);
+        self.token_claimable_revenue.insert(token_id, U256::from(0));
+        
+        // The funding contract rejects missing or inactive targets, which reverts the claim above
+        self.forward_reinvestment(target_project_id, holder, claimable)?;
+
+        evm::log(RevenueReinvested {
+            token_id,
+            holder,
+            target_project_id,
+            amount: claimable,
+        });
+
+        self.unlock_guard();
+        Ok(claimable)
+    }
+
+    #[payable]
     pub fn batch_distribute_revenue(&mut self, project_id: U256, total_amount: U256) -> Result<()> {
         self.require_revenue_distributor()?;
         require_valid_input(total_amount > U256::from(0), "Amount must be positive")?;
+        self.credit_project_revenue(project_id, total_amount)
+    }
+
+    pub fn release_unallocated_revenue(&mut self, project_id: U256) -> Result<U256> {
+        self.require_revenue_distributor()?;
         
-        // Update project total revenue
-        let current_revenue = self.project_total_revenue.get(project_id);
-        self.project_total_revenue.insert(project_id, current_revenue + total_amount);
-        
-        // Update revenue statistics
-        let mut stats = self.project_revenue_stats.get(project_id);
-        stats.total_revenue_generated += total_amount;
-        stats.last_distribution_timestamp = U256::from(block::timestamp());
-        self.project_revenue_stats.insert(project_id, stats);
+        let pending = self.unallocated_revenue.get(project_id);
+        require_valid_input(pending > U256::from(0), "No unallocated revenue")?;
+        require_valid_input(
+            self.project_holder_count.get(project_id) > U256::from(0),
+            "Project has no holders"
+        )?;
         
-        // Update claimable amounts for all token holders
-        let holders = self.project_holders.get(project_id);
-        for i in 0..holders.len() {
-            if let Some(token_id) = holders.get(i) {
-                let claimable = self.calculate_claimable_revenue(token_id)?;
-                self.token_claimable_revenue.insert(token_id, claimable);
-            }
-        }
+        self.unallocated_revenue.insert(project_id, U256::from(0));
+        self.batch_distribute_revenue(project_id, pending)?;
         
-        self.last_distribution_block.insert(project_id, U256::from(block::number()));
+        Ok(pending)
+    }
 
-        evm::log(RevenueDistributed {
+    pub fn burn_revenue_nft(&mut self, token_id: U256) -> Result<()> {
+        self.require_minter()?;
+        let holder = self.owners.get(token_id);
+        require_valid_input(!holder.is_zero(), "Token does not exist")?;
+        require_valid_input(self.token_catalog.get(token_id) == U256::from(0), "Catalog shares cannot be burned")?;
+        
+        // The share goes with the token, so nothing further accrues to it
+        let project_id = self.token_project.get(token_id);
+        let allocated = self.project_allocated_share.get(project_id);
+        self.project_allocated_share.insert(
             project_id,
-            total_amount,
-            creator_share: U256::from(0), // Would be calculated based on project settings
-            community_share: total_amount, // Simplified for this example
-            platform_fee: U256::from(0),
+            allocated.saturating_sub(self.token_revenue_share.get(token_id))
+        );
+        self.token_approvals.insert(token_id, Address::ZERO);
+        self.balances.insert(holder, self.balances.get(holder) - U256::from(1));
+        self.owners.insert(token_id, Address::ZERO);
+        self.token_revenue_share.insert(token_id, U256::from(0));
+        self.token_claimable_revenue.insert(token_id, U256::from(0));
+        self.remove_project_holder(project_id, token_id);
+        
+        evm::log(Transfer {
+            from: holder,
+            to: Address::ZERO,
+            token_id,
         });
-
+        
+        evm::log(RevenueNFTBurned {
+            token_id,
+            project_id,
+            holder,
+        });
+        
         Ok(())
     }
 
     pub fn remove_transfer_restriction(&mut self, token_id: U256) -> Result<()> {
         require_valid_input(self.owners.get(token_id) != Address::ZERO, "Token does not exist")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         let token_owner = self.owners.get(token_id);
         
         require_authorized(
@@ -278,6 +501,13 @@ impl RevenueShareNFT {
     }
 
     // ERC721 Implementation
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        // Enumerable and royalty IDs are defined in interfaces but not implemented yet
+        interface_id == ERC165_INTERFACE_ID ||
+        interface_id == ERC721_INTERFACE_ID ||
+        interface_id == ERC721_METADATA_INTERFACE_ID
+    }
+
     pub fn balance_of(&self, owner: Address) -> Result<U256> {
         require_valid_input(!owner.is_zero(), "Zero address query")?;
         Ok(self.balances.get(owner))
@@ -294,7 +524,7 @@ impl RevenueShareNFT {
         require_valid_input(!owner.is_zero(), "Token does not exist")?;
         require_valid_input(to != owner, "Approval to current owner")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             caller == owner || self.is_approved_for_all(owner, caller),
             "Not owner or approved operator"
@@ -311,13 +541,48 @@ impl RevenueShareNFT {
         Ok(())
     }
 
+    pub fn set_approval_for_all(&mut self, operator: Address, approved: bool) -> Result<()> {
+        let owner = env::sender();
+        require_valid_input(!operator.is_zero(), "Invalid operator")?;
+        require_valid_input(operator != owner, "Approval to caller")?;
+        
+        self.operator_approvals.get_mut(owner).insert(operator, approved);
+        
+        evm::log(ApprovalForAll {
+            owner,
+            operator,
+            approved,
+        });
+        
+        Ok(())
+    }
+
+    pub fn is_approved_for_all(&self, owner: Address, operator: Address) -> bool {
+        self.operator_approvals.get(owner).get(operator)
+    }
+
+    pub fn get_approved(&self, token_id: U256) -> Result<Address> {
+        require_valid_input(!self.owners.get(token_id).is_zero(), "Token does not exist")?;
+        Ok(self.token_approvals.get(token_id))
+    }
+
+    #[payable]
     pub fn transfer_from(&mut self, from: Address, to: Address, token_id: U256) -> Result<()> {
-        require_valid_input(self.is_approved_or_owner(msg::sender(), token_id)?, "Not authorized")?;
+        require_valid_input(self.is_approved_or_owner(env::sender(), token_id)?, "Not authorized")?;
         require_valid_input(!self.transfer_restrictions.get(token_id), "Transfer restricted")?;
         
-        self.transfer(from, to, token_id)
+        self.transfer(from, to, token_id)?;
+        
+        // Any attached value is the sale price; the fee share rewards the project's holders
+        let sale_price = env::value();
+        if sale_price > U256::from(0) {
+            self.settle_sale(from, token_id, sale_price)?;
+        }
+        
+        Ok(())
     }
 
+    #[payable]
     pub fn safe_transfer_from(&mut self, from: Address, to: Address, token_id: U256) -> Result<()> {
         self.transfer_from(from, to, token_id)
     }
@@ -343,6 +608,27 @@ impl RevenueShareNFT {
         })
     }
 
+    pub fn get_breakeven_revenue(&self, token_id: U256) -> Result<U256> {
+        require_valid_input(self.owners.get(token_id) != Address::ZERO, "Token does not exist")?;
+        
+        // Total project revenue at which the holder's entitlement repays the contribution
+        let share_bps = self.token_revenue_share.get(token_id);
+        if share_bps == U256::from(0) {
+            return Ok(U256::MAX);
+        }
+        Ok(self.token_funding_amount.get(token_id) * U256::from(10000) / share_bps)
+    }
+
+    pub fn get_transfer_fee_bps(&self) -> U256 {
+        self.transfer_fee_bps.get()
+    }
+
+    pub fn quote_transfer_fee(&self, token_id: U256, sale_price: U256) -> Result<(U256, U256)> {
+        require_valid_input(self.owners.get(token_id) != Address::ZERO, "Token does not exist")?;
+        let fee = self.transfer_fee_for(token_id, sale_price);
+        Ok((fee, sale_price - fee))
+    }
+
     pub fn get_project_holders(&self, project_id: U256) -> Vec<U256> {
         let holders = self.project_holders.get(project_id);
         let mut result = Vec::new();
@@ -354,10 +640,56 @@ impl RevenueShareNFT {
         result
     }
 
+    pub fn get_catalog(&self, catalog_id: U256) -> Result<(Address, Vec<(U256, U256)>)> {
+        let creator = self.catalog_creator.get(catalog_id);
+        require_valid_input(!creator.is_zero(), "Catalog not found")?;
+        
+        let projects = self.catalog_projects.get(catalog_id);
+        let mut basket = Vec::new();
+        for i in 0..projects.len() {
+            if let Some(project_id) = projects.get(i) {
+                basket.push((project_id, self.catalog_weights.get(catalog_id).get(project_id)));
+            }
+        }
+        Ok((creator, basket))
+    }
+
+    pub fn get_token_catalog(&self, token_id: U256) -> U256 {
+        self.token_catalog.get(token_id)
+    }
+
+    pub fn get_project_allocated_share(&self, project_id: U256) -> U256 {
+        self.project_allocated_share.get(project_id)
+    }
+
     pub fn get_project_revenue_stats(&self, project_id: U256) -> RevenueStats {
         self.project_revenue_stats.get(project_id)
     }
 
+    pub fn get_unallocated_revenue(&self, project_id: U256) -> U256 {
+        self.unallocated_revenue.get(project_id)
+    }
+
+    pub fn get_min_claim_amount(&self, token: Address) -> U256 {
+        self.min_claim_for(token)
+    }
+
+    pub fn collected_fees(&self) -> U256 {
+        self.collected_fees.get()
+    }
+
+    pub fn collected_token_fees(&self, token: Address) -> U256 {
+        self.collected_token_fees.get(token)
+    }
+
+    pub fn total_burned_fees(&self) -> U256 {
+        self.total_burned_fees.get()
+    }
+
+    pub fn burn_address(&self) -> Address {
+        BURN_ADDRESS
+    }
+
     pub fn token_uri(&self, token_id: U256) -> Result<String> {
         require_valid_input(self.owners.get(token_id) != Address::ZERO, "Token does not exist")?;
         
@@ -382,6 +714,13 @@ impl RevenueShareNFT {
         Ok(())
     }
 
+    pub fn set_project_creator(&mut self, project_id: U256, creator: Address) -> Result<()> {
+        self.require_minter()?;
+        require_valid_input(!creator.is_zero(), "Invalid creator")?;
+        self.project_creators.insert(project_id, creator);
+        Ok(())
+    }
+
     pub fn add_minter(&mut self, minter: Address) -> Result<()> {
         self.require_owner()?;
         self.minters.insert(minter, true);
@@ -399,16 +738,91 @@ impl RevenueShareNFT {
         self.min_claim_amount.set(amount);
         Ok(())
     }
+
+    pub fn set_min_claim_amount_for_token(&mut self, token: Address, amount: U256) -> Result<()> {
+        self.require_owner()?;
+        self.min_claim_by_token.insert(token, amount);
+        Ok(())
+    }
+
+    pub fn set_project_revenue_token(&mut self, project_id: U256, token: Address) -> Result<()> {
+        self.require_revenue_distributor()?;
+        self.project_revenue_token.insert(project_id, token);
+        Ok(())
+    }
+
+    pub fn set_burn_bps(&mut self, burn_bps: U256) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(burn_bps <= U256::from(10000), "Burn share too high")?;
+        self.burn_bps.set(burn_bps);
+        Ok(())
+    }
+
+    pub fn set_transfer_fee_bps(&mut self, fee_bps: U256) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(fee_bps <= U256::from(MAX_TRANSFER_FEE_BPS), "Transfer fee too high")?;
+        self.transfer_fee_bps.set(fee_bps);
+        Ok(())
+    }
+
+    pub fn withdraw_fees(&mut self, recipient: Address, amount: U256) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(!recipient.is_zero(), "Invalid recipient")?;
+        require_valid_input(amount <= self.collected_fees.get(), "Insufficient collected fees")?;
+        
+        self.nonreentrant_guard()?;
+        self.collected_fees.set(self.collected_fees.get() - amount);
+        env::transfer_eth(recipient, amount)?;
+        self.unlock_guard();
+        Ok(())
+    }
+
+    pub fn withdraw_token_fees(&mut self, token: Address, recipient: Address, amount: U256) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(!recipient.is_zero(), "Invalid recipient")?;
+        let collected = self.collected_token_fees.get(token);
+        require_valid_input(amount <= collected, "Insufficient collected fees")?;
+        
+        self.nonreentrant_guard()?;
+        self.collected_token_fees.insert(token, collected - amount);
+        self.push_token_payment(token, recipient, amount)?;
+        self.unlock_guard();
+        Ok(())
+    }
 }
 
 // Internal helper functions
 impl RevenueShareNFT {
+    fn allocate_project_share(&mut self, project_id: U256, share_bps: U256) -> Result<()> {
+        let allocated = self.project_allocated_share.get(project_id) + share_bps;
+        require_valid_input(allocated <= U256::from(10000), "Project revenue share exhausted")?;
+        self.project_allocated_share.insert(project_id, allocated);
+        Ok(())
+    }
+
+    fn push_token_payment(&mut self, token: Address, recipient: Address, amount: U256) -> Result<()> {
+        require_valid_input(!recipient.is_zero(), "Invalid recipient")?;
+        require_valid_input(!token.is_zero(), "Invalid token")?;
+        
+        let mut calldata = stylus_sdk::function_selector!("transfer", Address, U256).to_vec();
+        calldata.extend_from_slice(recipient.into_word().as_slice());
+        calldata.extend_from_slice(&amount.to_be_bytes::<32>());
+        let returned = env::call_contract(self, U256::from(0), token, &calldata)
+            .map_err(|_| AfroCreateError::TransferFailed("Token transfer failed".to_string()))?;
+        
+        // Tokens that return nothing from transfer are taken at their word; the rest must return true
+        if !returned.is_empty() && returned.iter().all(|byte| *byte == 0) {
+            return Err(AfroCreateError::TransferFailed("Token transfer failed".to_string()));
+        }
+        Ok(())
+    }
+
     fn require_owner(&self) -> Result<()> {
-        require_authorized(msg::sender() == self.owner.get(), "Only owner")
+        require_authorized(env::sender() == self.owner.get(), "Only owner")
     }
 
     fn require_minter(&self) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             self.minters.get(caller) || caller == self.owner.get(),
             "Not authorized minter"
@@ -416,7 +830,7 @@ impl RevenueShareNFT {
     }
 
     fn require_revenue_distributor(&self) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             caller == self.revenue_distributor.get() || 
             caller == self.owner.get(),
@@ -424,6 +838,143 @@ impl RevenueShareNFT {
         )
     }
 
+    fn forward_reinvestment(&mut self, target_project_id: U256, holder: Address, amount: U256) -> Result<()> {
+        let funding = self.funding_contract.get();
+        require_valid_input(!funding.is_zero(), "Funding contract not set")?;
+        
+        // Credits `holder` on `target_project_id` with `amount` attached as value
+        let mut calldata = stylus_sdk::function_selector!("reinvestContribution", U256, Address).to_vec();
+        calldata.extend_from_slice(&target_project_id.to_be_bytes::<32>());
+        calldata.extend_from_slice(holder.into_word().as_slice());
+        env::call_contract(self, amount, funding, &calldata)
+            .map_err(|_| AfroCreateError::TransferFailed("Reinvestment failed".to_string()))?;
+        Ok(())
+    }
+
+    fn catalog_weighted_revenue(&self, catalog_id: U256) -> U256 {
+        let projects = self.catalog_projects.get(catalog_id);
+        let weights = self.catalog_weights.get(catalog_id);
+        let mut weighted = U256::from(0);
+        
+        for i in 0..projects.len() {
+            if let Some(project_id) = projects.get(i) {
+                weighted += self.project_total_revenue.get(project_id) * weights.get(project_id) / U256::from(10000);
+            }
+        }
+        
+        weighted
+    }
+
+    fn transfer_fee_for(&self, token_id: U256, sale_price: U256) -> U256 {
+        // Catalog shares span several projects, so there is no single pool to credit
+        if self.token_catalog.get(token_id) != U256::from(0) {
+            return U256::from(0);
+        }
+        mul_bps(sale_price, self.transfer_fee_bps.get())
+    }
+
+    fn settle_sale(&mut self, seller: Address, token_id: U256, sale_price: U256) -> Result<()> {
+        self.nonreentrant_guard()?;
+        
+        let project_id = self.token_project.get(token_id);
+        let fee = self.transfer_fee_for(token_id, sale_price);
+        if fee > U256::from(0) {
+            self.credit_project_revenue(project_id, fee)?;
+            
+            evm::log(TransferFeeCharged {
+                token_id,
+                project_id,
+                sale_price,
+                fee,
+            });
+        }
+        
+        let proceeds = sale_price - fee;
+        if proceeds > U256::from(0) {
+            env::transfer_eth(seller, proceeds)?;
+        }
+        
+        self.unlock_guard();
+        Ok(())
+    }
+
+    fn credit_project_revenue(&mut self, project_id: U256, total_amount: U256) -> Result<()> {
+        // With no holders the share would be unclaimable, so park it until holders exist
+        if self.project_holder_count.get(project_id) == U256::from(0) {
+            let pending = self.unallocated_revenue.get(project_id);
+            self.unallocated_revenue.insert(project_id, pending + total_amount);
+            
+            evm::log(RevenueHeldUnallocated {
+                project_id,
+                amount: total_amount,
+                total_unallocated: pending + total_amount,
+            });
+            
+            return Ok(());
+        }
+        
+        // Revenue parked before the first holder arrived rides along with the next distribution
+        let parked = self.unallocated_revenue.get(project_id);
+        let total_amount = if parked > U256::from(0) {
+            self.unallocated_revenue.insert(project_id, U256::from(0));
+            total_amount + parked
+        } else {
+            total_amount
+        };
+        
+        // Update project total revenue
+        let current_revenue = self.project_total_revenue.get(project_id);
+        self.project_total_revenue.insert(project_id, current_revenue + total_amount);
+        
+        // Update revenue statistics
+        let mut stats = self.project_revenue_stats.get(project_id);
+        stats.total_revenue_generated += total_amount;
+        stats.last_distribution_timestamp = U256::from(env::timestamp());
+        self.project_revenue_stats.insert(project_id, stats);
+        
+        // Update claimable amounts for all token holders
+        let holders = self.project_holders.get(project_id);
+        for i in 0..holders.len() {
+            if let Some(token_id) = holders.get(i) {
+                let claimable = self.calculate_claimable_revenue(token_id)?;
+                self.token_claimable_revenue.insert(token_id, claimable);
+            }
+        }
+        
+        self.last_distribution_block.insert(project_id, U256::from(block::number()));
+
+        evm::log(RevenueDistributed {
+            project_id,
+            total_amount,
+            creator_share: U256::from(0), // Would be calculated based on project settings
+            community_share: total_amount, // Simplified for this example
+            platform_fee: U256::from(0),
+        });
+
+        Ok(())
+    }
+
+    fn remove_project_holder(&mut self, project_id: U256, token_id: U256) {
+        // Rebuild the list without the token
+        let holders = self.project_holders.get(project_id);
+        let mut remaining = Vec::new();
+        for i in 0..holders.len() {
+            if let Some(existing) = holders.get(i) {
+                if existing != token_id {
+                    remaining.push(existing);
+                }
+            }
+        }
+        
+        let mut holders = self.project_holders.get_mut(project_id);
+        holders.erase();
+        for existing in remaining {
+            holders.push(existing);
+        }
+        let holder_count = self.project_holder_count.get(project_id);
+        self.project_holder_count.insert(project_id, holder_count.saturating_sub(U256::from(1)));
+    }
+
     fn nonreentrant_guard(&mut self) -> Result<()> {
         require_valid_input(!self.locked.get(), "Reentrant call")?;
         self.locked.set(true);
@@ -434,8 +985,14 @@ impl RevenueShareNFT {
         self.locked.set(false);
     }
 
-    fn is_approved_for_all(&self, owner: Address, operator: Address) -> bool {
-        self.operator_approvals.get(owner).get(operator)
+    fn min_claim_for(&self, token: Address) -> U256 {
+        // Token minimums are in the token's own decimals; unset tokens fall back to the global minimum
+        let token_minimum = self.min_claim_by_token.get(token);
+        if token.is_zero() || token_minimum == U256::from(0) {
+            self.min_claim_amount.get()
+        } else {
+            token_minimum
+        }
     }
 
     fn is_approved_or_owner(&self, spender: Address, token_id: U256) -> Result<bool> {
@@ -443,14 +1000,10 @@ impl RevenueShareNFT {
         require_valid_input(!owner.is_zero(), "Token does not exist")?;
         
         Ok(spender == owner || 
-           self.get_approved(token_id) == spender || 
+           self.token_approvals.get(token_id) == spender || 
            self.is_approved_for_all(owner, spender))
     }
 
-    fn get_approved(&self, token_id: U256) -> Address {
-        self.token_approvals.get(token_id)
-    }
-
     fn transfer(&mut self, from: Address, to: Address, token_id: U256) -> Result<()> {
         require_valid_input(!to.is_zero(), "Transfer to zero address")?;
         require_valid_input(self.owners.get(token_id) == from, "Transfer from incorrect owner")?;
@@ -1,23 +1,60 @@
 use alloy_primitives::{Address, U256, FixedBytes};
+use alloy_sol_types::SolValue;
 use stylus_sdk::{
-    block, call, contract, evm, msg,
+    block,
+    contract, evm,
     prelude::*,
     storage::{StorageAddress, StorageBool, StorageMap, StorageString, StorageU256, StorageVec},
 };
 
 use crate::types::{
+    env,
     errors::{AfroCreateError, Result, require_authorized, require_valid_input},
     events::*,
     interfaces::{ENSRegistry, IProjectFunding, ICulturalValidator},
-    CreatorProfile, ProjectInfo, PLATFORM_FEE_BPS, AFROCREATE_ENS_NODE,
+    CreatorProfile, ProjectInfo, VotingRole, PLATFORM_FEE_BPS, AFROCREATE_ENS_NODE, VALIDATION_THRESHOLD,
+    MAX_CREATOR_REPUTATION,
 };
 
+#[derive(SolidityType, Clone, Debug)]
+pub struct FundingReconciliation {
+    pub project_id: U256,
+    pub platform_raised: U256,
+    pub ledger_raised: U256, // as last reported by ProjectFunding
+    pub ledger_escrow: U256,
+    pub ledger_reported: bool, // false until ProjectFunding first reports; a reported 0 is still a report
+    pub discrepancy: U256,
+    pub platform_ahead: bool,
+}
+
+const MAX_HEALTH_SCAN: u64 = 500; // most recent projects classified by get_platform_health
+const MAX_STATUS_BATCH: usize = 100;
+const UNKNOWN_PROJECT_STATUS: u8 = 255; // returned by get_project_statuses for ids that don't exist
+
+#[derive(SolidityType, Clone, Debug)]
+pub struct PlatformHealth {
+    pub total_creators: U256,
+    pub total_projects: U256,
+    pub active_projects: U256,
+    pub successful_projects: U256,
+    pub failed_projects: U256,
+    pub projects_scanned: U256, // status counts cover only the most recent projects
+    pub total_funding_raised: U256,
+    pub total_revenue_distributed: U256, // as last reported by RevenueDistributor
+    pub cultural_fund_balance: U256, // as last reported by PlatformGovernance
+    pub validator_count: U256, // as last reported by CulturalValidator
+}
+
 #[storage]
 #[entrypoint]
 pub struct AfroCreatePlatform {
     // Core registries
     creators: StorageMap<Address, CreatorProfile>,
+    creator_exists: StorageMap<Address, bool>,
     projects: StorageMap<U256, ProjectInfo>,
+    project_exists: StorageMap<U256, bool>,
+    project_created_block: StorageMap<U256, U256>, // project -> block number at creation
+    project_created_at: StorageMap<U256, U256>, // project -> timestamp at creation
     creator_count: StorageU256,
     project_count: StorageU256,
     
@@ -30,7 +67,17 @@ pub struct AfroCreatePlatform {
     // Platform settings
     platform_fee_bps: StorageU256,
     min_project_funding: StorageU256,
+    category_min_funding: StorageMap<String, U256>, // overrides min_project_funding when set
     max_project_duration: StorageU256,
+    max_active_projects: StorageU256, // per creator, 0 = unlimited
+    max_active_projects_verified: StorageU256, // per verified creator, 0 = unlimited
+    validation_reset_threshold_bps: StorageU256, // target changes above this share reset validation
+    auto_validation_min_reputation: StorageU256, // verified creators at or above this skip validation
+    auto_validation_max_funding: StorageU256, // ...for projects targeting at most this (0 = auto-approval off)
+    milestone_reputation_points: StorageU256, // creator reputation gained per verified milestone
+    high_value_threshold: StorageU256, // projects targeting more than this need a reputation bar (0 = off)
+    high_value_min_reputation: StorageU256,
+    high_value_verified_exempt: StorageBool, // verified creators skip the reputation bar
     
     // Contract addresses
     project_funding: StorageAddress,
@@ -40,7 +87,9 @@ pub struct AfroCreatePlatform {
     
     // Security and access control
     paused: StorageBool,
+    funding_paused: StorageBool, // blocks new projects only; everything else keeps running
     owner: StorageAddress,
+    pending_owner: StorageAddress, // Must call accept_ownership before control moves
     admins: StorageMap<Address, bool>,
     
     // Metrics
@@ -54,6 +103,37 @@ pub struct AfroCreatePlatform {
     // Cultural categories
     approved_categories: StorageVec<String>,
     category_projects: StorageMap<String, StorageVec<U256>>,
+    category_total_raised: StorageMap<String, U256>,
+    category_successful: StorageMap<String, U256>,
+    category_validated: StorageMap<String, U256>,
+    category_score_sum: StorageMap<String, U256>, // sum of current validation scores of validated projects
+    fallback_category: StorageString, // receives the projects of a removed category
+    
+    // Co-creator collaboration
+    co_creator_invites: StorageMap<U256, StorageMap<Address, U256>>, // project -> invitee -> pending share bps
+    co_creator_shares: StorageMap<U256, StorageMap<Address, U256>>, // project -> co-creator -> accepted share bps
+    project_co_creators: StorageMap<U256, StorageVec<Address>>,
+    total_co_creator_bps: StorageMap<U256, U256>, // project -> accepted + pending share bps
+    
+    // Failure handling
+    refunds_processed: StorageMap<U256, bool>,
+    
+    // Milestones already credited toward creator reputation
+    milestones_rewarded: StorageMap<U256, StorageMap<U256, bool>>, // project -> milestone -> credited
+    
+    // Auto-approved projects awaiting post-hoc review
+    auto_validated: StorageMap<U256, bool>,
+    auto_validated_projects: StorageVec<U256>,
+    
+    // Funding ledger snapshots reported by ProjectFunding, for reconciliation
+    ledger_raised: StorageMap<U256, U256>,
+    ledger_escrow: StorageMap<U256, U256>,
+    ledger_reported: StorageMap<U256, bool>,
+    
+    // Metrics mirrored from the other contracts for get_platform_health
+    reported_revenue_distributed: StorageU256,
+    reported_cultural_fund_balance: StorageU256,
+    reported_validator_count: StorageU256,
 }
 
 #[public]
@@ -66,12 +146,15 @@ impl AfroCreatePlatform {
     ) -> Result<()> {
         require_valid_input(!self.owner.get().is_zero(), "Already initialized")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         self.owner.set(caller);
         self.ens_registry.set(ens_registry);
         self.platform_fee_bps.set(U256::from(PLATFORM_FEE_BPS));
         self.min_project_funding.set(min_funding);
         self.max_project_duration.set(max_duration);
+        self.validation_reset_threshold_bps.set(U256::from(2000)); // 20%
+        self.milestone_reputation_points.set(U256::from(10));
+        self.fallback_category.set("Uncategorized".to_string());
         
         // Initialize approved cultural categories
         self.approved_categories.push("Music".to_string());
@@ -94,9 +177,9 @@ impl AfroCreatePlatform {
         self.require_not_paused()?;
         require_valid_input(self.validate_ens_name(&ens_subdomain)?, "Invalid ENS subdomain")?;
         
-        let creator = msg::sender();
+        let creator = env::sender();
         require_valid_input(
-            self.creators.get(creator).creator_address.is_zero(),
+            !self.creator_exists.get(creator),
             "Creator already registered"
         )?;
         
@@ -107,6 +190,7 @@ impl AfroCreatePlatform {
         )?;
 
         let creator_id = self.creator_count.get() + U256::from(1);
+        let registration_timestamp = U256::from(env::timestamp());
         
         let profile = CreatorProfile {
             creator_address: creator,
@@ -116,10 +200,11 @@ impl AfroCreatePlatform {
             projects_created: U256::from(0),
             total_funding_raised: U256::from(0),
             is_verified: false,
-            registration_timestamp: U256::from(block::timestamp()),
+            registration_timestamp,
         };
 
         self.creators.insert(creator, profile);
+        self.creator_exists.insert(creator, true);
         self.subdomain_registry.insert(ens_subdomain.clone(), creator);
         self.creator_ens_names.insert(creator, ens_subdomain.clone());
         self.creator_count.set(creator_id);
@@ -129,7 +214,7 @@ impl AfroCreatePlatform {
             creator,
             ens_name: ens_subdomain,
             cultural_background,
-            timestamp: U256::from(block::timestamp()),
+            timestamp: registration_timestamp,
         });
 
         Ok(creator_id)
@@ -145,18 +230,19 @@ impl AfroCreatePlatform {
         metadata_uri: String,
     ) -> Result<U256> {
         self.require_not_paused()?;
-        let creator = msg::sender();
+        self.require_funding_not_paused()?;
+        let creator = env::sender();
         
         // Verify creator is registered
         let mut creator_profile = self.creators.get(creator);
         require_valid_input(
-            !creator_profile.creator_address.is_zero(),
+            self.creator_exists.get(creator),
             "Creator not registered"
         )?;
         
         // Validate inputs
         require_valid_input(
-            funding_target >= self.min_project_funding.get(),
+            funding_target >= self.min_funding_for_category(&cultural_category),
             "Funding target too low"
         )?;
         require_valid_input(
@@ -167,9 +253,15 @@ impl AfroCreatePlatform {
             self.is_approved_category(&cultural_category),
             "Cultural category not approved"
         )?;
+        
+        self.require_active_project_slot(creator, creator_profile.is_verified)?;
+        require_valid_input(
+            self.meets_high_value_bar(&creator_profile, funding_target),
+            "Reputation too low for high-value project"
+        )?;
 
         let project_id = self.project_count.get() + U256::from(1);
-        let deadline = U256::from(block::timestamp()) + (duration_days * U256::from(86400));
+        let deadline = U256::from(env::timestamp()) + (duration_days * U256::from(86400));
 
         let project = ProjectInfo {
             project_id,
@@ -184,10 +276,14 @@ impl AfroCreatePlatform {
             validation_status: 0, // Pending
             validation_score: U256::from(0),
             metadata_uri,
+            regions: Vec::new(),
         };
 
         self.projects.insert(project_id, project);
+        self.project_exists.insert(project_id, true);
         self.project_count.set(project_id);
+        self.project_created_block.insert(project_id, U256::from(block::number()));
+        self.project_created_at.insert(project_id, U256::from(env::timestamp()));
         
         // Update creator profile
         creator_profile.projects_created += U256::from(1);
@@ -206,32 +302,143 @@ impl AfroCreatePlatform {
             cultural_category,
             funding_target,
             deadline,
+            block_number: U256::from(block::number()),
+            created_at: U256::from(env::timestamp()),
         });
+        
+        // Small projects from trusted creators skip full validation, flagged for later review
+        if self.is_auto_validation_eligible(&creator_profile, funding_target) {
+            self.auto_validated.insert(project_id, true);
+            self.auto_validated_projects.push(project_id);
+            self.apply_project_validation(project_id, U256::from(VALIDATION_THRESHOLD), true);
+            
+            evm::log(ProjectAutoValidated {
+                project_id,
+                creator,
+                funding_target,
+            });
+        }
 
         Ok(project_id)
     }
 
+    pub fn invite_co_creator(&mut self, project_id: U256, invitee: Address, share_bps: U256) -> Result<()> {
+        self.require_not_paused()?;
+        
+        let project = self.projects.get(project_id);
+        require_valid_input(self.project_exists.get(project_id), "Project not found")?;
+        require_authorized(env::sender() == project.creator, "Only project creator")?;
+        require_valid_input(!invitee.is_zero() && invitee != project.creator, "Invalid invitee")?;
+        require_valid_input(share_bps > U256::from(0), "Share must be positive")?;
+        require_valid_input(
+            self.co_creator_invites.get(project_id).get(invitee) == U256::from(0) &&
+            self.co_creator_shares.get(project_id).get(invitee) == U256::from(0),
+            "Invitee already invited or accepted"
+        )?;
+        
+        let committed_bps = self.total_co_creator_bps.get(project_id) + share_bps;
+        require_valid_input(committed_bps <= U256::from(10000), "Co-creator shares exceed 100%")?;
+        
+        self.co_creator_invites.get_mut(project_id).insert(invitee, share_bps);
+        self.total_co_creator_bps.insert(project_id, committed_bps);
+
+        evm::log(CoCreatorInvited {
+            project_id,
+            invitee,
+            share_bps,
+        });
+
+        Ok(())
+    }
+
+    pub fn accept_co_creator_invite(&mut self, project_id: U256) -> Result<()> {
+        self.require_not_paused()?;
+        
+        let invitee = env::sender();
+        let share_bps = self.co_creator_invites.get(project_id).get(invitee);
+        require_valid_input(share_bps > U256::from(0), "No pending invitation")?;
+        
+        self.co_creator_invites.get_mut(project_id).insert(invitee, U256::from(0));
+        self.co_creator_shares.get_mut(project_id).insert(invitee, share_bps);
+        self.project_co_creators.get_mut(project_id).push(invitee);
+
+        evm::log(CoCreatorInviteResolved {
+            project_id,
+            invitee,
+            share_bps,
+            accepted: true,
+        });
+
+        Ok(())
+    }
+
+    pub fn decline_co_creator_invite(&mut self, project_id: U256) -> Result<()> {
+        let invitee = env::sender();
+        let share_bps = self.co_creator_invites.get(project_id).get(invitee);
+        require_valid_input(share_bps > U256::from(0), "No pending invitation")?;
+        
+        // Release the reserved share back to the project
+        self.co_creator_invites.get_mut(project_id).insert(invitee, U256::from(0));
+        let committed_bps = self.total_co_creator_bps.get(project_id);
+        self.total_co_creator_bps.insert(project_id, committed_bps - share_bps);
+
+        evm::log(CoCreatorInviteResolved {
+            project_id,
+            invitee,
+            share_bps,
+            accepted: false,
+        });
+
+        Ok(())
+    }
+
     pub fn validate_ens_ownership(&self, subdomain: &str, claimer: Address) -> Result<bool> {
         // Simplified validation - in production, would call ENS registry
         Ok(!subdomain.is_empty() && subdomain.len() >= 3 && !claimer.is_zero())
     }
 
     pub fn get_creator_profile(&self, creator: Address) -> Result<CreatorProfile> {
-        let profile = self.creators.get(creator);
-        require_valid_input(
-            !profile.creator_address.is_zero(),
-            "Creator not found"
-        )?;
-        Ok(profile)
+        require_valid_input(self.creator_exists.get(creator), "Creator not found")?;
+        Ok(self.creators.get(creator))
     }
 
     pub fn get_project_info(&self, project_id: U256) -> Result<ProjectInfo> {
+        require_valid_input(self.project_exists.get(project_id), "Project not found")?;
+        Ok(self.projects.get(project_id))
+    }
+
+    pub fn get_project_creation_context(&self, project_id: U256) -> Result<(U256, U256)> {
+        require_valid_input(self.project_exists.get(project_id), "Project not found")?;
+        Ok((self.project_created_block.get(project_id), self.project_created_at.get(project_id)))
+    }
+
+    pub fn get_effective_status(&self, project_id: U256) -> Result<u8> {
+        require_valid_input(self.project_exists.get(project_id), "Project not found")?;
         let project = self.projects.get(project_id);
-        require_valid_input(
-            project.project_id != U256::from(0),
-            "Project not found"
-        )?;
-        Ok(project)
+        
+        // Stored status stays Active past the deadline until someone finalizes it
+        if project.status == 0 && U256::from(env::timestamp()) > project.deadline {
+            if project.funding_raised >= project.funding_target {
+                return Ok(1); // Successful
+            }
+            return Ok(2); // Failed
+        }
+        
+        Ok(project.status)
+    }
+
+    pub fn get_project_statuses(&self, project_ids: Vec<U256>) -> Result<Vec<u8>> {
+        require_valid_input(project_ids.len() <= MAX_STATUS_BATCH, "Too many projects")?;
+        
+        let mut statuses = Vec::with_capacity(project_ids.len());
+        for project_id in project_ids {
+            if self.project_exists.get(project_id) {
+                statuses.push(self.get_effective_status(project_id)?);
+            } else {
+                statuses.push(UNKNOWN_PROJECT_STATUS);
+            }
+        }
+        Ok(statuses)
     }
 
     pub fn get_creator_projects(&self, creator: Address) -> Result<Vec<U256>> {
@@ -245,6 +452,20 @@ impl AfroCreatePlatform {
         Ok(result)
     }
 
+    pub fn get_projects_by_ens(&self, ens_name: String) -> Result<Vec<U256>> {
+        // Accept either the bare subdomain or the full name
+        let subdomain = ens_name.strip_suffix(".afrocreate.eth").unwrap_or(&ens_name).to_string();
+        
+        let creator = self.subdomain_registry.get(subdomain.clone());
+        require_valid_input(!creator.is_zero(), "ENS name not registered")?;
+        require_valid_input(
+            self.creator_ens_names.get(creator) == subdomain,
+            "ENS name not registered"
+        )?;
+        
+        self.get_creator_projects(creator)
+    }
+
     pub fn get_category_projects(&self, category: String) -> Result<Vec<U256>> {
         let projects = self.category_projects.get(category);
         let mut result = Vec::new();
@@ -256,26 +477,90 @@ impl AfroCreatePlatform {
         Ok(result)
     }
 
+    pub fn get_co_creator_share(&self, project_id: U256, co_creator: Address) -> U256 {
+        self.co_creator_shares.get(project_id).get(co_creator)
+    }
+
+    pub fn get_pending_co_creator_invite(&self, project_id: U256, invitee: Address) -> U256 {
+        self.co_creator_invites.get(project_id).get(invitee)
+    }
+
+    pub fn get_project_co_creators(&self, project_id: U256) -> Vec<Address> {
+        let co_creators = self.project_co_creators.get(project_id);
+        let mut result = Vec::new();
+        for i in 0..co_creators.len() {
+            if let Some(co_creator) = co_creators.get(i) {
+                result.push(co_creator);
+            }
+        }
+        result
+    }
+
+    pub fn record_milestone_completed(&mut self, project_id: U256, milestone_id: U256) -> Result<U256> {
+        self.require_contract_or_authorized(self.project_funding.get())?;
+        require_valid_input(self.project_exists.get(project_id), "Project not found")?;
+        require_valid_input(
+            !self.milestones_rewarded.get(project_id).get(milestone_id),
+            "Milestone already rewarded"
+        )?;
+        self.milestones_rewarded.get_mut(project_id).insert(milestone_id, true);
+        
+        // Delivery earns reputation, up to the global cap
+        let creator = self.projects.get(project_id).creator;
+        let mut profile = self.creators.get(creator);
+        let old_score = profile.reputation_score;
+        let new_score = core::cmp::min(
+            old_score + self.milestone_reputation_points.get(),
+            U256::from(MAX_CREATOR_REPUTATION),
+        );
+        profile.reputation_score = new_score;
+        self.creators.insert(creator, profile);
+
+        evm::log(ReputationChanged {
+            creator,
+            old_score,
+            new_score,
+            reason: "milestone".to_string(),
+        });
+
+        Ok(new_score)
+    }
+
     pub fn update_project_funding(&mut self, project_id: U256, amount_raised: U256) -> Result<()> {
-        self.require_authorized()?;
+        self.require_contract_or_authorized(self.project_funding.get())?;
         
         let mut project = self.projects.get(project_id);
         require_valid_input(
-            project.project_id != U256::from(0),
+            self.project_exists.get(project_id),
             "Project not found"
         )?;
 
+        // Track the category total by the change in raised amount
+        let category = project.cultural_category.clone();
+        let category_raised = self.category_total_raised.get(category.clone());
+        self.category_total_raised.insert(
+            category.clone(),
+            (category_raised + amount_raised).saturating_sub(project.funding_raised),
+        );
+        
         project.funding_raised = amount_raised;
         
         // Check if funding target is reached
         if amount_raised >= project.funding_target {
+            if project.status != 1 {
+                let category_successful = self.category_successful.get(category.clone());
+                self.category_successful.insert(category, category_successful + U256::from(1));
+            }
             project.status = 1; // Successful
             self.successful_projects.set(self.successful_projects.get() + U256::from(1));
             
             // Update creator's total funding raised
             let mut creator_profile = self.creators.get(project.creator);
             creator_profile.total_funding_raised += amount_raised;
+            let creator_power = creator_profile.total_funding_raised;
             self.creators.insert(project.creator, creator_profile);
+            // Creators vote with what their successful projects have raised
+            self.sync_creator_voting_power(project.creator, creator_power)?;
         }
         
         self.projects.insert(project_id, project);
@@ -284,30 +569,261 @@ impl AfroCreatePlatform {
         Ok(())
     }
 
-    pub fn set_project_validation(&mut self, project_id: U256, score: U256, approved: bool) -> Result<()> {
+    pub fn report_funding_ledger(&mut self, project_id: U256, raised: U256, escrow: U256) -> Result<()> {
+        self.require_contract_or_authorized(self.project_funding.get())?;
+        require_valid_input(
+            self.project_exists.get(project_id),
+            "Project not found"
+        )?;
+        
+        self.ledger_raised.insert(project_id, raised);
+        self.ledger_escrow.insert(project_id, escrow);
+        self.ledger_reported.insert(project_id, true);
+        Ok(())
+    }
+
+    pub fn report_revenue_distributed(&mut self, total_distributed: U256) -> Result<()> {
+        self.require_contract_or_authorized(self.revenue_distributor.get())?;
+        self.reported_revenue_distributed.set(total_distributed);
+        Ok(())
+    }
+
+    pub fn report_cultural_fund_balance(&mut self, balance: U256) -> Result<()> {
+        self.require_contract_or_authorized(self.governance.get())?;
+        self.reported_cultural_fund_balance.set(balance);
+        Ok(())
+    }
+
+    pub fn report_validator_count(&mut self, count: U256) -> Result<()> {
+        self.require_contract_or_authorized(self.cultural_validator.get())?;
+        self.reported_validator_count.set(count);
+        Ok(())
+    }
+
+    pub fn reconcile_project_funding(&self, project_id: U256) -> Result<FundingReconciliation> {
+        let project = self.projects.get(project_id);
+        require_valid_input(self.project_exists.get(project_id), "Project not found")?;
+        
+        // Without a report there is nothing to compare against, not a ledger of zero
+        let ledger_reported = self.ledger_reported.get(project_id);
+        let ledger_raised = self.ledger_raised.get(project_id);
+        let platform_ahead = ledger_reported && project.funding_raised > ledger_raised;
+        let discrepancy = if !ledger_reported {
+            U256::from(0)
+        } else if platform_ahead {
+            project.funding_raised - ledger_raised
+        } else {
+            ledger_raised - project.funding_raised
+        };
+        
+        Ok(FundingReconciliation {
+            project_id,
+            platform_raised: project.funding_raised,
+            ledger_raised,
+            ledger_escrow: self.ledger_escrow.get(project_id),
+            ledger_reported,
+            discrepancy,
+            platform_ahead,
+        })
+    }
+
+    pub fn resolve_funding_discrepancy(&mut self, project_id: U256) -> Result<U256> {
+        self.require_authorized()?;
+        
+        let reconciliation = self.reconcile_project_funding(project_id)?;
+        require_valid_input(reconciliation.ledger_reported, "Funding ledger not reported")?;
+        require_valid_input(reconciliation.discrepancy > U256::from(0), "Funding already in sync")?;
+        
+        // ProjectFunding holds the funds, so its ledger is the source of truth
+        let mut project = self.projects.get(project_id);
+        project.funding_raised = reconciliation.ledger_raised;
+        self.projects.insert(project_id, project);
+        
+        let total_raised = self.total_funding_raised.get();
+        if reconciliation.platform_ahead {
+            self.total_funding_raised.set(total_raised.saturating_sub(reconciliation.discrepancy));
+        } else {
+            self.total_funding_raised.set(total_raised + reconciliation.discrepancy);
+        }
+        
+        Ok(reconciliation.discrepancy)
+    }
+
+    pub fn mark_project_failed(&mut self, project_id: U256) -> Result<()> {
         self.require_authorized()?;
         
         let mut project = self.projects.get(project_id);
+        require_valid_input(self.project_exists.get(project_id), "Project not found")?;
+        require_valid_input(project.status == 0, "Project not active")?;
         require_valid_input(
-            project.project_id != U256::from(0),
-            "Project not found"
+            U256::from(env::timestamp()) > project.deadline,
+            "Funding deadline not reached"
+        )?;
+        require_valid_input(
+            project.funding_raised < project.funding_target,
+            "Funding target reached"
         )?;
+        
+        // Nothing to refund if no backer ever contributed, or the funding sweep already ran
+        let refunds_done = project.funding_raised == U256::from(0) || self.refunds_processed.get(project_id);
+        project.status = 2; // Failed
+        self.projects.insert(project_id, project);
+        self.refunds_processed.insert(project_id, refunds_done);
+        
+        Ok(())
+    }
 
-        project.validation_score = score;
-        project.validation_status = if approved { 1 } else { 2 }; // Approved/Rejected
+    pub fn mark_refunds_processed(&mut self, project_id: U256) -> Result<()> {
+        // Reported by ProjectFunding once its refund sweep completes, which may land
+        // before or after the project is marked failed here
+        self.require_contract_or_authorized(self.project_funding.get())?;
+        require_valid_input(self.project_exists.get(project_id), "Project not found")?;
+        
+        self.refunds_processed.insert(project_id, true);
+        Ok(())
+    }
+
+    pub fn relist_project(&mut self, project_id: U256, new_deadline: U256, new_target: U256) -> Result<()> {
+        self.require_not_paused()?;
         
+        let mut project = self.projects.get(project_id);
+        require_valid_input(self.project_exists.get(project_id), "Project not found")?;
+        require_authorized(env::sender() == project.creator, "Only project creator")?;
+        require_valid_input(project.status == 2, "Project not failed")?;
+        require_valid_input(self.refunds_processed.get(project_id), "Refunds pending")?;
+        
+        let current_time = U256::from(env::timestamp());
+        require_valid_input(new_deadline > current_time, "Deadline must be in the future")?;
+        require_valid_input(
+            new_deadline <= current_time + self.max_project_duration.get() * U256::from(86400),
+            "Project duration too long"
+        )?;
+        require_valid_input(
+            new_target >= self.min_funding_for_category(&project.cultural_category),
+            "Funding target too low"
+        )?;
+        // A relisted project is active again, so it needs a free slot like a new one
+        let creator_profile = self.creators.get(project.creator);
+        self.require_active_project_slot(project.creator, creator_profile.is_verified)?;
+        
+        // Refunded contributions no longer count toward the category
+        let category_raised = self.category_total_raised.get(project.cultural_category.clone());
+        self.category_total_raised.insert(
+            project.cultural_category.clone(),
+            category_raised.saturating_sub(project.funding_raised),
+        );
+        
+        // Validation status and score carry over from the previous listing
+        project.status = 0; // Active
+        project.funding_raised = U256::from(0);
+        project.funding_target = new_target;
+        project.deadline = new_deadline;
         self.projects.insert(project_id, project);
+        self.refunds_processed.insert(project_id, false);
+        self.reopen_project_funding(project_id, new_target, new_deadline)?;
 
-        evm::log(ValidationCompleted {
+        evm::log(ProjectRelisted {
             project_id,
-            final_score: score,
-            approved,
-            timestamp: U256::from(block::timestamp()),
+            funding_target: new_target,
+            deadline: new_deadline,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_project_regions(&mut self, project_id: U256, regions: Vec<String>) -> Result<()> {
+        self.require_not_paused()?;
+        
+        let mut project = self.projects.get(project_id);
+        require_valid_input(self.project_exists.get(project_id), "Project not found")?;
+        require_authorized(env::sender() == project.creator, "Only project creator")?;
+        require_valid_input(project.validation_status == 0, "Project already validated")?;
+        require_valid_input(!regions.is_empty(), "Must specify at least one region")?;
+        
+        project.regions = regions.clone();
+        self.projects.insert(project_id, project);
+        // Validators for the project are drawn from these regions
+        self.forward_project_regions(project_id, regions)?;
+        Ok(())
+    }
+
+    pub fn adjust_funding_target(&mut self, project_id: U256, new_target: U256) -> Result<bool> {
+        self.require_not_paused()?;
+        
+        let mut project = self.projects.get(project_id);
+        require_valid_input(self.project_exists.get(project_id), "Project not found")?;
+        require_authorized(env::sender() == project.creator, "Only project creator")?;
+        require_valid_input(project.status == 0, "Project not active")?;
+        require_valid_input(project.funding_raised == U256::from(0), "Project already has backers")?;
+        require_valid_input(new_target != project.funding_target, "Target unchanged")?;
+        require_valid_input(
+            new_target >= self.min_funding_for_category(&project.cultural_category),
+            "Funding target too low"
+        )?;
+        
+        // A significant change means validators judged a different project
+        let old_target = project.funding_target;
+        let change = if new_target > old_target { new_target - old_target } else { old_target - new_target };
+        let significant = change * U256::from(10000) > old_target * self.validation_reset_threshold_bps.get();
+        let validation_reset = significant && project.validation_status != 0;
+        if validation_reset {
+            let category = project.cultural_category.clone();
+            let validated = self.category_validated.get(category.clone());
+            self.category_validated.insert(category.clone(), validated.saturating_sub(U256::from(1)));
+            let score_sum = self.category_score_sum.get(category.clone());
+            self.category_score_sum.insert(category, score_sum.saturating_sub(project.validation_score));
+            
+            project.validation_status = 0; // Pending
+            project.validation_score = U256::from(0);
+            // In production, would ask CulturalValidator to reopen the project's validation
+        }
+        
+        project.funding_target = new_target;
+        self.projects.insert(project_id, project);
+        // Funding also checks that milestone-based projects still sum to the new target
+        self.adjust_project_funding_target(project_id, new_target)?;
+        
+        evm::log(FundingTargetAdjusted {
+            project_id,
+            old_target,
+            new_target,
+            validation_reset,
         });
         
+        Ok(validation_reset)
+    }
+
+    pub fn set_project_validation(&mut self, project_id: U256, score: U256, approved: bool) -> Result<()> {
+        self.require_contract_or_authorized(self.cultural_validator.get())?;
+        
+        require_valid_input(
+            self.project_exists.get(project_id),
+            "Project not found"
+        )?;
+
+        self.apply_project_validation(project_id, score, approved);
         Ok(())
     }
 
+    pub fn set_project_validations_batch(&mut self, entries: Vec<(U256, U256, bool)>) -> Result<U256> {
+        require_authorized(
+            env::sender() == self.cultural_validator.get(),
+            "Only validator contract"
+        )?;
+        
+        // Unknown project IDs are skipped so one bad entry doesn't revert the burst
+        let mut updated = U256::from(0);
+        for (project_id, score, approved) in entries {
+            if !self.project_exists.get(project_id) {
+                continue;
+            }
+            self.apply_project_validation(project_id, score, approved);
+            updated += U256::from(1);
+        }
+        
+        Ok(updated)
+    }
+
     // Administrative functions
     pub fn set_platform_fee(&mut self, new_fee_bps: U256) -> Result<()> {
         self.require_owner()?;
@@ -324,6 +840,196 @@ impl AfroCreatePlatform {
         Ok(())
     }
 
+    pub fn set_category_min_funding(&mut self, category: String, min_funding: U256) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(
+            self.is_approved_category(&category),
+            "Cultural category not approved"
+        )?;
+        self.category_min_funding.insert(category, min_funding);
+        Ok(())
+    }
+
+    pub fn remove_approved_category(&mut self, category: String) -> Result<U256> {
+        self.require_owner()?;
+        require_valid_input(self.is_approved_category(&category), "Cultural category not approved")?;
+        let fallback = self.fallback_category.get();
+        require_valid_input(category != fallback, "Cannot remove fallback category")?;
+        
+        let mut remaining = Vec::new();
+        for i in 0..self.approved_categories.len() {
+            if let Some(approved_category) = self.approved_categories.get(i) {
+                if approved_category != category {
+                    remaining.push(approved_category);
+                }
+            }
+        }
+        self.approved_categories.erase();
+        for approved_category in remaining {
+            self.approved_categories.push(approved_category);
+        }
+        
+        // Existing projects move to the fallback rather than dangling under a removed category
+        let project_ids = self.get_category_projects(category.clone())?;
+        for project_id in &project_ids {
+            let mut project = self.projects.get(*project_id);
+            project.cultural_category = fallback.clone();
+            self.projects.insert(*project_id, project);
+            self.category_projects.get_mut(fallback.clone()).push(*project_id);
+        }
+        self.category_projects.get_mut(category.clone()).erase();
+        
+        // Category stats follow their projects
+        Self::move_category_stat(&mut self.category_total_raised, &category, &fallback);
+        Self::move_category_stat(&mut self.category_successful, &category, &fallback);
+        Self::move_category_stat(&mut self.category_validated, &category, &fallback);
+        Self::move_category_stat(&mut self.category_score_sum, &category, &fallback);
+
+        evm::log(CategoryRemoved {
+            category,
+            fallback_category: fallback,
+            projects_reassigned: U256::from(project_ids.len()),
+        });
+
+        Ok(U256::from(project_ids.len()))
+    }
+
+    pub fn set_fallback_category(&mut self, category: String) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(!category.is_empty(), "Invalid fallback category")?;
+        self.fallback_category.set(category);
+        Ok(())
+    }
+
+    pub fn get_fallback_category(&self) -> String {
+        self.fallback_category.get()
+    }
+
+    pub fn set_milestone_reputation_points(&mut self, points: U256) -> Result<()> {
+        self.require_owner()?;
+        self.milestone_reputation_points.set(points);
+        Ok(())
+    }
+
+    pub fn set_validation_reset_threshold(&mut self, threshold_bps: U256) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(threshold_bps <= U256::from(10000), "Invalid threshold")?;
+        self.validation_reset_threshold_bps.set(threshold_bps);
+        Ok(())
+    }
+
+    pub fn set_auto_validation_thresholds(&mut self, min_reputation: U256, max_funding: U256) -> Result<()> {
+        self.require_authorized()?;
+        self.auto_validation_min_reputation.set(min_reputation);
+        self.auto_validation_max_funding.set(max_funding);
+        Ok(())
+    }
+
+    pub fn get_auto_validation_thresholds(&self) -> (U256, U256) {
+        (self.auto_validation_min_reputation.get(), self.auto_validation_max_funding.get())
+    }
+
+    pub fn set_high_value_gate(
+        &mut self,
+        threshold: U256,
+        min_reputation: U256,
+        verified_exempt: bool,
+    ) -> Result<()> {
+        self.require_authorized()?;
+        require_valid_input(
+            min_reputation <= U256::from(MAX_CREATOR_REPUTATION),
+            "Reputation bar above maximum"
+        )?;
+        self.high_value_threshold.set(threshold);
+        self.high_value_min_reputation.set(min_reputation);
+        self.high_value_verified_exempt.set(verified_exempt);
+        Ok(())
+    }
+
+    pub fn get_high_value_gate(&self) -> (U256, U256, bool) {
+        (
+            self.high_value_threshold.get(),
+            self.high_value_min_reputation.get(),
+            self.high_value_verified_exempt.get(),
+        )
+    }
+
+    pub fn set_creator_verified(&mut self, creator: Address, verified: bool) -> Result<()> {
+        self.require_authorized()?;
+        require_valid_input(self.creator_exists.get(creator), "Creator not registered")?;
+        let mut profile = self.creators.get(creator);
+        profile.is_verified = verified;
+        self.creators.insert(creator, profile);
+        Ok(())
+    }
+
+    pub fn is_auto_validated(&self, project_id: U256) -> bool {
+        self.auto_validated.get(project_id)
+    }
+
+    pub fn get_auto_validated_projects(&self) -> Vec<U256> {
+        let mut projects = Vec::new();
+        for i in 0..self.auto_validated_projects.len() {
+            if let Some(project_id) = self.auto_validated_projects.get(i) {
+                projects.push(project_id);
+            }
+        }
+        projects
+    }
+
+    pub fn set_max_active_projects(&mut self, standard_limit: U256, verified_limit: U256) -> Result<()> {
+        self.require_owner()?;
+        self.max_active_projects.set(standard_limit);
+        self.max_active_projects_verified.set(verified_limit);
+        Ok(())
+    }
+
+    pub fn set_contract_addresses(
+        &mut self,
+        project_funding: Address,
+        revenue_distributor: Address,
+        cultural_validator: Address,
+        governance: Address,
+    ) -> Result<()> {
+        self.require_owner()?;
+        self.project_funding.set(project_funding);
+        self.revenue_distributor.set(revenue_distributor);
+        self.cultural_validator.set(cultural_validator);
+        self.governance.set(governance);
+        Ok(())
+    }
+
+    pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(!new_owner.is_zero(), "Invalid new owner")?;
+        
+        self.pending_owner.set(new_owner);
+        
+        evm::log(OwnershipTransferStarted {
+            previous_owner: self.owner.get(),
+            new_owner,
+        });
+        
+        Ok(())
+    }
+
+    pub fn accept_ownership(&mut self) -> Result<()> {
+        let caller = env::sender();
+        let pending = self.pending_owner.get();
+        require_authorized(!pending.is_zero() && caller == pending, "Only pending owner")?;
+        
+        let previous_owner = self.owner.get();
+        self.owner.set(caller);
+        self.pending_owner.set(Address::ZERO);
+        
+        evm::log(OwnershipTransferred {
+            previous_owner,
+            new_owner: caller,
+        });
+        
+        Ok(())
+    }
+
     pub fn add_admin(&mut self, admin: Address) -> Result<()> {
         self.require_owner()?;
         self.admins.insert(admin, true);
@@ -341,7 +1047,7 @@ impl AfroCreatePlatform {
         self.paused.set(true);
         
         evm::log(PlatformPaused {
-            timestamp: U256::from(block::timestamp()),
+            timestamp: U256::from(env::timestamp()),
         });
         
         Ok(())
@@ -352,7 +1058,19 @@ impl AfroCreatePlatform {
         self.paused.set(false);
         
         evm::log(PlatformUnpaused {
-            timestamp: U256::from(block::timestamp()),
+            timestamp: U256::from(env::timestamp()),
+        });
+        
+        Ok(())
+    }
+
+    pub fn set_funding_paused(&mut self, paused: bool) -> Result<()> {
+        self.require_owner()?;
+        self.funding_paused.set(paused);
+        
+        evm::log(FundingPauseUpdated {
+            paused,
+            timestamp: U256::from(env::timestamp()),
         });
         
         Ok(())
@@ -363,14 +1081,30 @@ impl AfroCreatePlatform {
         self.paused.get()
     }
 
+    pub fn is_funding_paused(&self) -> bool {
+        self.funding_paused.get()
+    }
+
     pub fn owner(&self) -> Address {
         self.owner.get()
     }
 
+    pub fn pending_owner(&self) -> Address {
+        self.pending_owner.get()
+    }
+
     pub fn platform_fee_bps(&self) -> U256 {
         self.platform_fee_bps.get()
     }
 
+    pub fn is_registered_creator(&self, creator: Address) -> bool {
+        self.creator_exists.get(creator)
+    }
+
+    pub fn is_existing_project(&self, project_id: U256) -> bool {
+        self.project_exists.get(project_id)
+    }
+
     pub fn total_creators(&self) -> U256 {
         self.creator_count.get()
     }
@@ -379,6 +1113,58 @@ impl AfroCreatePlatform {
         self.project_count.get()
     }
 
+    pub fn category_min_funding(&self, category: String) -> U256 {
+        self.min_funding_for_category(&category)
+    }
+
+    pub fn get_category_stats(&self, category: String) -> (U256, U256, U256, U256) {
+        let validated = self.category_validated.get(category.clone());
+        let average_validation_score = if validated > U256::from(0) {
+            self.category_score_sum.get(category.clone()) / validated
+        } else {
+            U256::from(0)
+        };
+        
+        (
+            U256::from(self.category_projects.get(category.clone()).len()),
+            self.category_total_raised.get(category.clone()),
+            self.category_successful.get(category),
+            average_validation_score,
+        )
+    }
+
+    pub fn get_platform_health(&self) -> PlatformHealth {
+        // Read-only; status counts scan at most MAX_HEALTH_SCAN projects so the call stays gas-bounded.
+        // In production, the external metrics would be read from the stored contract addresses
+        let project_count = self.project_count.get().as_u64();
+        let first = project_count.saturating_sub(MAX_HEALTH_SCAN) + 1;
+        let mut active = U256::from(0);
+        let mut successful = U256::from(0);
+        let mut failed = U256::from(0);
+        
+        for id in first..=project_count {
+            match self.get_effective_status(U256::from(id)) {
+                Ok(0) => active += U256::from(1),
+                Ok(1) => successful += U256::from(1),
+                Ok(2) => failed += U256::from(1),
+                _ => {}
+            }
+        }
+        
+        PlatformHealth {
+            total_creators: self.creator_count.get(),
+            total_projects: self.project_count.get(),
+            active_projects: active,
+            successful_projects: successful,
+            failed_projects: failed,
+            projects_scanned: U256::from(project_count + 1 - first),
+            total_funding_raised: self.total_funding_raised.get(),
+            total_revenue_distributed: self.reported_revenue_distributed.get(),
+            cultural_fund_balance: self.reported_cultural_fund_balance.get(),
+            validator_count: self.reported_validator_count.get(),
+        }
+    }
+
     pub fn platform_stats(&self) -> (U256, U256, U256, U256) {
         (
             self.total_funding_raised.get(),
@@ -391,22 +1177,95 @@ impl AfroCreatePlatform {
 
 // Internal helper functions
 impl AfroCreatePlatform {
+    fn sync_creator_voting_power(&mut self, creator: Address, power: U256) -> Result<()> {
+        let governance = self.governance.get();
+        if governance.is_zero() {
+            return Ok(());
+        }
+        
+        let mut calldata = stylus_sdk::function_selector!("updateVotingPower", Address, u8, U256).to_vec();
+        calldata.extend_from_slice(creator.into_word().as_slice());
+        calldata.extend_from_slice(&U256::from(VotingRole::Creator as u8).to_be_bytes::<32>());
+        calldata.extend_from_slice(&power.to_be_bytes::<32>());
+        env::call_contract(self, U256::from(0), governance, &calldata)
+            .map_err(|_| AfroCreateError::InvalidInput("Voting power update failed".to_string()))?;
+        Ok(())
+    }
+
     fn require_not_paused(&self) -> Result<()> {
         require_valid_input(!self.paused.get(), "Contract is paused")
     }
 
+    fn require_funding_not_paused(&self) -> Result<()> {
+        require_valid_input(!self.funding_paused.get(), "Funding is paused")
+    }
+
     fn require_owner(&self) -> Result<()> {
-        require_authorized(msg::sender() == self.owner.get(), "Only owner")
+        require_authorized(env::sender() == self.owner.get(), "Only owner")
     }
 
     fn require_authorized(&self) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             caller == self.owner.get() || self.admins.get(caller),
             "Not authorized"
         )
     }
 
+    fn meets_high_value_bar(&self, profile: &CreatorProfile, funding_target: U256) -> bool {
+        let threshold = self.high_value_threshold.get();
+        if threshold == U256::from(0) || funding_target <= threshold {
+            return true;
+        }
+        (profile.is_verified && self.high_value_verified_exempt.get())
+            || profile.reputation_score >= self.high_value_min_reputation.get()
+    }
+
+    fn is_auto_validation_eligible(&self, profile: &CreatorProfile, funding_target: U256) -> bool {
+        let max_funding = self.auto_validation_max_funding.get();
+        max_funding > U256::from(0)
+            && funding_target <= max_funding
+            && profile.is_verified
+            && profile.reputation_score >= self.auto_validation_min_reputation.get()
+    }
+
+    fn apply_project_validation(&mut self, project_id: U256, score: U256, approved: bool) {
+        let mut project = self.projects.get(project_id);
+        
+        // A re-validation replaces the project's previous score in the category average
+        let category = project.cultural_category.clone();
+        let score_sum = self.category_score_sum.get(category.clone());
+        if project.validation_status == 0 {
+            let validated = self.category_validated.get(category.clone());
+            self.category_validated.insert(category.clone(), validated + U256::from(1));
+            self.category_score_sum.insert(category, score_sum + score);
+        } else {
+            self.category_score_sum.insert(category, score_sum - project.validation_score + score);
+        }
+        
+        project.validation_score = score;
+        project.validation_status = if approved { 1 } else { 2 }; // Approved/Rejected
+        
+        self.projects.insert(project_id, project);
+
+        evm::log(ValidationCompleted {
+            project_id,
+            final_score: score,
+            approved,
+            timestamp: U256::from(env::timestamp()),
+        });
+    }
+
+    fn require_contract_or_authorized(&self, contract: Address) -> Result<()> {
+        let caller = env::sender();
+        require_authorized(
+            (!contract.is_zero() && caller == contract)
+                || caller == self.owner.get()
+                || self.admins.get(caller),
+            "Not authorized"
+        )
+    }
+
     fn validate_ens_name(&self, name: &str) -> Result<bool> {
         require_valid_input(name.len() >= 3, "ENS name too short")?;
         require_valid_input(name.len() <= 63, "ENS name too long")?;
@@ -419,6 +1278,91 @@ impl AfroCreatePlatform {
         Ok(true)
     }
 
+    fn require_active_project_slot(&self, creator: Address, is_verified: bool) -> Result<()> {
+        let active_limit = if is_verified {
+            self.max_active_projects_verified.get()
+        } else {
+            self.max_active_projects.get()
+        };
+        require_valid_input(
+            active_limit == U256::from(0) || self.count_active_projects(creator) < active_limit,
+            "Too many active projects"
+        )
+    }
+
+    fn reopen_project_funding(&mut self, project_id: U256, target: U256, deadline: U256) -> Result<()> {
+        let funding = self.project_funding.get();
+        if funding.is_zero() {
+            // No funding contract wired yet, so there is no funding record to reopen
+            return Ok(());
+        }
+        
+        let mut calldata = stylus_sdk::function_selector!("reopenProjectFunding", U256, U256, U256).to_vec();
+        calldata.extend_from_slice(&project_id.to_be_bytes::<32>());
+        calldata.extend_from_slice(&target.to_be_bytes::<32>());
+        calldata.extend_from_slice(&deadline.to_be_bytes::<32>());
+        env::call_contract(self, U256::from(0), funding, &calldata)
+            .map_err(|_| AfroCreateError::InvalidInput("Funding record not reopened".to_string()))?;
+        Ok(())
+    }
+
+    fn forward_project_regions(&mut self, project_id: U256, regions: Vec<String>) -> Result<()> {
+        let validator = self.cultural_validator.get();
+        if validator.is_zero() {
+            // No validator wired yet, so the regions are only kept on the project
+            return Ok(());
+        }
+        
+        let mut calldata = stylus_sdk::function_selector!("setProjectRegions", U256, Vec<String>).to_vec();
+        calldata.extend_from_slice(&(project_id, regions).abi_encode_params());
+        env::call_contract(self, U256::from(0), validator, &calldata)
+            .map_err(|_| AfroCreateError::InvalidInput("Regions not forwarded to validator".to_string()))?;
+        Ok(())
+    }
+
+    fn adjust_project_funding_target(&mut self, project_id: U256, target: U256) -> Result<()> {
+        let funding = self.project_funding.get();
+        if funding.is_zero() {
+            // No funding contract wired yet, so there is no funding record to adjust
+            return Ok(());
+        }
+        
+        let mut calldata = stylus_sdk::function_selector!("adjustFundingTarget", U256, U256).to_vec();
+        calldata.extend_from_slice(&project_id.to_be_bytes::<32>());
+        calldata.extend_from_slice(&target.to_be_bytes::<32>());
+        env::call_contract(self, U256::from(0), funding, &calldata)
+            .map_err(|_| AfroCreateError::InvalidInput("Funding target not adjusted".to_string()))?;
+        Ok(())
+    }
+
+    fn count_active_projects(&self, creator: Address) -> U256 {
+        let project_ids = self.creator_projects.get(creator);
+        let mut active = U256::from(0);
+        for i in 0..project_ids.len() {
+            if let Some(project_id) = project_ids.get(i) {
+                if self.projects.get(project_id).status == 0 {
+                    active += U256::from(1);
+                }
+            }
+        }
+        active
+    }
+
+    fn min_funding_for_category(&self, category: &str) -> U256 {
+        let category_min = self.category_min_funding.get(category.to_string());
+        if category_min == U256::from(0) {
+            self.min_project_funding.get()
+        } else {
+            category_min
+        }
+    }
+
+    fn move_category_stat(stat: &mut StorageMap<String, U256>, from: &str, to: &str) {
+        let moved = stat.get(from.to_string());
+        stat.insert(to.to_string(), stat.get(to.to_string()) + moved);
+        stat.insert(from.to_string(), U256::from(0));
+    }
+
     fn is_approved_category(&self, category: &str) -> bool {
         for i in 0..self.approved_categories.len() {
             if let Some(approved_category) = self.approved_categories.get(i) {
@@ -1,16 +1,20 @@
 use alloy_primitives::{Address, U256};
 use stylus_sdk::{
-    block, evm, msg,
+    evm,
     prelude::*,
     storage::{StorageAddress, StorageBool, StorageMap, StorageU256, StorageVec},
 };
 
 use crate::types::{
-    errors::{AfroCreateError, Result, require_authorized, require_valid_input},
+    env,
+    errors::{AfroCreateError, Result, require_authorized, require_valid_input, require_sufficient_funds},
     events::*,
-    Proposal, Vote, ProposalStatus, Role,
+    math::mul_bps,
+    Proposal, Vote, ProposalStatus, ProposalType, Role, VotingRole,
 };
 
+const MAX_VOTING_POWER_BATCH: usize = 100;
+
 #[derive(SolidityType, Clone, Debug)]
 pub struct VotingPowerBreakdown {
     pub creator_power: U256,
@@ -39,6 +43,7 @@ pub struct CulturalFundAllocation {
     pub region: String,
     pub approved_timestamp: U256,
     pub disbursed: bool,
+    pub voided: bool,
 }
 
 #[storage]
@@ -49,6 +54,8 @@ pub struct PlatformGovernance {
     backer_voting_power: StorageMap<Address, U256>,
     validator_voting_power: StorageMap<Address, U256>,
     reputation_scores: StorageMap<Address, U256>,
+    power_acquired_at: StorageMap<Address, StorageMap<U256, U256>>, // user -> voting role -> last increase
+    pending_power: StorageMap<Address, StorageMap<U256, U256>>, // user -> voting role -> power still in its holding period
     
     // Proposals
     proposals: StorageMap<U256, Proposal>,
@@ -70,9 +77,14 @@ pub struct PlatformGovernance {
     
     // Governance parameters
     proposal_threshold: StorageU256,
+    proposal_deposit: StorageU256, // Forfeited to the treasury if a proposal misses quorum
     voting_period: StorageU256,
     execution_delay: StorageU256,
     quorum_threshold: StorageU256, // Minimum participation required
+    allocation_expiry: StorageU256, // Time an undisbursed allocation stays reserved
+    min_holding_period: StorageU256, // Power must be held this long before it counts toward votes
+    type_quorum_threshold: StorageMap<U256, U256>, // proposal type -> quorum bps (0 = global quorum)
+    type_approval_threshold: StorageMap<U256, U256>, // proposal type -> for-vote share bps (0 = simple majority)
     
     // Voting power weights
     creator_weight: StorageU256,
@@ -84,10 +96,15 @@ pub struct PlatformGovernance {
     cultural_regions: StorageVec<String>,
     regional_fund_allocation: StorageMap<String, U256>, // region -> allocated amount
     regional_coordinators: StorageMap<String, Address>,
+    regional_earmark_bps: StorageMap<String, U256>, // region -> share of each cultural fund inflow
+    total_regional_earmark_bps: StorageU256,
+    regional_earmarks: StorageMap<String, U256>, // region -> earmarked, claimable by its coordinator
     
     // Access control
     owner: StorageAddress,
     governance_admins: StorageMap<Address, bool>,
+    security_guardians: StorageMap<Address, bool>, // Manage the withdrawal allowlist, separate from governance admins
+    approved_recipients: StorageMap<Address, bool>, // Only these addresses may receive treasury or cultural fund payouts
     
     // Emergency controls
     emergency_pause: StorageBool,
@@ -114,7 +131,7 @@ impl PlatformGovernance {
     ) -> Result<()> {
         require_valid_input(self.owner.get().is_zero(), "Already initialized")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         self.owner.set(caller);
         self.platform_contract.set(platform_contract);
         self.validator_contract.set(validator_contract);
@@ -122,9 +139,17 @@ impl PlatformGovernance {
         
         // Set default governance parameters
         self.proposal_threshold.set(U256::from(1000000000000000000u64)); // 1 unit of voting power
+        self.proposal_deposit.set(U256::from(10000000000000000u64)); // 0.01 ETH
         self.voting_period.set(U256::from(7 * 24 * 3600)); // 7 days
         self.execution_delay.set(U256::from(24 * 3600)); // 1 day
         self.quorum_threshold.set(U256::from(1000)); // 10% in basis points
+        self.allocation_expiry.set(U256::from(90 * 24 * 3600)); // 90 days
+        self.min_holding_period.set(U256::from(24 * 3600)); // 1 day
+        
+        // Spending proposals need broader participation and a clear majority
+        self.type_quorum_threshold.insert(U256::from(ProposalType::TreasurySpend as u8), U256::from(2000)); // 20%
+        self.type_approval_threshold.insert(U256::from(ProposalType::TreasurySpend as u8), U256::from(6000)); // 60%
+        self.type_quorum_threshold.insert(U256::from(ProposalType::CulturalGrant as u8), U256::from(1500)); // 15%
         
         // Set voting power weights
         self.creator_weight.set(U256::from(100)); // Base weight for creators
@@ -141,24 +166,33 @@ impl PlatformGovernance {
         Ok(())
     }
 
+    #[payable]
     pub fn create_proposal(
         &mut self,
         title: String,
         description: String,
+        proposal_type: u8,
         execution_data: Vec<u8>,
     ) -> Result<U256> {
         self.require_not_paused()?;
+        require_valid_input(
+            proposal_type <= ProposalType::CulturalGrant as u8,
+            "Invalid proposal type"
+        )?;
         
-        let proposer = msg::sender();
-        let voting_power = self.calculate_voting_power(proposer)?;
+        let proposer = env::sender();
+        let voting_power = self.calculate_eligible_voting_power(proposer)?;
         
         require_valid_input(
             voting_power >= self.proposal_threshold.get(),
             "Insufficient voting power to create proposal"
         )?;
         
+        let deposit = env::value();
+        require_sufficient_funds(deposit >= self.proposal_deposit.get(), "Insufficient proposal deposit")?;
+        
         let proposal_id = self.next_proposal_id.get();
-        let current_time = U256::from(block::timestamp());
+        let current_time = U256::from(env::timestamp());
         
         let proposal = Proposal {
             id: proposal_id,
@@ -170,7 +204,9 @@ impl PlatformGovernance {
             for_votes: U256::from(0),
             against_votes: U256::from(0),
             status: 0, // Active
+            proposal_type,
             execution_data,
+            deposit,
         };
         
         self.proposals.insert(proposal_id, proposal.clone());
@@ -191,8 +227,8 @@ impl PlatformGovernance {
     pub fn vote(&mut self, proposal_id: U256, support: bool) -> Result<()> {
         self.require_not_paused()?;
         
-        let voter = msg::sender();
-        let voting_power = self.calculate_voting_power(voter)?;
+        let voter = env::sender();
+        let voting_power = self.calculate_eligible_voting_power(voter)?;
         
         require_valid_input(voting_power > U256::from(0), "No voting power")?;
         
@@ -200,7 +236,7 @@ impl PlatformGovernance {
         require_valid_input(proposal.id != U256::from(0), "Proposal not found")?;
         require_valid_input(proposal.status == 0, "Proposal not active")?;
         
-        let current_time = U256::from(block::timestamp());
+        let current_time = U256::from(env::timestamp());
         require_valid_input(
             current_time >= proposal.start_time && current_time <= proposal.end_time,
             "Voting period not active"
@@ -254,7 +290,7 @@ impl PlatformGovernance {
         require_valid_input(proposal.id != U256::from(0), "Proposal not found")?;
         require_valid_input(proposal.status == 0, "Proposal not active")?;
         
-        let current_time = U256::from(block::timestamp());
+        let current_time = U256::from(env::timestamp());
         require_valid_input(current_time > proposal.end_time, "Voting period not ended")?;
         require_valid_input(
             current_time >= proposal.end_time + self.execution_delay.get(),
@@ -263,11 +299,21 @@ impl PlatformGovernance {
         
         // Check if proposal passed
         let total_votes = proposal.for_votes + proposal.against_votes;
-        let total_voting_power = self.calculate_total_voting_power();
-        let quorum_required = (total_voting_power * self.quorum_threshold.get()) / U256::from(10000);
+        let quorum_required = self.quorum_required(proposal.proposal_type);
         
         require_valid_input(total_votes >= quorum_required, "Quorum not reached")?;
-        require_valid_input(proposal.for_votes > proposal.against_votes, "Proposal rejected")?;
+        
+        let approval_bps = self.type_approval_threshold.get(U256::from(proposal.proposal_type));
+        let approved = if approval_bps == U256::from(0) {
+            proposal.for_votes > proposal.against_votes
+        } else {
+            proposal.for_votes * U256::from(10000) >= total_votes * approval_bps
+        };
+        require_valid_input(approved, "Proposal rejected")?;
+        
+        // Quorum was reached, so the deposit goes back to the proposer
+        self.settle_deposit(proposal_id, true)?;
+        let proposal = self.proposals.get(proposal_id);
         
         // Execute proposal
         let success = self.execute_proposal_logic(&proposal)?;
@@ -289,6 +335,23 @@ impl PlatformGovernance {
         Ok(success)
     }
 
+    pub fn settle_proposal_deposit(&mut self, proposal_id: U256) -> Result<bool> {
+        let proposal = self.proposals.get(proposal_id);
+        require_valid_input(proposal.id != U256::from(0), "Proposal not found")?;
+        require_valid_input(proposal.deposit > U256::from(0), "No deposit to settle")?;
+        require_valid_input(
+            U256::from(env::timestamp()) > proposal.end_time,
+            "Voting period not ended"
+        )?;
+        
+        // Reaching quorum is what separates a genuine proposal from spam, pass or fail
+        let total_votes = proposal.for_votes + proposal.against_votes;
+        let reached_quorum = total_votes >= self.quorum_required(proposal.proposal_type);
+        self.settle_deposit(proposal_id, reached_quorum)?;
+        
+        Ok(reached_quorum)
+    }
+
     pub fn allocate_cultural_fund(
         &mut self,
         recipient: Address,
@@ -309,8 +372,9 @@ impl PlatformGovernance {
             amount,
             purpose,
             region: region.clone(),
-            approved_timestamp: U256::from(block::timestamp()),
+            approved_timestamp: U256::from(env::timestamp()),
             disbursed: false,
+            voided: false,
         };
         
         self.cultural_fund_allocations.insert(allocation_id, allocation);
@@ -332,9 +396,11 @@ impl PlatformGovernance {
         let mut allocation = self.cultural_fund_allocations.get(allocation_id);
         require_valid_input(allocation.allocation_id != U256::from(0), "Allocation not found")?;
         require_valid_input(!allocation.disbursed, "Already disbursed")?;
+        require_valid_input(!allocation.voided, "Allocation voided")?;
+        self.require_approved_recipient(allocation.recipient)?;
         
         // Transfer funds to recipient
-        stylus_sdk::call::transfer_eth(allocation.recipient, allocation.amount)?;
+        env::transfer_eth(allocation.recipient, allocation.amount)?;
         
         // Mark as disbursed
         allocation.disbursed = true;
@@ -347,11 +413,50 @@ impl PlatformGovernance {
         Ok(())
     }
 
+    pub fn withdraw_to(&mut self, recipient: Address, amount: U256) -> Result<()> {
+        self.require_governance_admin()?;
+        self.require_not_paused()?;
+        self.require_approved_recipient(recipient)?;
+        require_valid_input(amount > U256::from(0), "Invalid amount")?;
+        require_sufficient_funds(amount <= self.treasury_balance.get(), "Insufficient treasury balance")?;
+        
+        // Debit the treasury before the external transfer
+        self.treasury_balance.set(self.treasury_balance.get() - amount);
+        env::transfer_eth(recipient, amount)?;
+        
+        evm::log(TreasuryWithdrawn { recipient, amount });
+        
+        Ok(())
+    }
+
+    pub fn reclaim_expired_allocation(&mut self, allocation_id: U256) -> Result<U256> {
+        self.require_governance_admin()?;
+        
+        let mut allocation = self.cultural_fund_allocations.get(allocation_id);
+        require_valid_input(allocation.allocation_id != U256::from(0), "Allocation not found")?;
+        require_valid_input(!allocation.disbursed, "Already disbursed")?;
+        require_valid_input(!allocation.voided, "Allocation voided")?;
+        require_valid_input(
+            U256::from(env::timestamp()) > allocation.approved_timestamp + self.allocation_expiry.get(),
+            "Allocation not expired"
+        )?;
+        
+        // Release the reservation back into the fund
+        allocation.voided = true;
+        self.cultural_fund_allocations.insert(allocation_id, allocation.clone());
+        
+        let current_regional = self.regional_fund_allocation.get(allocation.region.clone());
+        self.regional_fund_allocation.insert(allocation.region, current_regional - allocation.amount);
+        self.cultural_fund_balance.set(self.cultural_fund_balance.get() + allocation.amount);
+        
+        Ok(allocation.amount)
+    }
+
     pub fn delegate_voting_power(&mut self, delegate: Address) -> Result<()> {
-        let delegator = msg::sender();
+        let delegator = env::sender();
         require_valid_input(delegator != delegate, "Cannot delegate to self")?;
         
-        let voting_power = self.calculate_voting_power(delegator)?;
+        let voting_power = self.calculate_eligible_voting_power(delegator)?;
         require_valid_input(voting_power > U256::from(0), "No voting power to delegate")?;
         
         // Remove previous delegation if exists
@@ -369,28 +474,65 @@ impl PlatformGovernance {
         Ok(())
     }
 
-    // View functions
-    pub fn calculate_voting_power(&self, user: Address) -> Result<U256> {
-        let creator_power = self.creator_voting_power.get(user) * self.creator_weight.get() / U256::from(100);
-        let backer_power = self.backer_voting_power.get(user) * self.backer_weight.get() / U256::from(100);
-        let validator_power = self.validator_voting_power.get(user) * self.validator_weight.get() / U256::from(100);
-        
-        let base_power = creator_power + backer_power + validator_power;
-        
-        // Apply reputation multiplier
-        let reputation = self.reputation_scores.get(user);
-        let multiplier = if reputation >= U256::from(80) {
-            self.reputation_multiplier.get()
+    pub fn update_voting_power(&mut self, user: Address, role: u8, amount: U256) -> Result<()> {
+        let caller = env::sender();
+        require_authorized(
+            caller == self.platform_contract.get()
+                || caller == self.validator_contract.get()
+                || caller == self.funding_contract.get()
+                || caller == self.owner.get()
+                || self.governance_admins.get(caller),
+            "Not authorized to update voting power"
+        )?;
+        require_valid_input(role <= VotingRole::Validator as u8, "Invalid voting role")?;
+        
+        let current = self.role_power(user, role);
+        let role_key = U256::from(role);
+        let now = U256::from(env::timestamp());
+        
+        // Only the increment waits out the holding period; power already held keeps counting.
+        // A decrease is taken from the newest power first.
+        let mut pending = self.unmatured_power(user, role);
+        if amount > current {
+            pending += amount - current;
+            self.power_acquired_at.get_mut(user).insert(role_key, now);
         } else {
-            U256::from(100) // No multiplier
-        };
+            pending = pending.saturating_sub(current - amount);
+        }
+        self.pending_power.get_mut(user).insert(role_key, pending);
         
-        let total_power = (base_power * multiplier) / U256::from(100);
+        if role == VotingRole::Creator as u8 {
+            self.creator_voting_power.insert(user, amount);
+        } else if role == VotingRole::Backer as u8 {
+            self.backer_voting_power.insert(user, amount);
+        } else {
+            self.validator_voting_power.insert(user, amount);
+        }
         
-        // Add delegated power
-        let delegated_power = self.delegate_power.get(user);
+        Ok(())
+    }
+
+    // View functions
+    pub fn calculate_voting_power(&self, user: Address) -> Result<U256> {
+        Ok(self.weighted_voting_power(user, false))
+    }
+
+    pub fn calculate_eligible_voting_power(&self, user: Address) -> Result<U256> {
+        Ok(self.weighted_voting_power(user, true))
+    }
+
+    pub fn min_holding_period(&self) -> U256 {
+        self.min_holding_period.get()
+    }
+
+    pub fn get_voting_powers(&self, users: Vec<Address>) -> Result<Vec<U256>> {
+        require_valid_input(users.len() <= MAX_VOTING_POWER_BATCH, "Too many users")?;
         
-        Ok(total_power + delegated_power)
+        let mut powers = Vec::with_capacity(users.len());
+        for user in users {
+            powers.push(self.calculate_voting_power(user)?);
+        }
+        Ok(powers)
     }
 
     pub fn get_proposal(&self, proposal_id: U256) -> Result<Proposal> {
@@ -399,6 +541,23 @@ impl PlatformGovernance {
         Ok(proposal)
     }
 
+    pub fn quorum_required(&self, proposal_type: u8) -> U256 {
+        let type_quorum = self.type_quorum_threshold.get(U256::from(proposal_type));
+        let quorum_bps = if type_quorum == U256::from(0) {
+            self.quorum_threshold.get()
+        } else {
+            type_quorum
+        };
+        (self.calculate_total_voting_power() * quorum_bps) / U256::from(10000)
+    }
+
+    pub fn get_proposal_type_parameters(&self, proposal_type: u8) -> (U256, U256) {
+        (
+            self.type_quorum_threshold.get(U256::from(proposal_type)),
+            self.type_approval_threshold.get(U256::from(proposal_type)),
+        )
+    }
+
     pub fn get_vote(&self, proposal_id: U256, voter: Address) -> Vote {
         self.proposal_votes.get(proposal_id).get(voter)
     }
@@ -427,9 +586,12 @@ impl PlatformGovernance {
         for (param, value) in new_params {
             match param.as_str() {
                 "proposal_threshold" => self.proposal_threshold.set(value),
+                "proposal_deposit" => self.proposal_deposit.set(value),
                 "voting_period" => self.voting_period.set(value),
                 "execution_delay" => self.execution_delay.set(value),
                 "quorum_threshold" => self.quorum_threshold.set(value),
+                "allocation_expiry" => self.allocation_expiry.set(value),
+                "min_holding_period" => self.min_holding_period.set(value),
                 _ => return Err(AfroCreateError::InvalidInput("Unknown parameter".to_string())),
             }
         }
@@ -437,20 +599,134 @@ impl PlatformGovernance {
         Ok(())
     }
 
+    pub fn set_proposal_type_parameters(
+        &mut self,
+        proposal_type: u8,
+        quorum_bps: U256,
+        approval_bps: U256,
+    ) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(
+            proposal_type <= ProposalType::CulturalGrant as u8,
+            "Invalid proposal type"
+        )?;
+        require_valid_input(quorum_bps <= U256::from(10000), "Quorum too high")?;
+        require_valid_input(approval_bps <= U256::from(10000), "Approval threshold too high")?;
+        
+        self.type_quorum_threshold.insert(U256::from(proposal_type), quorum_bps);
+        self.type_approval_threshold.insert(U256::from(proposal_type), approval_bps);
+        Ok(())
+    }
+
     pub fn add_governance_admin(&mut self, admin: Address) -> Result<()> {
         self.require_owner()?;
         self.governance_admins.insert(admin, true);
         Ok(())
     }
 
+    pub fn set_security_guardian(&mut self, guardian: Address, enabled: bool) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(!guardian.is_zero(), "Invalid guardian")?;
+        self.security_guardians.insert(guardian, enabled);
+        Ok(())
+    }
+
+    pub fn add_approved_recipient(&mut self, recipient: Address) -> Result<()> {
+        self.require_security_guardian()?;
+        require_valid_input(!recipient.is_zero(), "Invalid recipient")?;
+        self.update_approved_recipient(recipient, true);
+        Ok(())
+    }
+
+    pub fn remove_approved_recipient(&mut self, recipient: Address) -> Result<()> {
+        self.require_security_guardian()?;
+        self.update_approved_recipient(recipient, false);
+        Ok(())
+    }
+
+    pub fn is_approved_recipient(&self, recipient: Address) -> bool {
+        self.approved_recipients.get(recipient)
+    }
+
+    pub fn is_security_guardian(&self, account: Address) -> bool {
+        self.security_guardians.get(account)
+    }
+
+    #[payable]
     pub fn fund_cultural_fund(&mut self) -> Result<()> {
-        let amount = msg::value();
-        self.cultural_fund_balance.set(self.cultural_fund_balance.get() + amount);
+        let amount = env::value();
+        
+        // Earmark each region's share for its coordinator before the rest joins the general fund
+        let mut earmarked = U256::from(0);
+        for i in 0..self.cultural_regions.len() {
+            if let Some(region) = self.cultural_regions.get(i) {
+                let share_bps = self.regional_earmark_bps.get(region.clone());
+                if share_bps == U256::from(0) || self.regional_coordinators.get(region.clone()).is_zero() {
+                    continue;
+                }
+                let share = mul_bps(amount, share_bps);
+                let current = self.regional_earmarks.get(region.clone());
+                self.regional_earmarks.insert(region, current + share);
+                earmarked += share;
+            }
+        }
+        
+        self.cultural_fund_balance.set(self.cultural_fund_balance.get() + amount - earmarked);
         Ok(())
     }
 
+    pub fn set_regional_coordinator(&mut self, region: String, coordinator: Address) -> Result<()> {
+        self.require_governance_admin()?;
+        require_valid_input(self.is_supported_region(&region), "Unsupported region")?;
+        self.regional_coordinators.insert(region, coordinator);
+        Ok(())
+    }
+
+    pub fn set_regional_earmark(&mut self, region: String, share_bps: U256) -> Result<()> {
+        self.require_governance_admin()?;
+        require_valid_input(self.is_supported_region(&region), "Unsupported region")?;
+        
+        let total = self.total_regional_earmark_bps.get() - self.regional_earmark_bps.get(region.clone()) + share_bps;
+        require_valid_input(total <= U256::from(10000), "Regional earmarks exceed 100%")?;
+        
+        self.regional_earmark_bps.insert(region, share_bps);
+        self.total_regional_earmark_bps.set(total);
+        Ok(())
+    }
+
+    pub fn claim_regional_earmark(&mut self, region: String) -> Result<U256> {
+        let coordinator = env::sender();
+        let assigned = self.regional_coordinators.get(region.clone());
+        require_authorized(!assigned.is_zero() && coordinator == assigned, "Only regional coordinator")?;
+        // Checked at claim time so removing a coordinator from the allowlist freezes their payouts
+        self.require_approved_recipient(coordinator)?;
+        
+        let amount = self.regional_earmarks.get(region.clone());
+        require_valid_input(amount > U256::from(0), "Nothing earmarked")?;
+        
+        // Clear state before the external transfer
+        self.regional_earmarks.insert(region.clone(), U256::from(0));
+        env::transfer_eth(coordinator, amount)?;
+        
+        evm::log(RegionalEarmarkClaimed {
+            region,
+            coordinator,
+            amount,
+        });
+        
+        Ok(amount)
+    }
+
+    pub fn get_regional_earmark(&self, region: String) -> (Address, U256, U256) {
+        (
+            self.regional_coordinators.get(region.clone()),
+            self.regional_earmark_bps.get(region.clone()),
+            self.regional_earmarks.get(region),
+        )
+    }
+
     pub fn emergency_pause(&mut self) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             caller == self.owner.get() || self.is_emergency_council_member(caller),
             "Not authorized for emergency actions"
@@ -464,17 +740,34 @@ impl PlatformGovernance {
 // Internal helper functions
 impl PlatformGovernance {
     fn require_owner(&self) -> Result<()> {
-        require_authorized(msg::sender() == self.owner.get(), "Only owner")
+        require_authorized(env::sender() == self.owner.get(), "Only owner")
     }
 
     fn require_governance_admin(&self) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             caller == self.owner.get() || self.governance_admins.get(caller),
             "Only governance admin"
         )
     }
 
+    fn require_security_guardian(&self) -> Result<()> {
+        require_authorized(self.security_guardians.get(env::sender()), "Only security guardian")
+    }
+
+    fn require_approved_recipient(&self, recipient: Address) -> Result<()> {
+        require_authorized(self.approved_recipients.get(recipient), "Recipient not approved")
+    }
+
+    fn update_approved_recipient(&mut self, recipient: Address, approved: bool) {
+        self.approved_recipients.insert(recipient, approved);
+        evm::log(ApprovedRecipientUpdated {
+            recipient,
+            approved,
+            updated_by: env::sender(),
+        });
+    }
+
     fn require_not_paused(&self) -> Result<()> {
         require_valid_input(!self.emergency_pause.get(), "Governance paused")
     }
@@ -490,6 +783,88 @@ impl PlatformGovernance {
         false
     }
 
+    fn settle_deposit(&mut self, proposal_id: U256, refund: bool) -> Result<()> {
+        let mut proposal = self.proposals.get(proposal_id);
+        let amount = proposal.deposit;
+        if amount == U256::from(0) {
+            return Ok(());
+        }
+        
+        // Clear the deposit before any transfer
+        proposal.deposit = U256::from(0);
+        let proposer = proposal.proposer;
+        self.proposals.insert(proposal_id, proposal);
+        
+        if refund {
+            env::transfer_eth(proposer, amount)?;
+        } else {
+            self.treasury_balance.set(self.treasury_balance.get() + amount);
+        }
+        
+        evm::log(ProposalDepositSettled {
+            proposal_id,
+            proposer,
+            amount,
+            refunded: refund,
+        });
+        
+        Ok(())
+    }
+
+    fn role_power(&self, user: Address, role: u8) -> U256 {
+        if role == VotingRole::Creator as u8 {
+            self.creator_voting_power.get(user)
+        } else if role == VotingRole::Backer as u8 {
+            self.backer_voting_power.get(user)
+        } else {
+            self.validator_voting_power.get(user)
+        }
+    }
+
+    fn held_role_power(&self, user: Address, role: u8, matured_only: bool) -> U256 {
+        let power = self.role_power(user, role);
+        if !matured_only {
+            return power;
+        }
+        
+        // Power acquired within the holding period can't swing a vote
+        power.saturating_sub(self.unmatured_power(user, role))
+    }
+
+    fn unmatured_power(&self, user: Address, role: u8) -> U256 {
+        let role_key = U256::from(role);
+        let acquired_at = self.power_acquired_at.get(user).get(role_key);
+        if U256::from(env::timestamp()) < acquired_at + self.min_holding_period.get() {
+            self.pending_power.get(user).get(role_key)
+        } else {
+            U256::from(0)
+        }
+    }
+
+    fn weighted_voting_power(&self, user: Address, matured_only: bool) -> U256 {
+        let creator_power = self.held_role_power(user, VotingRole::Creator as u8, matured_only)
+            * self.creator_weight.get() / U256::from(100);
+        let backer_power = self.held_role_power(user, VotingRole::Backer as u8, matured_only)
+            * self.backer_weight.get() / U256::from(100);
+        let validator_power = self.held_role_power(user, VotingRole::Validator as u8, matured_only)
+            * self.validator_weight.get() / U256::from(100);
+        
+        let base_power = creator_power + backer_power + validator_power;
+        
+        // Apply reputation multiplier
+        let reputation = self.reputation_scores.get(user);
+        let multiplier = if reputation >= U256::from(80) {
+            self.reputation_multiplier.get()
+        } else {
+            U256::from(100) // No multiplier
+        };
+        
+        let total_power = (base_power * multiplier) / U256::from(100);
+        
+        // Add delegated power
+        total_power + self.delegate_power.get(user)
+    }
+
     fn calculate_total_voting_power(&self) -> U256 {
         // In production, would iterate through all users or maintain a cached total
         U256::from(1000000) // Placeholder
@@ -1,11 +1,12 @@
 use alloy_primitives::{Address, U256};
 use stylus_sdk::{
-    block, evm, msg,
+    block, evm,
     prelude::*,
     storage::{StorageAddress, StorageMap, StorageU256, StorageVec},
 };
 
 use crate::types::{
+    env,
     errors::{AfroCreateError, Result, require_authorized, require_valid_input},
     events::*,
     ProjectInfo, FundingModel, Milestone,
@@ -23,6 +24,11 @@ pub struct ProjectFactory {
     project_templates: StorageMap<String, U256>, // category -> template_id
     template_configs: StorageMap<U256, ProjectTemplate>,
     
+    // Project listings
+    project_creators: StorageMap<U256, Address>, // projectId -> named creator
+    pending_confirmation: StorageMap<U256, bool>, // projectId -> Draft until the named creator confirms
+    draft_requests: StorageMap<U256, ProjectCreateRequest>, // held until confirmation sets up funding
+    
     // Access control
     owner: StorageAddress,
     authorized_creators: StorageMap<Address, bool>,
@@ -64,7 +70,7 @@ impl ProjectFactory {
     ) -> Result<()> {
         require_valid_input(self.owner.get().is_zero(), "Already initialized")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         self.owner.set(caller);
         self.platform_contract.set(platform_contract);
         self.funding_contract.set(funding_contract);
@@ -78,43 +84,39 @@ impl ProjectFactory {
     }
 
     pub fn create_project(&mut self, request: ProjectCreateRequest) -> Result<U256> {
-        let creator = msg::sender();
-        
-        // Validate creator authorization (would check with platform contract)
-        self.validate_creator_eligibility(creator)?;
-        
-        // Validate request against template
-        self.validate_project_request(&request)?;
-        
-        let project_id = self.next_project_id.get();
-        
-        // Create project in platform contract (simplified)
-        self.create_project_in_platform(project_id, &request, creator)?;
-        
-        // Setup funding in funding contract
-        self.setup_project_funding(project_id, &request, creator)?;
-        
-        // Submit for cultural validation if required
-        let template = self.get_template_for_category(&request.cultural_category)?;
-        if template.validation_required {
-            self.submit_for_validation(project_id, &request)?;
-        }
+        let creator = env::sender();
+        let project_id = self.register_project(&request, creator)?;
+        self.list_project(project_id, request, creator)?;
+        Ok(project_id)
+    }
+
+    pub fn create_project_for(&mut self, creator: Address, request: ProjectCreateRequest) -> Result<U256> {
+        let proposer = env::sender();
+        let project_id = self.register_project(&request, creator)?;
         
-        self.next_project_id.set(project_id + U256::from(1));
-        self.projects_created.set(self.projects_created.get() + U256::from(1));
+        // Listed on someone else's behalf: a Draft until the named creator consents
+        self.pending_confirmation.insert(project_id, true);
+        self.draft_requests.insert(project_id, request);
 
-        evm::log(ProjectCreated {
+        evm::log(ProjectDraftProposed {
             project_id,
             creator,
-            title: request.title,
-            cultural_category: request.cultural_category,
-            funding_target: request.funding_target,
-            deadline: U256::from(block::timestamp()) + (request.duration_days * U256::from(86400)),
+            proposer,
         });
 
         Ok(project_id)
     }
 
+    pub fn confirm_project(&mut self, project_id: U256) -> Result<()> {
+        require_valid_input(self.pending_confirmation.get(project_id), "Project not awaiting confirmation")?;
+        let creator = self.project_creators.get(project_id);
+        require_authorized(env::sender() == creator, "Only named creator")?;
+        
+        self.pending_confirmation.insert(project_id, false);
+        let request = self.draft_requests.get(project_id);
+        self.list_project(project_id, request, creator)
+    }
+
     pub fn add_project_template(
         &mut self,
         category: String,
@@ -158,6 +160,18 @@ impl ProjectFactory {
         self.authorized_creators.get(creator)
     }
 
+    pub fn get_project_creator(&self, project_id: U256) -> Address {
+        self.project_creators.get(project_id)
+    }
+
+    pub fn is_project_draft(&self, project_id: U256) -> bool {
+        self.pending_confirmation.get(project_id)
+    }
+
+    pub fn is_project_fundable(&self, project_id: U256) -> bool {
+        !self.project_creators.get(project_id).is_zero() && !self.pending_confirmation.get(project_id)
+    }
+
     pub fn factory_stats(&self) -> (U256, U256) {
         (self.projects_created.get(), self.next_project_id.get())
     }
@@ -171,7 +185,50 @@ impl ProjectFactory {
 // Internal helper functions
 impl ProjectFactory {
     fn require_owner(&self) -> Result<()> {
-        require_authorized(msg::sender() == self.owner.get(), "Only owner")
+        require_authorized(env::sender() == self.owner.get(), "Only owner")
+    }
+
+    fn register_project(&mut self, request: &ProjectCreateRequest, creator: Address) -> Result<U256> {
+        // Validate creator authorization (would check with platform contract)
+        self.validate_creator_eligibility(creator)?;
+        
+        // Validate request against template
+        self.validate_project_request(request)?;
+        
+        let project_id = self.next_project_id.get();
+        
+        // Create project in platform contract (simplified)
+        self.create_project_in_platform(project_id, request, creator)?;
+        
+        self.project_creators.insert(project_id, creator);
+        self.next_project_id.set(project_id + U256::from(1));
+        self.projects_created.set(self.projects_created.get() + U256::from(1));
+        
+        Ok(project_id)
+    }
+
+    fn list_project(&mut self, project_id: U256, request: ProjectCreateRequest, creator: Address) -> Result<()> {
+        // Setup funding in funding contract
+        self.setup_project_funding(project_id, &request, creator)?;
+        
+        // Submit for cultural validation if required
+        let template = self.get_template_for_category(&request.cultural_category)?;
+        if template.validation_required {
+            self.submit_for_validation(project_id, &request)?;
+        }
+
+        evm::log(ProjectCreated {
+            project_id,
+            creator,
+            title: request.title,
+            cultural_category: request.cultural_category,
+            funding_target: request.funding_target,
+            deadline: U256::from(env::timestamp()) + (request.duration_days * U256::from(86400)),
+            block_number: U256::from(block::number()),
+            created_at: U256::from(env::timestamp()),
+        });
+
+        Ok(())
     }
 
     fn validate_creator_eligibility(&self, creator: Address) -> Result<()> {
@@ -250,7 +307,7 @@ impl ProjectFactory {
     ) -> Result<()> {
         // In production, would call funding contract
         // For now, just validate the setup
-        let deadline = U256::from(block::timestamp()) + (request.duration_days * U256::from(86400));
+        let deadline = U256::from(env::timestamp()) + (request.duration_days * U256::from(86400));
         
         require_valid_input(!self.funding_contract.get().is_zero(), "Funding contract not set")?;
         
@@ -1,17 +1,31 @@
 use alloy_primitives::{Address, U256};
 use stylus_sdk::{
-    block, call, evm, msg,
+    evm,
     prelude::*,
     storage::{StorageAddress, StorageMap, StorageU256, StorageVec, StorageBool},
 };
 
 use crate::types::{
+    env,
     errors::{AfroCreateError, Result, require_authorized, require_valid_input, require_sufficient_funds},
     events::*,
     interfaces::{IAfroCreatePlatform, IRevenueShareNFT},
-    FundingInfo, FundingModel, Milestone,
+    math::{mul_bps, normalize_amount, split_bps_with_dust, split_fee, BPS_DENOMINATOR},
+    FundingInfo, FundingModel, Milestone, VotingRole,
 };
 
+const MAX_LEADERBOARD_SIZE: usize = 50;
+const MAX_VALIDATION_RESERVE_BPS: u64 = 500; // at most 5% of escrow
+const MAX_TOKEN_DECIMALS: u64 = 36; // keeps 10^decimals in normalize_amount well inside U256
+
+#[derive(SolidityType, Clone, Debug)]
+pub struct FundingReceipt {
+    pub token_id: U256,
+    pub contribution: U256,
+    pub revenue_share_bps: U256,
+    pub total_raised: U256,
+}
+
 #[storage]
 #[entrypoint]
 pub struct ProjectFunding {
@@ -19,40 +33,91 @@ pub struct ProjectFunding {
     project_funding: StorageMap<U256, FundingInfo>,
     backer_contributions: StorageMap<U256, StorageMap<Address, U256>>, // projectId -> (backer -> amount)
     project_backers: StorageMap<U256, StorageVec<Address>>, // projectId -> backers list
+    backer_projects: StorageMap<Address, StorageVec<U256>>, // backer -> projects backed
+    backer_project_indexed: StorageMap<Address, StorageMap<U256, bool>>, // backer -> (projectId -> listed)
     
     // NFT contract for revenue shares
     revenue_nft_contract: StorageAddress,
+    backer_nft_tokens: StorageMap<U256, StorageMap<Address, StorageVec<U256>>>, // projectId -> backer -> revenue NFTs minted
     
     // Funding models
     funding_models: StorageMap<U256, U256>, // projectId -> FundingModel (as u8)
+    min_backers: StorageMap<U256, U256>, // projectId -> backers required for success (0 = no minimum)
     
     // Milestones
     project_milestones: StorageMap<U256, StorageVec<Milestone>>,
     milestone_releases: StorageMap<U256, StorageMap<U256, bool>>, // projectId -> (milestoneId -> released)
     milestone_completion: StorageMap<U256, StorageMap<U256, bool>>, // projectId -> (milestoneId -> completed)
+    milestone_grace_periods: StorageMap<U256, U256>, // projectId -> grace after each milestone deadline
+    max_milestone_grace: StorageU256,
+    
+    // Backer dispute flags against the creator
+    dispute_flags: StorageMap<U256, StorageMap<Address, bool>>, // projectId -> (backer -> flagged)
+    dispute_flagged_amount: StorageMap<U256, U256>, // projectId -> contributions held by flagging backers
+    releases_frozen: StorageMap<U256, bool>, // projectId -> milestone releases frozen pending review
+    dispute_threshold_bps: StorageU256, // share of raised funds that must flag before releases freeze
     
     // Platform integration
     platform_contract: StorageAddress,
     
     // Settings
     platform_fee_bps: StorageU256,
+    fee_bps_by_token: StorageMap<Address, U256>, // token -> discounted platform fee
+    token_fee_override: StorageMap<Address, bool>, // token -> fee_bps_by_token applies instead of the default
+    max_platform_fee_absolute: StorageU256, // per-project cap on the native platform fee (0 = no cap)
+    dust_recipient: StorageAddress, // receives rounding dust from finalizations (zero = platform treasury)
+    accumulated_dust: StorageU256, // rounding dust awaiting a sweep
+    fee_rebate_score_threshold: StorageU256, // validation score a project must exceed for a rebate (0 = off)
+    fee_rebate_bps: StorageU256, // share of the platform fee returned to the creator
+    project_validation_scores: StorageMap<U256, U256>, // project -> final validation score, synced from the validator
     min_contribution: StorageU256,
     refund_period: StorageU256, // Period after deadline for refunds
+    refund_fee_bps: StorageU256, // charged on voluntary pre-deadline withdrawals only
     
     // Escrow and treasury
     project_escrow: StorageMap<U256, U256>, // projectId -> escrowed amount
+    refunded_contributions: StorageMap<U256, U256>, // projectId -> contributions already refunded
+    emergency_withdrawn: StorageMap<U256, U256>, // projectId -> escrow pulled by emergency_withdraw, owed to backers
+    refund_credits: StorageMap<Address, U256>, // backer -> native refunds whose push failed during a sweep
     platform_treasury: StorageU256,
     
+    // Cultural fund auto-top-up from native platform fees
+    governance_contract: StorageAddress,
+    cultural_fund_auto_bps: StorageU256, // share of each platform fee forwarded to the cultural fund
+    cultural_fund_auto_contributed: StorageU256, // running total forwarded
+    matched_funds: StorageMap<U256, U256>, // project -> treasury-funded match held in escrow
+    validator_contract: StorageAddress,
+    validation_reserve_bps: StorageMap<U256, U256>, // project -> share of escrow paid to its validators
+    validation_reserve_paid: StorageMap<U256, U256>, // project -> reserve paid out at finalization
+    
+    // Multi-token funding normalized to a common 18-decimal denomination (e.g. USD)
+    oracle_manager: StorageAddress,
+    token_prices: StorageMap<Address, U256>, // token -> price per whole token, synced from OracleManager
+    token_decimals: StorageMap<Address, U256>,
+    price_normalized: StorageMap<U256, bool>, // projectId -> target expressed in common denomination
+    accepted_funding_tokens: StorageMap<U256, StorageMap<Address, bool>>, // projectId -> (token -> accepted)
+    project_accepted_tokens: StorageMap<U256, StorageVec<Address>>,
+    token_escrow: StorageMap<U256, StorageMap<Address, U256>>, // projectId -> (token -> raw escrowed amount)
+    token_treasury: StorageMap<Address, U256>, // platform fees collected in ERC20 tokens
+    backer_token_contributions: StorageMap<U256, StorageMap<Address, StorageMap<Address, U256>>>, // projectId -> backer -> token -> raw amount
+    
     // Access control
     owner: StorageAddress,
     authorized_callers: StorageMap<Address, bool>,
+    funding_paused: StorageBool, // blocks new contributions; refunds and releases keep working
+    claims_blocked_while_paused: StorageBool, // opt-in: the funding pause also blocks refund claims
     
     // Metrics
     total_projects_funded: StorageU256,
     total_amount_raised: StorageU256,
     total_backers: StorageU256,
     
-    // Reentrancy guard
+    // Platform-wide backer leaderboard
+    backer_platform_totals: StorageMap<Address, U256>, // backer -> cumulative contributions
+    platform_backers: StorageVec<Address>,
+    top_platform_backers: StorageVec<Address>, // largest totals first, at most MAX_LEADERBOARD_SIZE
+    
+    // Reentrancy guard shared by every function that makes an external call
     locked: StorageBool,
 }
 
@@ -66,81 +131,81 @@ impl ProjectFunding {
     ) -> Result<()> {
         require_valid_input(self.owner.get().is_zero(), "Already initialized")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         self.owner.set(caller);
         self.platform_contract.set(platform_contract);
         self.revenue_nft_contract.set(revenue_nft_contract);
         self.platform_fee_bps.set(platform_fee_bps);
         self.min_contribution.set(U256::from(1000000000000000u64)); // 0.001 ETH minimum
         self.refund_period.set(U256::from(30 * 24 * 3600)); // 30 days
+        self.max_milestone_grace.set(U256::from(14 * 24 * 3600)); // 14 days
+        self.dispute_threshold_bps.set(U256::from(5000)); // more than half of contributions
         
         Ok(())
     }
 
     #[payable]
     pub fn fund_project(&mut self, project_id: U256, backer_ens_name: String) -> Result<U256> {
+        let receipt = self.fund_project_with_receipt(project_id, backer_ens_name)?;
+        Ok(receipt.token_id)
+    }
+
+    #[payable]
+    pub fn fund_project_with_receipt(&mut self, project_id: U256, backer_ens_name: String) -> Result<FundingReceipt> {
+        self.require_funding_not_paused()?;
         self.nonreentrant_guard()?;
         
-        let backer = msg::sender();
-        let contribution = msg::value();
-        
-        require_sufficient_funds(
-            contribution >= self.min_contribution.get(),
-            "Contribution too small"
-        )?;
+        let receipt = self.contribute_native(project_id, env::sender(), env::value(), backer_ens_name)?;
         
-        // Get project info from platform contract
-        let funding_info = self.project_funding.get(project_id);
-        require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
-        require_valid_input(funding_info.status == 0, "Project not active")?;
-        require_valid_input(
-            U256::from(block::timestamp()) <= funding_info.deadline,
-            "Funding deadline passed"
+        self.unlock_guard();
+        Ok(receipt)
+    }
+
+    #[payable]
+    pub fn reinvest_contribution(&mut self, project_id: U256, backer: Address) -> Result<FundingReceipt> {
+        // Revenue reinvested from the NFT contract is credited to the holder, not the caller
+        let caller = env::sender();
+        require_authorized(
+            caller == self.revenue_nft_contract.get() || caller == self.owner.get(),
+            "Only revenue NFT contract"
         )?;
+        self.require_funding_not_paused()?;
+        self.nonreentrant_guard()?;
         
-        // Update funding info
-        let mut updated_funding = funding_info;
-        updated_funding.raised += contribution;
-        
-        // Track backer contribution
-        let previous_contribution = self.backer_contributions.get(project_id).get(backer);
-        self.backer_contributions.get_mut(project_id).insert(backer, previous_contribution + contribution);
-        
-        // Add to backers list if first contribution
-        if previous_contribution == U256::from(0) {
-            self.project_backers.get_mut(project_id).push(backer);
-            updated_funding.backer_count += U256::from(1);
-        }
+        let receipt = self.contribute_native(project_id, backer, env::value(), String::new())?;
         
-        // Update escrow
-        let current_escrow = self.project_escrow.get(project_id);
-        self.project_escrow.insert(project_id, current_escrow + contribution);
+        self.unlock_guard();
+        Ok(receipt)
+    }
+
+    pub fn fund_project_with_token(
+        &mut self,
+        project_id: U256,
+        token: Address,
+        amount: U256,
+        backer_ens_name: String,
+    ) -> Result<U256> {
+        self.require_funding_not_paused()?;
+        self.nonreentrant_guard()?;
         
-        // Check if funding target reached
-        let funding_model = self.get_funding_model(project_id);
-        if updated_funding.raised >= updated_funding.target {
-            updated_funding.status = 1; // Successful
-            self.total_projects_funded.set(self.total_projects_funded.get() + U256::from(1));
-        }
+        let backer = env::sender();
+        require_valid_input(!token.is_zero(), "Use fund_project for native contributions")?;
         
-        self.project_funding.insert(project_id, updated_funding);
-        self.total_amount_raised.set(self.total_amount_raised.get() + contribution);
+        let credited = if self.price_normalized.get(project_id) {
+            self.pull_token_contribution(token, backer, amount)?;
+            self.escrow_normalized_contribution(project_id, backer, token, amount)?
+        } else {
+            let funding_token = self.project_funding.get(project_id).funding_token;
+            require_valid_input(token == funding_token, "Funding token mismatch")?;
+            self.pull_token_contribution(token, backer, amount)?;
+            self.add_token_escrow(project_id, token, amount);
+            amount
+        };
         
-        // Mint revenue-sharing NFT to backer
-        let nft_token_id = self.mint_revenue_nft(project_id, backer, contribution, backer_ens_name)?;
+        let receipt = self.record_contribution(project_id, backer, credited, backer_ens_name)?;
         
-        // Update platform contract
-        self.update_platform_funding(project_id, updated_funding.raised)?;
-
-        evm::log(ProjectFunded {
-            project_id,
-            backer,
-            amount: contribution,
-            total_raised: updated_funding.raised,
-        });
-
         self.unlock_guard();
-        Ok(nft_token_id)
+        Ok(receipt.token_id)
     }
 
     pub fn setup_project_funding(
@@ -151,6 +216,8 @@ impl ProjectFunding {
         creator: Address,
         funding_model: U256, // FundingModel as u8
         milestones: Vec<Milestone>,
+        funding_token: Address, // Address::ZERO for native ETH
+        min_backers: U256, // 0 for no minimum
     ) -> Result<()> {
         self.require_authorized_caller()?;
         
@@ -167,10 +234,12 @@ impl ProjectFunding {
             creator,
             backer_count: U256::from(0),
             funding_model: funding_model.as_u8(),
+            funding_token,
         };
         
         self.project_funding.insert(project_id, funding_info);
         self.funding_models.insert(project_id, funding_model);
+        self.min_backers.insert(project_id, min_backers);
         
         // Setup milestones for milestone-based funding
         if funding_model == 2 { // MilestoneBased
@@ -183,8 +252,99 @@ impl ProjectFunding {
         Ok(())
     }
 
+    pub fn reopen_project_funding(&mut self, project_id: U256, target: U256, deadline: U256) -> Result<()> {
+        self.require_authorized_caller()?;
+        
+        let funding_info = self.project_funding.get(project_id);
+        require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
+        require_valid_input(funding_info.status == 2, "Project not refunded")?;
+        require_valid_input(
+            self.escrow_balance(project_id, funding_info.funding_token) == U256::from(0),
+            "Escrow not settled"
+        )?;
+        require_valid_input(target > U256::from(0), "Invalid target")?;
+        // Milestones were sized for the original target
+        require_valid_input(
+            funding_info.funding_model != 2 || target == funding_info.target,
+            "Milestone total must match target"
+        )?;
+        
+        // Every previous backer was refunded, so the new round starts with an empty backer list
+        self.project_backers.get_mut(project_id).erase();
+        self.refunded_contributions.insert(project_id, U256::from(0));
+        
+        let reopened = FundingInfo {
+            target,
+            raised: U256::from(0),
+            deadline,
+            status: 0, // Active
+            creator: funding_info.creator,
+            backer_count: U256::from(0),
+            funding_model: funding_info.funding_model,
+            funding_token: funding_info.funding_token,
+        };
+        self.project_funding.insert(project_id, reopened);
+        
+        Ok(())
+    }
+
+    pub fn enable_normalized_funding(&mut self, project_id: U256, tokens: Vec<Address>) -> Result<()> {
+        self.require_authorized_caller()?;
+        
+        let funding_info = self.project_funding.get(project_id);
+        require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
+        require_valid_input(funding_info.raised == U256::from(0), "Project already has contributions")?;
+        require_valid_input(!tokens.is_empty(), "No funding tokens")?;
+        
+        // Address::ZERO in the list accepts native ETH
+        for token in tokens {
+            require_valid_input(self.token_prices.get(token) > U256::from(0), "No price feed for token")?;
+            if !self.accepted_funding_tokens.get(project_id).get(token) {
+                self.accepted_funding_tokens.get_mut(project_id).insert(token, true);
+                self.project_accepted_tokens.get_mut(project_id).push(token);
+            }
+        }
+        self.price_normalized.insert(project_id, true);
+        
+        Ok(())
+    }
+
+    pub fn sync_token_price(&mut self, token: Address, price: U256, decimals: U256) -> Result<()> {
+        let caller = env::sender();
+        require_authorized(
+            caller == self.oracle_manager.get() || caller == self.owner.get(),
+            "Only oracle manager"
+        )?;
+        require_valid_input(price > U256::from(0), "Price must be positive")?;
+        require_valid_input(decimals <= U256::from(MAX_TOKEN_DECIMALS), "Unsupported token decimals")?;
+        
+        self.token_prices.insert(token, price);
+        self.token_decimals.insert(token, decimals);
+        Ok(())
+    }
+
+    pub fn mark_milestone_completed(&mut self, project_id: U256, milestone_id: U256) -> Result<()> {
+        self.require_authorized_caller()?;
+        
+        let funding_info = self.project_funding.get(project_id);
+        require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
+        require_valid_input(
+            milestone_id.as_usize() < self.project_milestones.get(project_id).len(),
+            "Invalid milestone ID"
+        )?;
+        require_valid_input(
+            !self.milestone_completion.get(project_id).get(milestone_id),
+            "Milestone already completed"
+        )?;
+        
+        // Verified delivery is what unlocks release_milestone_funds
+        self.milestone_completion.get_mut(project_id).insert(milestone_id, true);
+        Ok(())
+    }
+
     pub fn release_milestone_funds(&mut self, project_id: U256, milestone_id: U256) -> Result<()> {
         self.require_authorized_caller()?;
+        self.nonreentrant_guard()?;
         
         let funding_info = self.project_funding.get(project_id);
         require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
@@ -192,6 +352,11 @@ impl ProjectFunding {
             self.get_funding_model(project_id) == FundingModel::MilestoneBased,
             "Not milestone-based project"
         )?;
+        self.require_not_halted(&funding_info)?;
+        require_valid_input(
+            !self.releases_frozen.get(project_id),
+            "Milestone releases frozen by dispute"
+        )?;
         
         // Check milestone exists and is completed
         require_valid_input(
@@ -212,11 +377,20 @@ impl ProjectFunding {
         if let Some(milestone) = milestones.get(milestone_id.as_usize()) {
             let release_amount = milestone.funding_amount;
             
-            // Transfer funds to creator
-            self.transfer_to_creator(funding_info.creator, release_amount)?;
+            // Releases come out of the project's escrow, in the token it was funded with
+            if self.price_normalized.get(project_id) {
+                self.release_normalized_milestone(project_id, funding_info.creator, release_amount)?;
+            } else {
+                let token = funding_info.funding_token;
+                let escrow_amount = self.escrow_balance(project_id, token);
+                require_sufficient_funds(release_amount <= escrow_amount, "Insufficient escrow for milestone")?;
+                self.set_escrow_balance(project_id, token, escrow_amount - release_amount);
+                self.pay_out(token, funding_info.creator, release_amount)?;
+            }
             
             // Mark as released
             self.milestone_releases.get_mut(project_id).insert(milestone_id, true);
+            self.report_milestone_completed(project_id, milestone_id)?;
             
             evm::log(MilestoneCompleted {
                 project_id,
@@ -225,118 +399,520 @@ impl ProjectFunding {
             });
         }
         
+        self.unlock_guard();
         Ok(())
     }
 
-    pub fn process_refunds(&mut self, project_id: U256) -> Result<()> {
-        self.nonreentrant_guard()?;
+    pub fn flag_creator_dispute(&mut self, project_id: U256, reason_uri: String) -> Result<bool> {
+        let backer = env::sender();
+        let funding_info = self.project_funding.get(project_id);
+        require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
+        require_valid_input(!reason_uri.is_empty(), "Reason required")?;
+        
+        let contribution = self.backer_contributions.get(project_id).get(backer);
+        require_authorized(contribution > U256::from(0), "Only backers can flag disputes")?;
+        require_valid_input(
+            !self.dispute_flags.get(project_id).get(backer),
+            "Dispute already flagged"
+        )?;
         
+        self.dispute_flags.get_mut(project_id).insert(backer, true);
+        let flagged_total = self.dispute_flagged_amount.get(project_id) + contribution;
+        self.dispute_flagged_amount.insert(project_id, flagged_total);
+        
+        // Freeze remaining releases once flagging backers hold more than the threshold
+        let threshold = funding_info.raised * self.dispute_threshold_bps.get() / U256::from(10000);
+        if flagged_total > threshold {
+            self.releases_frozen.insert(project_id, true);
+        }
+        let frozen = self.releases_frozen.get(project_id);
+        
+        evm::log(CreatorDisputeFlagged {
+            project_id,
+            backer,
+            contribution,
+            flagged_total,
+            reason_uri,
+            releases_frozen: frozen,
+        });
+        
+        Ok(frozen)
+    }
+
+    pub fn resolve_creator_dispute(&mut self, project_id: U256) -> Result<()> {
+        // Admin or governance (as an authorized caller) review lifts the freeze
+        self.require_authorized_caller()?;
+        
+        let flagged_total = self.dispute_flagged_amount.get(project_id);
+        require_valid_input(flagged_total > U256::from(0), "No dispute to resolve")?;
+        
+        let backers = self.project_backers.get(project_id);
+        for i in 0..backers.len() {
+            if let Some(backer) = backers.get(i) {
+                self.dispute_flags.get_mut(project_id).insert(backer, false);
+            }
+        }
+        self.dispute_flagged_amount.insert(project_id, U256::from(0));
+        self.releases_frozen.insert(project_id, false);
+        
+        evm::log(CreatorDisputeResolved {
+            project_id,
+            resolver: env::sender(),
+            flagged_total,
+        });
+        
+        Ok(())
+    }
+
+    pub fn adjust_funding_target(&mut self, project_id: U256, new_target: U256) -> Result<()> {
+        self.require_authorized_caller()?;
+        
+        let mut funding_info = self.project_funding.get(project_id);
+        require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
+        require_valid_input(funding_info.raised == U256::from(0), "Project already has backers")?;
+        require_valid_input(new_target > U256::from(0), "Invalid target")?;
+        
+        if self.get_funding_model(project_id) == FundingModel::MilestoneBased {
+            let milestones = self.project_milestones.get(project_id);
+            let mut milestone_total = U256::from(0);
+            for i in 0..milestones.len() {
+                if let Some(milestone) = milestones.get(i) {
+                    milestone_total += milestone.funding_amount;
+                }
+            }
+            require_valid_input(milestone_total == new_target, "Milestone total must match target")?;
+        }
+        
+        funding_info.target = new_target;
+        self.project_funding.insert(project_id, funding_info);
+        Ok(())
+    }
+
+    pub fn set_milestone_grace_period(&mut self, project_id: U256, grace_period: U256) -> Result<()> {
         let funding_info = self.project_funding.get(project_id);
         require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
+        require_authorized(env::sender() == funding_info.creator, "Only project creator")?;
+        require_valid_input(
+            grace_period <= self.max_milestone_grace.get(),
+            "Grace period exceeds maximum"
+        )?;
         
-        let funding_model = self.get_funding_model(project_id);
-        let current_time = U256::from(block::timestamp());
+        self.milestone_grace_periods.insert(project_id, grace_period);
+        Ok(())
+    }
+
+    pub fn reorder_milestones(&mut self, project_id: U256, new_order: Vec<U256>) -> Result<()> {
+        let funding_info = self.project_funding.get(project_id);
+        require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
+        require_authorized(env::sender() == funding_info.creator, "Only project creator")?;
+        require_valid_input(funding_info.raised == U256::from(0), "Funding already started")?;
         
-        // Check if refunds are allowed
-        let refund_eligible = match funding_model {
-            FundingModel::AllOrNothing => {
-                funding_info.status == 2 || // Failed
-                (current_time > funding_info.deadline && funding_info.raised < funding_info.target)
-            },
-            FundingModel::MilestoneBased => {
-                funding_info.status == 3 // Cancelled
-            },
-            _ => false,
-        };
+        let current = self.get_project_milestones(project_id);
+        require_valid_input(new_order.len() == current.len(), "Order length mismatch")?;
         
-        require_valid_input(refund_eligible, "Refunds not available")?;
+        // new_order[i] names the current index of the milestone that moves to position i
+        let mut seen = vec![false; current.len()];
+        for index in &new_order {
+            let position = index.as_usize();
+            require_valid_input(position < current.len(), "Invalid milestone ID")?;
+            require_valid_input(!seen[position], "Duplicate milestone index")?;
+            seen[position] = true;
+        }
+        
+        let mut milestone_storage = self.project_milestones.get_mut(project_id);
+        milestone_storage.erase();
+        for (position, index) in new_order.iter().enumerate() {
+            let mut milestone = current[index.as_usize()].clone();
+            milestone.id = U256::from(position); // ids track storage position
+            milestone_storage.push(milestone);
+        }
+        
+        Ok(())
+    }
+
+    pub fn check_milestone_deadline(&self, project_id: U256, milestone_id: U256) -> Result<bool> {
+        let milestones = self.project_milestones.get(project_id);
         require_valid_input(
-            current_time <= funding_info.deadline + self.refund_period.get(),
-            "Refund period expired"
+            milestone_id.as_usize() < milestones.len(),
+            "Invalid milestone ID"
         )?;
         
+        let milestone = milestones.get(milestone_id.as_usize()).unwrap();
+        let completed = milestone.is_completed || self.milestone_completion.get(project_id).get(milestone_id);
+        if completed {
+            return Ok(false);
+        }
+        
+        // Delinquent only once the grace window after the deadline has also passed
+        let grace_period = self.milestone_grace_periods.get(project_id);
+        Ok(U256::from(env::timestamp()) > milestone.deadline + grace_period)
+    }
+
+    pub fn process_refunds(&mut self, project_id: U256) -> Result<()> {
+        self.nonreentrant_guard()?;
+        
+        let funding_info = self.project_funding.get(project_id);
+        require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
+        
+        self.require_refund_eligible(project_id, &funding_info)?;
+        
         // Process refunds for all backers
         let backers = self.project_backers.get(project_id);
-        let escrow_amount = self.project_escrow.get(project_id);
-        let total_raised = funding_info.raised;
+        let token = funding_info.funding_token;
+        let mut escrow_amount = self.backer_escrow(project_id, token);
         
         for i in 0..backers.len() {
             if let Some(backer) = backers.get(i) {
                 let contribution = self.backer_contributions.get(project_id).get(backer);
-                if contribution > U256::from(0) {
+                if contribution > U256::from(0) && self.price_normalized.get(project_id) {
+                    // Mixed-token contributions are returned in their original tokens
+                    self.backer_contributions.get_mut(project_id).insert(backer, U256::from(0));
+                    self.refund_normalized_contribution(project_id, backer, true)?;
+                } else if contribution > U256::from(0) {
                     // Calculate refund amount proportionally
-                    let refund_amount = if total_raised > U256::from(0) {
-                        (contribution * escrow_amount) / total_raised
-                    } else {
-                        contribution
-                    };
-                    
-                    // Transfer refund
-                    if refund_amount > U256::from(0) {
-                        call::transfer_eth(backer, refund_amount)?;
-                    }
+                    let refund_amount = self.pro_rata_refund(project_id, &funding_info, contribution, escrow_amount);
+                    escrow_amount -= refund_amount;
                     
-                    // Clear contribution
+                    // Clear contribution before the transfer
                     self.backer_contributions.get_mut(project_id).insert(backer, U256::from(0));
+                    
+                    // A backer that rejects a native transfer is credited instead of blocking the sweep
+                    if refund_amount > U256::from(0) && token.is_zero() {
+                        self.refund_or_credit(project_id, backer, refund_amount);
+                    } else if refund_amount > U256::from(0) {
+                        self.push_token_payment(token, backer, refund_amount)?;
+                    }
                 }
             }
         }
         
+        self.return_matched_to_treasury(project_id)?;
+        
         // Clear escrow
-        self.project_escrow.insert(project_id, U256::from(0));
+        self.set_escrow_balance(project_id, token, U256::from(0));
         
         // Update project status
         let mut updated_funding = funding_info;
         updated_funding.status = 2; // Failed/Refunded
         self.project_funding.insert(project_id, updated_funding);
         
+        // Lets the creator relist on the platform
+        self.notify_refunds_processed(project_id)?;
+        
         self.unlock_guard();
         Ok(())
     }
 
-    pub fn finalize_successful_project(&mut self, project_id: U256) -> Result<()> {
-        self.require_authorized_caller()?;
+    pub fn claim_refund(&mut self, project_id: U256) -> Result<U256> {
+        self.require_claims_open()?;
+        self.nonreentrant_guard()?;
         
+        let backer = env::sender();
         let funding_info = self.project_funding.get(project_id);
         require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
-        require_valid_input(funding_info.status == 1, "Project not successful")?;
+        self.require_refund_eligible(project_id, &funding_info)?;
         
-        let funding_model = self.get_funding_model(project_id);
-        let escrow_amount = self.project_escrow.get(project_id);
+        let contribution = self.backer_contributions.get(project_id).get(backer);
+        require_valid_input(contribution > U256::from(0), "No contribution to refund")?;
         
-        match funding_model {
-            FundingModel::AllOrNothing | FundingModel::FlexibleFunding => {
-                // Release all funds to creator minus platform fee
-                let platform_fee = (escrow_amount * self.platform_fee_bps.get()) / U256::from(10000);
-                let creator_amount = escrow_amount - platform_fee;
-                
-                self.transfer_to_creator(funding_info.creator, creator_amount)?;
-                self.platform_treasury.set(self.platform_treasury.get() + platform_fee);
-                
-                // Clear escrow
-                self.project_escrow.insert(project_id, U256::from(0));
-            },
-            FundingModel::MilestoneBased => {
-                // Funds released per milestone, no action needed here
-            }
+        if self.price_normalized.get(project_id) {
+            // Returned in native wei; token contributions go back in their own raw units
+            self.backer_contributions.get_mut(project_id).insert(backer, U256::from(0));
+            let native_refund = self.refund_normalized_contribution(project_id, backer, false)?;
+            self.unlock_guard();
+            return Ok(native_refund);
         }
         
-        Ok(())
+        let token = funding_info.funding_token;
+        let escrow_amount = self.escrow_balance(project_id, token);
+        let refund_amount = self.pro_rata_refund(
+            project_id, &funding_info, contribution, self.backer_escrow(project_id, token)
+        );
+        
+        // Clear state before the external transfer
+        self.backer_contributions.get_mut(project_id).insert(backer, U256::from(0));
+        self.set_escrow_balance(project_id, token, escrow_amount - refund_amount);
+        
+        if refund_amount > U256::from(0) {
+            self.pay_out(token, backer, refund_amount)?;
+        }
+        
+        self.unlock_guard();
+        Ok(refund_amount)
     }
 
-    // View functions
-    pub fn get_funding_stats(&self, project_id: U256) -> Result<FundingInfo> {
+    #[payable]
+    pub fn add_matched_funds(&mut self, project_id: U256) -> Result<U256> {
+        let caller = env::sender();
+        require_authorized(
+            !caller.is_zero() && caller == self.governance_contract.get(),
+            "Only governance"
+        )?;
+        
         let funding_info = self.project_funding.get(project_id);
         require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
-        Ok(funding_info)
-    }
-
-    pub fn get_backer_contributions(&self, project_id: U256, backer: Address) -> U256 {
-        self.backer_contributions.get(project_id).get(backer)
+        require_valid_input(funding_info.status == 0, "Project not active")?;
+        require_valid_input(funding_info.funding_token.is_zero(), "Only native projects can be matched")?;
+        require_valid_input(env::value() > U256::from(0), "Match amount must be positive")?;
+        
+        // Matched funds sit in escrow alongside contributions but are tracked apart from them
+        let total_matched = self.matched_funds.get(project_id) + env::value();
+        self.matched_funds.insert(project_id, total_matched);
+        self.project_escrow.insert(project_id, self.project_escrow.get(project_id) + env::value());
+        
+        evm::log(MatchedFundsAdded {
+            project_id,
+            amount: env::value(),
+            total_matched,
+        });
+        
+        Ok(total_matched)
     }
 
-    pub fn get_project_backers(&self, project_id: U256) -> Vec<Address> {
-        let backers = self.project_backers.get(project_id);
+    pub fn return_matched_funds(&mut self, project_id: U256) -> Result<U256> {
+        self.nonreentrant_guard()?;
+        
+        let funding_info = self.project_funding.get(project_id);
+        require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
+        self.require_refund_eligible(project_id, &funding_info)?;
+        
+        require_valid_input(self.matched_funds.get(project_id) > U256::from(0), "No matched funds")?;
+        let returned = self.return_matched_to_treasury(project_id)?;
+        
+        self.unlock_guard();
+        Ok(returned)
+    }
+
+    pub fn set_validation_reward_reserve(&mut self, project_id: U256, reserve_bps: U256) -> Result<()> {
+        let funding_info = self.project_funding.get(project_id);
+        require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
+        let caller = env::sender();
+        require_authorized(
+            caller == funding_info.creator || caller == self.platform_contract.get() || caller == self.owner.get(),
+            "Only project creator"
+        )?;
+        require_valid_input(funding_info.raised == U256::from(0), "Funding already started")?;
+        require_valid_input(
+            reserve_bps <= U256::from(MAX_VALIDATION_RESERVE_BPS),
+            "Validation reserve too high"
+        )?;
+        
+        self.validation_reserve_bps.insert(project_id, reserve_bps);
+        Ok(())
+    }
+
+    pub fn withdraw_contribution(&mut self, project_id: U256) -> Result<U256> {
+        self.nonreentrant_guard()?;
+        
+        let backer = env::sender();
+        let mut funding_info = self.project_funding.get(project_id);
+        require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
+        self.require_not_halted(&funding_info)?;
+        require_valid_input(funding_info.status == 0, "Project not active")?;
+        require_valid_input(
+            U256::from(env::timestamp()) <= funding_info.deadline,
+            "Funding deadline passed"
+        )?;
+        require_valid_input(
+            funding_info.funding_token.is_zero() && !self.price_normalized.get(project_id),
+            "Only native contributions can be withdrawn"
+        )?;
+        
+        let contribution = self.backer_contributions.get(project_id).get(backer);
+        require_valid_input(contribution > U256::from(0), "No contribution to withdraw")?;
+        
+        // A voluntary withdrawal pays the refund fee into the treasury; matched funds are never withdrawable
+        let escrow_amount = self.project_escrow.get(project_id);
+        let withdrawn = core::cmp::min(contribution, self.backer_escrow(project_id, Address::ZERO));
+        let (fee, net_amount) = split_fee(withdrawn, self.refund_fee_bps.get());
+        
+        // Clear state before the external transfer
+        self.backer_contributions.get_mut(project_id).insert(backer, U256::from(0));
+        self.remove_project_backer(project_id, backer);
+        self.clear_dispute_flag(project_id, backer, contribution);
+        self.unwind_platform_contribution(backer, contribution);
+        let platform_total = self.backer_platform_totals.get(backer);
+        self.sync_backer_voting_power(backer, platform_total)?;
+        self.project_escrow.insert(project_id, escrow_amount - withdrawn);
+        self.platform_treasury.set(self.platform_treasury.get() + fee);
+        funding_info.raised -= contribution;
+        funding_info.backer_count -= U256::from(1);
+        self.project_funding.insert(project_id, funding_info.clone());
+        
+        self.burn_revenue_nfts(project_id, backer)?;
+        self.update_platform_funding(project_id, funding_info.raised)?;
+        if net_amount > U256::from(0) {
+            env::transfer_eth(backer, net_amount)?;
+        }
+
+        evm::log(ContributionWithdrawn {
+            project_id,
+            backer,
+            amount: net_amount,
+            fee,
+        });
+
+        self.unlock_guard();
+        Ok(net_amount)
+    }
+
+    pub fn withdraw_refund_credit(&mut self) -> Result<U256> {
+        self.nonreentrant_guard()?;
+        
+        let backer = env::sender();
+        let credit = self.refund_credits.get(backer);
+        require_valid_input(credit > U256::from(0), "No refund credit")?;
+        
+        // Clear state before the external transfer
+        self.refund_credits.insert(backer, U256::from(0));
+        env::transfer_eth(backer, credit)?;
+        
+        self.unlock_guard();
+        Ok(credit)
+    }
+
+    pub fn finalize_successful_project(&mut self, project_id: U256) -> Result<()> {
+        self.require_authorized_caller()?;
+        self.nonreentrant_guard()?;
+        
+        let funding_info = self.project_funding.get(project_id);
+        require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
+        require_valid_input(funding_info.status == 1, "Project not successful")?;
+        
+        let funding_model = self.get_funding_model(project_id);
+        let escrow_amount = self.escrow_balance(project_id, funding_info.funding_token);
+        
+        match funding_model {
+            FundingModel::AllOrNothing | FundingModel::FlexibleFunding => {
+                // Release all funds to creator minus platform fee
+                let token = funding_info.funding_token;
+                if token.is_zero() {
+                    let (platform_fee, creator_amount, dust) = self.split_platform_fee(escrow_amount);
+                    self.accumulated_dust.set(self.accumulated_dust.get() + dust);
+                    let (platform_fee, creator_amount) =
+                        self.apply_fee_rebate(project_id, funding_info.creator, platform_fee, creator_amount);
+                    // The project's own validation reserve comes out of the creator's payout,
+                    // but only when there is a validator contract to receive it
+                    let reserve = if self.validator_contract.get().is_zero() {
+                        U256::from(0)
+                    } else {
+                        core::cmp::min(
+                            mul_bps(escrow_amount, self.validation_reserve_bps.get(project_id)),
+                            creator_amount
+                        )
+                    };
+                    let creator_amount = creator_amount - self.pay_validation_reserve(project_id, reserve);
+                    let (cultural_share, treasury_share) = split_fee(platform_fee, self.cultural_fund_auto_bps.get());
+                    
+                    self.transfer_to_creator(funding_info.creator, creator_amount)?;
+                    self.platform_treasury.set(self.platform_treasury.get() + treasury_share);
+                    self.forward_to_cultural_fund(project_id, cultural_share)?;
+                } else {
+                    // Token-denominated escrow is paid out, and its fee kept, in that token
+                    let (platform_fee, creator_amount) = split_fee(escrow_amount, self.fee_bps_for(token));
+                    let (platform_fee, creator_amount) =
+                        self.apply_fee_rebate(project_id, funding_info.creator, platform_fee, creator_amount);
+                    
+                    self.token_treasury.insert(token, self.token_treasury.get(token) + platform_fee);
+                    self.push_token_payment(token, funding_info.creator, creator_amount)?;
+                }
+                
+                // Clear escrow
+                self.set_escrow_balance(project_id, funding_info.funding_token, U256::from(0));
+                
+                if self.price_normalized.get(project_id) {
+                    self.release_token_escrow(project_id, funding_info.creator)?;
+                }
+            },
+            FundingModel::MilestoneBased => {
+                // Funds released per milestone, no action needed here
+            }
+        }
+        
+        self.unlock_guard();
+        Ok(())
+    }
+
+    // View functions
+    pub fn get_funding_stats(&self, project_id: U256) -> Result<FundingInfo> {
+        let funding_info = self.project_funding.get(project_id);
+        require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
+        Ok(funding_info)
+    }
+
+    pub fn get_dispute_status(&self, project_id: U256) -> (U256, bool) {
+        (self.dispute_flagged_amount.get(project_id), self.releases_frozen.get(project_id))
+    }
+
+    pub fn has_flagged_dispute(&self, project_id: U256, backer: Address) -> bool {
+        self.dispute_flags.get(project_id).get(backer)
+    }
+
+    pub fn get_backer_revenue_nfts(&self, project_id: U256, backer: Address) -> Vec<U256> {
+        let tokens = self.backer_nft_tokens.get(project_id);
+        let tokens = tokens.get(backer);
+        let mut result = Vec::new();
+        for i in 0..tokens.len() {
+            if let Some(token_id) = tokens.get(i) {
+                result.push(token_id);
+            }
+        }
+        result
+    }
+
+    pub fn get_backer_contributions(&self, project_id: U256, backer: Address) -> U256 {
+        self.backer_contributions.get(project_id).get(backer)
+    }
+
+    pub fn preview_platform_fee(&self, amount: U256) -> (U256, U256) {
+        self.capped_platform_fee(amount)
+    }
+
+    pub fn get_token_fee_bps(&self, token: Address) -> U256 {
+        self.fee_bps_for(token)
+    }
+
+    pub fn get_token_treasury(&self, token: Address) -> U256 {
+        self.token_treasury.get(token)
+    }
+
+    pub fn get_validation_reward_reserve(&self, project_id: U256) -> (U256, U256) {
+        (self.validation_reserve_bps.get(project_id), self.validation_reserve_paid.get(project_id))
+    }
+
+    pub fn get_fee_rebate(&self) -> (U256, U256) {
+        (self.fee_rebate_score_threshold.get(), self.fee_rebate_bps.get())
+    }
+
+    pub fn get_cultural_fund_auto_contribution(&self) -> (U256, U256) {
+        (self.cultural_fund_auto_bps.get(), self.cultural_fund_auto_contributed.get())
+    }
+
+    pub fn get_emergency_withdrawn(&self, project_id: U256) -> U256 {
+        self.emergency_withdrawn.get(project_id)
+    }
+
+    pub fn get_emergency_claim(&self, project_id: U256, backer: Address) -> U256 {
+        let funding_info = self.project_funding.get(project_id);
+        if funding_info.status != 4 || funding_info.raised == U256::from(0) {
+            return U256::from(0);
+        }
+        
+        // Each backer is owed their pro-rata share of what was withdrawn
+        let contribution = self.backer_contributions.get(project_id).get(backer);
+        contribution * self.emergency_withdrawn.get(project_id) / funding_info.raised
+    }
+
+    pub fn get_refund_fee_bps(&self) -> U256 {
+        self.refund_fee_bps.get()
+    }
+
+    pub fn get_refund_credit(&self, backer: Address) -> U256 {
+        self.refund_credits.get(backer)
+    }
+
+    pub fn get_project_backers(&self, project_id: U256) -> Vec<Address> {
+        let backers = self.project_backers.get(project_id);
         let mut result = Vec::new();
         for i in 0..backers.len() {
             if let Some(backer) = backers.get(i) {
@@ -346,6 +922,61 @@ impl ProjectFunding {
         result
     }
 
+    pub fn get_backer_platform_total(&self, backer: Address) -> U256 {
+        self.backer_platform_totals.get(backer)
+    }
+
+    pub fn get_top_platform_backers(&self, n: U256) -> Vec<(Address, U256)> {
+        let limit = core::cmp::min(n.as_usize(), self.top_platform_backers.len());
+        let mut top = Vec::with_capacity(limit);
+        for i in 0..limit {
+            if let Some(backer) = self.top_platform_backers.get(i) {
+                top.push((backer, self.backer_platform_totals.get(backer)));
+            }
+        }
+        top
+    }
+
+    pub fn get_normalized_value(&self, token: Address, amount: U256) -> Result<U256> {
+        let price = self.token_prices.get(token);
+        require_valid_input(price > U256::from(0), "No price feed for token")?;
+        Ok(normalize_amount(amount, price, self.token_decimals.get(token)))
+    }
+
+    pub fn get_project_accepted_tokens(&self, project_id: U256) -> Vec<Address> {
+        let tokens = self.project_accepted_tokens.get(project_id);
+        let mut result = Vec::new();
+        for i in 0..tokens.len() {
+            if let Some(token) = tokens.get(i) {
+                result.push(token);
+            }
+        }
+        result
+    }
+
+    pub fn get_matched_funds(&self, project_id: U256) -> U256 {
+        self.matched_funds.get(project_id)
+    }
+
+    pub fn get_token_escrow(&self, project_id: U256, token: Address) -> U256 {
+        if token.is_zero() {
+            self.project_escrow.get(project_id)
+        } else {
+            self.token_escrow.get(project_id).get(token)
+        }
+    }
+
+    pub fn get_backer_projects(&self, backer: Address) -> Vec<U256> {
+        let projects = self.backer_projects.get(backer);
+        let mut result = Vec::new();
+        for i in 0..projects.len() {
+            if let Some(project_id) = projects.get(i) {
+                result.push(project_id);
+            }
+        }
+        result
+    }
+
     pub fn get_project_milestones(&self, project_id: U256) -> Vec<Milestone> {
         let milestones = self.project_milestones.get(project_id);
         let mut result = Vec::new();
@@ -386,6 +1017,118 @@ impl ProjectFunding {
         Ok(())
     }
 
+    pub fn set_refund_fee_bps(&mut self, fee_bps: U256) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(fee_bps <= U256::from(1000), "Fee too high")?; // Max 10%
+        self.refund_fee_bps.set(fee_bps);
+        Ok(())
+    }
+
+    pub fn set_validator_contract(&mut self, validator: Address) -> Result<()> {
+        self.require_owner()?;
+        self.validator_contract.set(validator);
+        Ok(())
+    }
+
+    pub fn set_governance_contract(&mut self, governance: Address) -> Result<()> {
+        self.require_owner()?;
+        self.governance_contract.set(governance);
+        Ok(())
+    }
+
+    pub fn set_cultural_fund_auto_bps(&mut self, share_bps: U256) -> Result<()> {
+        let caller = env::sender();
+        require_authorized(
+            !caller.is_zero() && caller == self.governance_contract.get(),
+            "Only governance"
+        )?;
+        require_valid_input(share_bps <= U256::from(10000), "Invalid cultural fund share")?;
+        self.cultural_fund_auto_bps.set(share_bps);
+        Ok(())
+    }
+
+    pub fn set_funding_paused(&mut self, paused: bool) -> Result<()> {
+        self.require_owner()?;
+        self.funding_paused.set(paused);
+        
+        evm::log(FundingPauseUpdated {
+            paused,
+            timestamp: U256::from(env::timestamp()),
+        });
+        
+        Ok(())
+    }
+
+    pub fn is_funding_paused(&self) -> bool {
+        self.funding_paused.get()
+    }
+
+    pub fn set_claims_allowed_while_paused(&mut self, allowed: bool) -> Result<()> {
+        self.require_owner()?;
+        self.claims_blocked_while_paused.set(!allowed);
+        Ok(())
+    }
+
+    pub fn claims_allowed_while_paused(&self) -> bool {
+        !self.claims_blocked_while_paused.get()
+    }
+
+    pub fn set_token_fee_bps(&mut self, token: Address, fee_bps: U256) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(fee_bps <= U256::from(1000), "Fee too high")?; // Max 10%
+        self.fee_bps_by_token.insert(token, fee_bps);
+        self.token_fee_override.insert(token, true);
+        Ok(())
+    }
+
+    pub fn clear_token_fee_bps(&mut self, token: Address) -> Result<()> {
+        self.require_owner()?;
+        self.fee_bps_by_token.insert(token, U256::from(0));
+        self.token_fee_override.insert(token, false);
+        Ok(())
+    }
+
+    pub fn set_max_platform_fee_absolute(&mut self, max_fee: U256) -> Result<()> {
+        self.require_owner()?;
+        self.max_platform_fee_absolute.set(max_fee);
+        Ok(())
+    }
+
+    pub fn set_fee_rebate(&mut self, score_threshold: U256, rebate_bps: U256) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(score_threshold <= U256::from(100), "Invalid score threshold")?;
+        require_valid_input(rebate_bps <= U256::from(10000), "Invalid rebate share")?;
+        self.fee_rebate_score_threshold.set(score_threshold);
+        self.fee_rebate_bps.set(rebate_bps);
+        Ok(())
+    }
+
+    pub fn sync_validation_score(&mut self, project_id: U256, score: U256) -> Result<()> {
+        self.require_authorized_caller()?;
+        require_valid_input(score <= U256::from(100), "Score must be 0-100")?;
+        self.project_validation_scores.insert(project_id, score);
+        Ok(())
+    }
+
+    pub fn set_dispute_threshold(&mut self, threshold_bps: U256) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(threshold_bps < U256::from(10000), "Invalid dispute threshold")?;
+        self.dispute_threshold_bps.set(threshold_bps);
+        Ok(())
+    }
+
+    pub fn set_oracle_manager(&mut self, oracle_manager: Address) -> Result<()> {
+        self.require_owner()?;
+        self.oracle_manager.set(oracle_manager);
+        Ok(())
+    }
+
+    pub fn set_max_milestone_grace(&mut self, max_grace: U256) -> Result<()> {
+        self.require_owner()?;
+        self.max_milestone_grace.set(max_grace);
+        Ok(())
+    }
+
     pub fn set_platform_fee(&mut self, new_fee_bps: U256) -> Result<()> {
         self.require_owner()?;
         require_valid_input(new_fee_bps <= U256::from(1000), "Fee too high"); // Max 10%
@@ -395,47 +1138,639 @@ impl ProjectFunding {
 
     pub fn emergency_withdraw(&mut self, project_id: U256) -> Result<()> {
         self.require_owner()?;
-        let escrow_amount = self.project_escrow.get(project_id);
+        
+        let funding_info = self.project_funding.get(project_id);
+        require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
+        self.require_not_halted(&funding_info)?;
+        
+        self.nonreentrant_guard()?;
+        let token = funding_info.funding_token;
+        let escrow_amount = self.escrow_balance(project_id, token);
+        
+        // Halt the project and keep backer contributions as claims until governance makes them whole
+        let mut updated_funding = funding_info;
+        updated_funding.status = 4; // EmergencyHalted
+        self.project_funding.insert(project_id, updated_funding);
+        let withdrawn = self.emergency_withdrawn.get(project_id) + escrow_amount;
+        self.emergency_withdrawn.insert(project_id, withdrawn);
+        
         if escrow_amount > U256::from(0) {
-            call::transfer_eth(self.owner.get(), escrow_amount)?;
-            self.project_escrow.insert(project_id, U256::from(0));
+            self.set_escrow_balance(project_id, token, U256::from(0));
+            self.pay_out(token, self.owner.get(), escrow_amount)?;
             
             evm::log(EmergencyWithdrawal {
-                token: Address::ZERO, // ETH
+                token, // Address::ZERO for ETH
                 recipient: self.owner.get(),
                 amount: escrow_amount,
             });
         }
+        
+        evm::log(ProjectEmergencyHalted {
+            project_id,
+            amount_withdrawn: escrow_amount,
+            outstanding_claims: withdrawn,
+            timestamp: U256::from(env::timestamp()),
+        });
+        
+        self.unlock_guard();
         Ok(())
     }
-}
 
-// Internal helper functions
-impl ProjectFunding {
-    fn require_owner(&self) -> Result<()> {
-        require_authorized(msg::sender() == self.owner.get(), "Only owner")
+    pub fn set_dust_recipient(&mut self, recipient: Address) -> Result<()> {
+        self.require_owner()?;
+        self.dust_recipient.set(recipient);
+        Ok(())
     }
 
-    fn require_authorized_caller(&self) -> Result<()> {
-        let caller = msg::sender();
-        require_authorized(
-            caller == self.platform_contract.get() || 
-            caller == self.owner.get() ||
-            self.authorized_callers.get(caller),
-            "Not authorized caller"
-        )
+    pub fn sweep_dust(&mut self) -> Result<U256> {
+        self.require_owner()?;
+        let amount = self.accumulated_dust.get();
+        require_valid_input(amount > U256::from(0), "No dust to sweep")?;
+        
+        self.nonreentrant_guard()?;
+        self.accumulated_dust.set(U256::from(0));
+        let recipient = self.dust_recipient.get();
+        if recipient.is_zero() {
+            // Default destination is the platform treasury
+            self.platform_treasury.set(self.platform_treasury.get() + amount);
+        } else {
+            env::transfer_eth(recipient, amount)?;
+        }
+        
+        evm::log(DustSwept { recipient, amount });
+        
+        self.unlock_guard();
+        Ok(amount)
     }
 
-    fn nonreentrant_guard(&mut self) -> Result<()> {
-        require_valid_input(!self.locked.get(), "Reentrant call")?;
-        self.locked.set(true);
-        Ok(())
+    pub fn get_accumulated_dust(&self) -> (Address, U256) {
+        (self.dust_recipient.get(), self.accumulated_dust.get())
     }
 
-    fn unlock_guard(&mut self) {
+    pub fn withdraw_treasury(&mut self, recipient: Address, amount: U256) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(!recipient.is_zero(), "Invalid recipient")?;
+        require_valid_input(amount > U256::from(0), "Amount must be positive")?;
+        
+        let treasury = self.platform_treasury.get();
+        require_sufficient_funds(amount <= treasury, "Insufficient treasury balance")?;
+        
+        self.nonreentrant_guard()?;
+        let remaining = treasury - amount;
+        self.platform_treasury.set(remaining);
+        env::transfer_eth(recipient, amount)?;
+        
+        evm::log(TreasuryWithdrawal {
+            recipient,
+            amount,
+            remaining_treasury: remaining,
+        });
+        
+        self.unlock_guard();
+        Ok(())
+    }
+}
+
+// Internal helper functions
+impl ProjectFunding {
+    fn forward_to_cultural_fund(&mut self, project_id: U256, amount: U256) -> Result<()> {
+        if amount == U256::from(0) {
+            return Ok(());
+        }
+        
+        let governance = self.governance_contract.get();
+        if governance.is_zero() {
+            // Nowhere to forward to yet; the share stays in the platform treasury
+            self.platform_treasury.set(self.platform_treasury.get() + amount);
+            return Ok(());
+        }
+        
+        self.cultural_fund_auto_contributed.set(self.cultural_fund_auto_contributed.get() + amount);
+        let calldata = stylus_sdk::function_selector!("fundCulturalFund").to_vec();
+        env::call_contract(self, amount, governance, &calldata)
+            .map_err(|_| AfroCreateError::TransferFailed("Cultural fund transfer failed".to_string()))?;
+
+        evm::log(CulturalFundAutoContributed {
+            project_id,
+            amount,
+        });
+        
+        Ok(())
+    }
+
+    fn return_matched_to_treasury(&mut self, project_id: U256) -> Result<U256> {
+        // Milestone releases may already have spent part of the match
+        let escrow_amount = self.project_escrow.get(project_id);
+        let matched = core::cmp::min(self.matched_funds.get(project_id), escrow_amount);
+        self.matched_funds.insert(project_id, U256::from(0));
+        if matched == U256::from(0) {
+            return Ok(U256::from(0));
+        }
+        let treasury = self.governance_contract.get();
+        require_valid_input(!treasury.is_zero(), "Treasury not set")?;
+        
+        // Clear state before the external transfer
+        self.project_escrow.insert(project_id, escrow_amount - matched);
+        env::transfer_eth(treasury, matched)?;
+        
+        evm::log(MatchedFundsReturned {
+            project_id,
+            treasury,
+            amount: matched,
+        });
+        
+        Ok(matched)
+    }
+
+    fn pay_validation_reserve(&mut self, project_id: U256, amount: U256) -> U256 {
+        if amount == U256::from(0) {
+            return U256::from(0);
+        }
+        
+        let validator = self.validator_contract.get();
+        let mut calldata = stylus_sdk::function_selector!("fundProjectValidationRewards", U256).to_vec();
+        calldata.extend_from_slice(&project_id.to_be_bytes::<32>());
+        
+        // Projects approved outside the validator (auto-approval, admin review) have no result
+        // to fund, so the reserve goes back to the creator instead of blocking the payout
+        if env::call_contract(self, amount, validator, &calldata).is_err() {
+            evm::log(ValidationRewardReserveReturned {
+                project_id,
+                amount,
+            });
+            return U256::from(0);
+        }
+        
+        self.validation_reserve_paid.insert(project_id, amount);
+        evm::log(ValidationRewardReservePaid {
+            project_id,
+            amount,
+        });
+        
+        amount
+    }
+
+    fn require_owner(&self) -> Result<()> {
+        require_authorized(env::sender() == self.owner.get(), "Only owner")
+    }
+
+    fn require_funding_not_paused(&self) -> Result<()> {
+        require_valid_input(!self.funding_paused.get(), "Funding is paused")
+    }
+
+    fn require_claims_open(&self) -> Result<()> {
+        // Backers keep access to their refunds during a pause unless the owner opts out
+        require_valid_input(
+            !(self.funding_paused.get() && self.claims_blocked_while_paused.get()),
+            "Claims are paused"
+        )
+    }
+
+    fn require_not_halted(&self, funding_info: &FundingInfo) -> Result<()> {
+        require_valid_input(funding_info.status != 4, "Project emergency halted")
+    }
+
+    fn require_authorized_caller(&self) -> Result<()> {
+        let caller = env::sender();
+        require_authorized(
+            caller == self.platform_contract.get() || 
+            caller == self.owner.get() ||
+            self.authorized_callers.get(caller),
+            "Not authorized caller"
+        )
+    }
+
+    fn nonreentrant_guard(&mut self) -> Result<()> {
+        require_valid_input(!self.locked.get(), "Reentrant call")?;
+        self.locked.set(true);
+        Ok(())
+    }
+
+    fn unlock_guard(&mut self) {
         self.locked.set(false);
     }
 
+    fn record_contribution(
+        &mut self,
+        project_id: U256,
+        backer: Address,
+        contribution: U256,
+        backer_ens_name: String,
+    ) -> Result<FundingReceipt> {
+        require_sufficient_funds(
+            contribution >= self.min_contribution.get(),
+            "Contribution too small"
+        )?;
+        
+        // Get project info from platform contract
+        let funding_info = self.project_funding.get(project_id);
+        require_valid_input(funding_info.target > U256::from(0), "Project not found")?;
+        self.require_not_halted(&funding_info)?;
+        require_valid_input(funding_info.status == 0, "Project not active")?;
+        require_valid_input(
+            U256::from(env::timestamp()) <= funding_info.deadline,
+            "Funding deadline passed"
+        )?;
+        
+        // Update funding info
+        let mut updated_funding = funding_info;
+        updated_funding.raised += contribution;
+        
+        // Track backer contribution
+        let previous_contribution = self.backer_contributions.get(project_id).get(backer);
+        self.backer_contributions.get_mut(project_id).insert(backer, previous_contribution + contribution);
+        
+        // Add to backers list if first contribution
+        if previous_contribution == U256::from(0) {
+            self.project_backers.get_mut(project_id).push(backer);
+            updated_funding.backer_count += U256::from(1);
+        }
+        
+        // Platform-wide running total for the leaderboard
+        let platform_total = self.backer_platform_totals.get(backer);
+        if platform_total == U256::from(0) {
+            self.platform_backers.push(backer);
+            self.total_backers.set(self.total_backers.get() + U256::from(1));
+        }
+        self.backer_platform_totals.insert(backer, platform_total + contribution);
+        self.rerank_platform_backer(backer, false);
+        // Backers vote with what they have put into the platform
+        self.sync_backer_voting_power(backer, platform_total + contribution)?;
+        
+        // Reverse index for portfolio lookups; survives refunds so re-funding never duplicates
+        if !self.backer_project_indexed.get(backer).get(project_id) {
+            self.backer_project_indexed.get_mut(backer).insert(project_id, true);
+            self.backer_projects.get_mut(backer).push(project_id);
+        }
+        
+        // Check if funding target and backer minimum are both reached
+        if self.meets_success_criteria(project_id, &updated_funding) {
+            updated_funding.status = 1; // Successful
+            self.total_projects_funded.set(self.total_projects_funded.get() + U256::from(1));
+        }
+        
+        self.project_funding.insert(project_id, updated_funding.clone());
+        self.total_amount_raised.set(self.total_amount_raised.get() + contribution);
+        
+        // Mint revenue-sharing NFT to backer
+        let (nft_token_id, share_bps) = self.mint_revenue_nft(project_id, backer, contribution, backer_ens_name)?;
+        self.backer_nft_tokens.get_mut(project_id).get_mut(backer).push(nft_token_id);
+        
+        // Update platform contract
+        self.update_platform_funding(project_id, updated_funding.raised)?;
+
+        evm::log(ProjectFunded {
+            project_id,
+            backer,
+            amount: contribution,
+            total_raised: updated_funding.raised,
+        });
+
+        Ok(FundingReceipt {
+            token_id: nft_token_id,
+            contribution,
+            revenue_share_bps: share_bps,
+            total_raised: updated_funding.raised,
+        })
+    }
+
+    fn contribute_native(
+        &mut self,
+        project_id: U256,
+        backer: Address,
+        contribution: U256,
+        backer_ens_name: String,
+    ) -> Result<FundingReceipt> {
+        let credited = if self.price_normalized.get(project_id) {
+            self.escrow_normalized_contribution(project_id, backer, Address::ZERO, contribution)?
+        } else {
+            require_valid_input(
+                self.project_funding.get(project_id).funding_token.is_zero(),
+                "Project not funded in native token"
+            )?;
+            self.add_escrow(project_id, contribution);
+            contribution
+        };
+        
+        self.record_contribution(project_id, backer, credited, backer_ens_name)
+    }
+
+    fn add_escrow(&mut self, project_id: U256, amount: U256) {
+        let current_escrow = self.project_escrow.get(project_id);
+        self.project_escrow.insert(project_id, current_escrow + amount);
+    }
+
+    fn add_token_escrow(&mut self, project_id: U256, token: Address, amount: U256) {
+        let escrowed = self.token_escrow.get(project_id).get(token);
+        self.token_escrow.get_mut(project_id).insert(token, escrowed + amount);
+    }
+
+    fn escrow_balance(&self, project_id: U256, token: Address) -> U256 {
+        // Native ETH and each ERC20 are escrowed separately so one never pays out the other
+        if token.is_zero() {
+            self.project_escrow.get(project_id)
+        } else {
+            self.token_escrow.get(project_id).get(token)
+        }
+    }
+
+    fn set_escrow_balance(&mut self, project_id: U256, token: Address, amount: U256) {
+        if token.is_zero() {
+            self.project_escrow.insert(project_id, amount);
+        } else {
+            self.token_escrow.get_mut(project_id).insert(token, amount);
+        }
+    }
+
+    fn backer_escrow(&self, project_id: U256, token: Address) -> U256 {
+        // Matched funds (native only) go back to the treasury, not to backers
+        let escrow_amount = self.escrow_balance(project_id, token);
+        if token.is_zero() {
+            escrow_amount.saturating_sub(self.matched_funds.get(project_id))
+        } else {
+            escrow_amount
+        }
+    }
+
+    fn pro_rata_refund(
+        &mut self,
+        project_id: U256,
+        funding_info: &FundingInfo,
+        contribution: U256,
+        backer_escrow: U256,
+    ) -> U256 {
+        // A short escrow is shared in proportion to what is still owed, so the order in
+        // which backers claim (or the sweep reaches them) never changes what they get
+        let refunded = self.refunded_contributions.get(project_id);
+        let outstanding = funding_info.raised.saturating_sub(refunded);
+        self.refunded_contributions.insert(project_id, refunded + contribution);
+        
+        if outstanding == U256::from(0) {
+            return core::cmp::min(contribution, backer_escrow);
+        }
+        core::cmp::min(contribution, contribution * backer_escrow / outstanding)
+    }
+
+    fn pay_out(&self, token: Address, recipient: Address, amount: U256) -> Result<()> {
+        if token.is_zero() {
+            env::transfer_eth(recipient, amount)?;
+        } else {
+            self.push_token_payment(token, recipient, amount)?;
+        }
+        Ok(())
+    }
+
+    fn escrow_normalized_contribution(
+        &mut self,
+        project_id: U256,
+        backer: Address,
+        token: Address,
+        amount: U256,
+    ) -> Result<U256> {
+        require_valid_input(
+            self.accepted_funding_tokens.get(project_id).get(token),
+            "Token not accepted for project"
+        )?;
+        let credited = self.get_normalized_value(token, amount)?;
+        
+        // Native ETH stays in the regular escrow; tokens are held per token in raw units
+        if token.is_zero() {
+            self.add_escrow(project_id, amount);
+        } else {
+            self.add_token_escrow(project_id, token, amount);
+        }
+        
+        let previous = self.backer_token_contributions.get(project_id).get(backer).get(token);
+        self.backer_token_contributions
+            .get_mut(project_id)
+            .get_mut(backer)
+            .insert(token, previous + amount);
+        
+        Ok(credited)
+    }
+
+    fn refund_normalized_contribution(&mut self, project_id: U256, backer: Address, sweep: bool) -> Result<U256> {
+        let mut native_refund = U256::from(0);
+        for token in self.get_project_accepted_tokens(project_id) {
+            let raw_amount = self.backer_token_contributions.get(project_id).get(backer).get(token);
+            if raw_amount == U256::from(0) {
+                continue;
+            }
+            
+            // Clear state before the external transfer
+            self.backer_token_contributions.get_mut(project_id).get_mut(backer).insert(token, U256::from(0));
+            if token.is_zero() {
+                let escrow_amount = self.project_escrow.get(project_id);
+                self.project_escrow.insert(project_id, escrow_amount - raw_amount);
+                native_refund = raw_amount;
+                if sweep {
+                    self.refund_or_credit(project_id, backer, raw_amount);
+                } else {
+                    env::transfer_eth(backer, raw_amount)?;
+                }
+            } else {
+                let escrow_amount = self.token_escrow.get(project_id).get(token);
+                self.token_escrow.get_mut(project_id).insert(token, escrow_amount - raw_amount);
+                self.push_token_payment(token, backer, raw_amount)?;
+            }
+        }
+        Ok(native_refund)
+    }
+
+    fn release_normalized_milestone(&mut self, project_id: U256, creator: Address, release_amount: U256) -> Result<()> {
+        // Milestone amounts are in the common denomination, so release the same fraction of
+        // every token's escrow in its own raw units; the last milestone takes whatever is left
+        let unreleased = self.unreleased_milestone_total(project_id);
+        require_valid_input(unreleased > U256::from(0), "No unreleased milestones")?;
+        
+        for token in self.get_project_accepted_tokens(project_id) {
+            let escrow_amount = self.escrow_balance(project_id, token);
+            let payout = core::cmp::min(escrow_amount * release_amount / unreleased, escrow_amount);
+            if payout == U256::from(0) {
+                continue;
+            }
+            self.set_escrow_balance(project_id, token, escrow_amount - payout);
+            self.pay_out(token, creator, payout)?;
+        }
+        Ok(())
+    }
+
+    fn unreleased_milestone_total(&self, project_id: U256) -> U256 {
+        let milestones = self.project_milestones.get(project_id);
+        let releases = self.milestone_releases.get(project_id);
+        let mut total = U256::from(0);
+        for i in 0..milestones.len() {
+            if let Some(milestone) = milestones.get(i) {
+                if !releases.get(U256::from(i)) {
+                    total += milestone.funding_amount;
+                }
+            }
+        }
+        total
+    }
+
+    fn fee_bps_for(&self, token: Address) -> U256 {
+        if self.token_fee_override.get(token) {
+            self.fee_bps_by_token.get(token)
+        } else {
+            self.platform_fee_bps.get()
+        }
+    }
+
+    fn capped_platform_fee(&self, amount: U256) -> (U256, U256) {
+        let (fee, net, dust) = self.split_platform_fee(amount);
+        (fee, net + dust)
+    }
+
+    fn split_platform_fee(&self, amount: U256) -> (U256, U256, U256) {
+        let fee_bps = self.fee_bps_for(Address::ZERO);
+        let (parts, dust) = split_bps_with_dust(
+            amount,
+            &[fee_bps, U256::from(BPS_DENOMINATOR).saturating_sub(fee_bps)],
+        );
+        let (fee, net) = (parts[0], parts[1]);
+        let cap = self.max_platform_fee_absolute.get();
+        
+        // Anything above the cap goes to the creator instead
+        if cap > U256::from(0) && fee > cap {
+            (cap, net + fee - cap, dust)
+        } else {
+            (fee, net, dust)
+        }
+    }
+
+    fn apply_fee_rebate(&self, project_id: U256, creator: Address, fee: U256, net: U256) -> (U256, U256) {
+        // In production, would read the final score from CulturalValidator::get_validation_status
+        let threshold = self.fee_rebate_score_threshold.get();
+        let score = self.project_validation_scores.get(project_id);
+        if threshold == U256::from(0) || score <= threshold {
+            return (fee, net);
+        }
+        
+        let rebate = mul_bps(fee, self.fee_rebate_bps.get());
+        if rebate > U256::from(0) {
+            evm::log(PlatformFeeRebated {
+                project_id,
+                creator,
+                validation_score: score,
+                rebate,
+            });
+        }
+        (fee - rebate, net + rebate)
+    }
+
+    fn refund_or_credit(&mut self, project_id: U256, backer: Address, amount: U256) {
+        if env::transfer_eth(backer, amount).is_err() {
+            let credit = self.refund_credits.get(backer);
+            self.refund_credits.insert(backer, credit + amount);
+            
+            evm::log(RefundCredited {
+                project_id,
+                backer,
+                amount,
+            });
+        }
+    }
+
+    fn release_token_escrow(&mut self, project_id: U256, creator: Address) -> Result<()> {
+        for token in self.get_project_accepted_tokens(project_id) {
+            if token.is_zero() {
+                continue;
+            }
+            let escrow_amount = self.token_escrow.get(project_id).get(token);
+            if escrow_amount == U256::from(0) {
+                continue;
+            }
+            
+            let (platform_fee, creator_amount) = split_fee(escrow_amount, self.fee_bps_for(token));
+            self.token_escrow.get_mut(project_id).insert(token, U256::from(0));
+            self.token_treasury.insert(token, self.token_treasury.get(token) + platform_fee);
+            self.push_token_payment(token, creator, creator_amount)?;
+        }
+        Ok(())
+    }
+
+    fn push_token_payment(&mut self, token: Address, recipient: Address, amount: U256) -> Result<()> {
+        require_valid_input(!recipient.is_zero(), "Invalid recipient")?;
+        require_valid_input(!token.is_zero(), "Invalid token")?;
+        
+        let mut calldata = stylus_sdk::function_selector!("transfer", Address, U256).to_vec();
+        calldata.extend_from_slice(recipient.into_word().as_slice());
+        calldata.extend_from_slice(&amount.to_be_bytes::<32>());
+        self.call_token(token, &calldata)
+    }
+
+    fn pull_token_contribution(&mut self, token: Address, backer: Address, amount: U256) -> Result<()> {
+        require_valid_input(!backer.is_zero(), "Invalid backer")?;
+        require_valid_input(amount > U256::from(0), "Amount must be positive")?;
+        
+        let mut calldata = stylus_sdk::function_selector!("transferFrom", Address, Address, U256).to_vec();
+        calldata.extend_from_slice(backer.into_word().as_slice());
+        calldata.extend_from_slice(env::contract_address().into_word().as_slice());
+        calldata.extend_from_slice(&amount.to_be_bytes::<32>());
+        self.call_token(token, &calldata)
+    }
+
+    fn call_token(&mut self, token: Address, calldata: &[u8]) -> Result<()> {
+        let returned = env::call_contract(self, U256::from(0), token, calldata)
+            .map_err(|_| AfroCreateError::TransferFailed("Token transfer failed".to_string()))?;
+        
+        // Tokens that return nothing are taken at their word; the rest must return true
+        if !returned.is_empty() && returned.iter().all(|byte| *byte == 0) {
+            return Err(AfroCreateError::TransferFailed("Token transfer failed".to_string()));
+        }
+        Ok(())
+    }
+
+    fn meets_success_criteria(&self, project_id: U256, funding_info: &FundingInfo) -> bool {
+        // A single whale reaching the target isn't enough when a backer minimum is set
+        funding_info.raised >= funding_info.target
+            && funding_info.backer_count >= self.min_backers.get(project_id)
+    }
+
+    fn remove_project_backer(&mut self, project_id: U256, backer: Address) {
+        // Rebuild the list so a later contribution re-adds the backer exactly once
+        let backers = self.project_backers.get(project_id);
+        let mut remaining = Vec::new();
+        for i in 0..backers.len() {
+            if let Some(existing) = backers.get(i) {
+                if existing != backer {
+                    remaining.push(existing);
+                }
+            }
+        }
+        
+        let mut backers = self.project_backers.get_mut(project_id);
+        backers.erase();
+        for existing in remaining {
+            backers.push(existing);
+        }
+    }
+
+    fn require_refund_eligible(&self, project_id: U256, funding_info: &FundingInfo) -> Result<()> {
+        // Halted escrow is gone; backers are made whole through governance instead
+        self.require_not_halted(funding_info)?;
+        let current_time = U256::from(env::timestamp());
+        
+        // Check if refunds are allowed
+        let refund_eligible = match self.get_funding_model(project_id) {
+            FundingModel::AllOrNothing => {
+                funding_info.status == 2 || // Failed
+                (current_time > funding_info.deadline && !self.meets_success_criteria(project_id, funding_info))
+            },
+            FundingModel::MilestoneBased => {
+                funding_info.status == 3 // Cancelled
+            },
+            _ => false,
+        };
+        
+        require_valid_input(refund_eligible, "Refunds not available")?;
+        require_valid_input(
+            current_time <= funding_info.deadline + self.refund_period.get(),
+            "Refund period expired"
+        )
+    }
+
     fn get_funding_model(&self, project_id: U256) -> FundingModel {
         let model_u8 = self.funding_models.get(project_id).as_u8();
         match model_u8 {
@@ -452,7 +1787,7 @@ impl ProjectFunding {
         backer: Address,
         funding_amount: U256,
         ens_data: String,
-    ) -> Result<U256> {
+    ) -> Result<(U256, U256)> {
         // Call revenue NFT contract to mint
         // This is a simplified version - would use actual contract call in production
         let share_bps = self.calculate_revenue_share(project_id, funding_amount)?;
@@ -468,7 +1803,165 @@ impl ProjectFunding {
             revenue_share_bps: share_bps,
         });
         
-        Ok(token_id)
+        Ok((token_id, share_bps))
+    }
+
+    fn burn_revenue_nfts(&mut self, project_id: U256, backer: Address) -> Result<()> {
+        // A withdrawn contribution gives up its revenue share along with the escrow
+        let token_ids = self.get_backer_revenue_nfts(project_id, backer);
+        self.backer_nft_tokens.get_mut(project_id).get_mut(backer).erase();
+        
+        // The NFT contract releases each token's share and logs the burn
+        let nft = self.revenue_nft_contract.get();
+        for token_id in token_ids {
+            let mut calldata = stylus_sdk::function_selector!("burnRevenueNFT", U256).to_vec();
+            calldata.extend_from_slice(&token_id.to_be_bytes::<32>());
+            env::call_contract(self, U256::from(0), nft, &calldata)
+                .map_err(|_| AfroCreateError::InvalidInput("Revenue NFT burn failed".to_string()))?;
+        }
+        Ok(())
+    }
+
+    fn unwind_platform_contribution(&mut self, backer: Address, amount: U256) {
+        // Reverses what record_contribution added to the platform-wide totals
+        self.total_amount_raised.set(self.total_amount_raised.get().saturating_sub(amount));
+        let remaining = self.backer_platform_totals.get(backer).saturating_sub(amount);
+        self.backer_platform_totals.insert(backer, remaining);
+        self.rerank_platform_backer(backer, true);
+        if remaining > U256::from(0) {
+            return;
+        }
+        
+        // A backer with nothing left on the platform is no longer counted or ranked
+        self.total_backers.set(self.total_backers.get().saturating_sub(U256::from(1)));
+        let mut remaining_backers = Vec::new();
+        for i in 0..self.platform_backers.len() {
+            if let Some(existing) = self.platform_backers.get(i) {
+                if existing != backer {
+                    remaining_backers.push(existing);
+                }
+            }
+        }
+        self.platform_backers.erase();
+        for existing in remaining_backers {
+            self.platform_backers.push(existing);
+        }
+    }
+
+    fn rerank_platform_backer(&mut self, backer: Address, decreased: bool) {
+        let was_full = self.top_platform_backers.len() >= MAX_LEADERBOARD_SIZE;
+        let mut previous = Vec::with_capacity(MAX_LEADERBOARD_SIZE);
+        for i in 0..self.top_platform_backers.len() {
+            if let Some(existing) = self.top_platform_backers.get(i) {
+                previous.push(existing);
+            }
+        }
+        let listed = previous.contains(&backer);
+        if decreased && !listed {
+            // Falling further below the leaderboard changes nothing on it
+            return;
+        }
+        
+        let mut ranked: Vec<Address> = previous.iter().copied().filter(|existing| *existing != backer).collect();
+        let mut candidate = (backer, self.backer_platform_totals.get(backer));
+        if decreased && was_full {
+            // The freed place may now belong to someone who was just below the leaderboard
+            if let Some(outsider) = self.best_unranked_backer(&ranked, backer) {
+                if outsider.1 > candidate.1 {
+                    candidate = outsider;
+                }
+            }
+        }
+        
+        // Equal totals keep their earlier place
+        if candidate.1 > U256::from(0) {
+            let position = ranked.iter()
+                .position(|existing| candidate.1 > self.backer_platform_totals.get(*existing))
+                .unwrap_or(ranked.len());
+            if position < MAX_LEADERBOARD_SIZE {
+                ranked.insert(position, candidate.0);
+                ranked.truncate(MAX_LEADERBOARD_SIZE);
+            }
+        }
+        
+        if ranked != previous {
+            self.top_platform_backers.erase();
+            for existing in ranked {
+                self.top_platform_backers.push(existing);
+            }
+        }
+    }
+
+    fn best_unranked_backer(&self, ranked: &[Address], excluded: Address) -> Option<(Address, U256)> {
+        let mut best: Option<(Address, U256)> = None;
+        for i in 0..self.platform_backers.len() {
+            if let Some(backer) = self.platform_backers.get(i) {
+                if backer == excluded || ranked.contains(&backer) {
+                    continue;
+                }
+                let total = self.backer_platform_totals.get(backer);
+                let better = match best {
+                    Some((_, best_total)) => total > best_total,
+                    None => true,
+                };
+                if better {
+                    best = Some((backer, total));
+                }
+            }
+        }
+        best
+    }
+
+    fn clear_dispute_flag(&mut self, project_id: U256, backer: Address, contribution: U256) {
+        if !self.dispute_flags.get(project_id).get(backer) {
+            return;
+        }
+        self.dispute_flags.get_mut(project_id).insert(backer, false);
+        let flagged_total = self.dispute_flagged_amount.get(project_id).saturating_sub(contribution);
+        self.dispute_flagged_amount.insert(project_id, flagged_total);
+    }
+
+    fn report_milestone_completed(&mut self, project_id: U256, milestone_id: U256) -> Result<()> {
+        let platform = self.platform_contract.get();
+        if platform.is_zero() {
+            return Ok(());
+        }
+        
+        // The platform credits the creator's reputation for the delivery
+        let mut calldata = stylus_sdk::function_selector!("recordMilestoneCompleted", U256, U256).to_vec();
+        calldata.extend_from_slice(&project_id.to_be_bytes::<32>());
+        calldata.extend_from_slice(&milestone_id.to_be_bytes::<32>());
+        env::call_contract(self, U256::from(0), platform, &calldata)
+            .map_err(|_| AfroCreateError::InvalidInput("Platform milestone update failed".to_string()))?;
+        Ok(())
+    }
+
+    fn sync_backer_voting_power(&mut self, backer: Address, power: U256) -> Result<()> {
+        let governance = self.governance_contract.get();
+        if governance.is_zero() {
+            return Ok(());
+        }
+        
+        let mut calldata = stylus_sdk::function_selector!("updateVotingPower", Address, u8, U256).to_vec();
+        calldata.extend_from_slice(backer.into_word().as_slice());
+        calldata.extend_from_slice(&U256::from(VotingRole::Backer as u8).to_be_bytes::<32>());
+        calldata.extend_from_slice(&power.to_be_bytes::<32>());
+        env::call_contract(self, U256::from(0), governance, &calldata)
+            .map_err(|_| AfroCreateError::InvalidInput("Voting power update failed".to_string()))?;
+        Ok(())
+    }
+
+    fn notify_refunds_processed(&mut self, project_id: U256) -> Result<()> {
+        let platform = self.platform_contract.get();
+        if platform.is_zero() {
+            return Ok(());
+        }
+        
+        let mut calldata = stylus_sdk::function_selector!("markRefundsProcessed", U256).to_vec();
+        calldata.extend_from_slice(&project_id.to_be_bytes::<32>());
+        env::call_contract(self, U256::from(0), platform, &calldata)
+            .map_err(|_| AfroCreateError::InvalidInput("Platform refund update failed".to_string()))?;
+        Ok(())
     }
 
     fn update_platform_funding(&self, project_id: U256, amount_raised: U256) -> Result<()> {
@@ -479,7 +1972,7 @@ impl ProjectFunding {
 
     fn transfer_to_creator(&self, creator: Address, amount: U256) -> Result<()> {
         if amount > U256::from(0) {
-            call::transfer_eth(creator, amount)?;
+            env::transfer_eth(creator, amount)?;
         }
         Ok(())
     }
@@ -1,15 +1,20 @@
 use alloy_primitives::{Address, U256};
 use stylus_sdk::{
-    block, evm, msg,
+    evm,
     prelude::*,
     storage::{StorageAddress, StorageBool, StorageMap, StorageString, StorageU256, StorageVec},
 };
 
 use crate::types::{
+    env,
     errors::{AfroCreateError, Result, require_authorized, require_valid_input},
     events::*,
+    math,
 };
 
+const MAX_TOKEN_DECIMALS: u64 = 36;
+const MAX_BPS: u64 = 10000;
+
 #[derive(SolidityType, Clone, Debug)]
 pub struct OracleConfig {
     pub oracle_address: Address,
@@ -31,6 +36,21 @@ pub struct RevenueData {
     pub is_disputed: bool,
 }
 
+#[derive(SolidityType, Clone, Debug)]
+pub struct OracleDispute {
+    pub dispute_id: U256,
+    pub oracle: Address,
+    pub disputer: Address,
+    pub project_id: U256,
+    pub source: String,
+    pub disputed_amount: U256, // Value the oracle submitted
+    pub bond: U256,
+    pub confirmations: U256, // Other oracles agreeing with the dispute
+    pub created_at: U256,
+    pub resolved: bool,
+    pub upheld: bool,
+}
+
 #[storage]
 #[entrypoint]
 pub struct OracleManager {
@@ -50,6 +70,7 @@ pub struct OracleManager {
     // Platform integration
     revenue_distributor: StorageAddress,
     platform_contract: StorageAddress,
+    funding_contract: StorageAddress, // receives every token price update
     
     // Access control
     owner: StorageAddress,
@@ -59,10 +80,31 @@ pub struct OracleManager {
     oracle_accuracy: StorageMap<Address, U256>, // Running accuracy score
     oracle_response_times: StorageMap<Address, U256>, // Average response time
     
+    // Token price feeds (price of one whole token in an 18-decimal common denomination)
+    token_prices: StorageMap<Address, U256>,
+    token_decimals: StorageMap<Address, U256>,
+    token_price_updated: StorageMap<Address, U256>,
+    
     // Dispute handling
     disputed_reports: StorageMap<U256, bool>, // reportId -> disputed
     dispute_resolution_period: StorageU256,
     
+    // Bonded oracle disputes
+    oracle_stakes: StorageMap<Address, U256>, // Slashable stake per oracle
+    oracle_disputes: StorageMap<U256, OracleDispute>,
+    dispute_confirmed_by: StorageMap<U256, StorageMap<Address, bool>>, // dispute -> oracle -> confirmed
+    oracle_dispute_count: StorageU256,
+    dispute_bond: StorageU256,
+    dispute_quorum: StorageU256, // Oracle confirmations needed to uphold a dispute
+    oracle_slash_bps: StorageU256, // Share of stake slashed when a dispute is upheld
+    disputer_reward_bps: StorageU256, // Share of the slash paid to the disputer
+    forfeited_funds: StorageU256, // Forfeited bonds and unrewarded slashes
+    report_dispute: StorageMap<U256, StorageMap<String, StorageMap<Address, U256>>>, // project -> source -> oracle -> disputeId
+    open_disputes_against: StorageMap<Address, U256>, // oracle -> unresolved disputes
+    unbonding_amount: StorageMap<Address, U256>, // oracle -> stake requested for withdrawal
+    unbonding_available_at: StorageMap<Address, U256>,
+    unbonding_period: StorageU256,
+    
     // Emergency controls
     paused: StorageBool,
 }
@@ -76,7 +118,7 @@ impl OracleManager {
     ) -> Result<()> {
         require_valid_input(self.owner.get().is_zero(), "Already initialized")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         self.owner.set(caller);
         self.revenue_distributor.set(revenue_distributor);
         self.platform_contract.set(platform_contract);
@@ -85,6 +127,11 @@ impl OracleManager {
         self.min_oracles_required.set(U256::from(3));
         self.consensus_threshold.set(U256::from(70)); // 70% agreement
         self.dispute_resolution_period.set(U256::from(48 * 3600)); // 48 hours
+        self.dispute_bond.set(U256::from(10u64.pow(16))); // 0.01 ETH
+        self.dispute_quorum.set(U256::from(2));
+        self.oracle_slash_bps.set(U256::from(5000)); // 50% of stake
+        self.disputer_reward_bps.set(U256::from(2000)); // 20% of the slash
+        self.unbonding_period.set(U256::from(7 * 86400)); // 7 days
         
         Ok(())
     }
@@ -125,7 +172,7 @@ impl OracleManager {
     ) -> Result<()> {
         self.require_not_paused()?;
         
-        let oracle = msg::sender();
+        let oracle = env::sender();
         let oracle_config = self.oracles.get(oracle);
         
         require_valid_input(!oracle_config.oracle_address.is_zero(), "Oracle not registered")?;
@@ -176,7 +223,7 @@ impl OracleManager {
                 project_id,
                 source: source.clone(),
                 amount: U256::from(consensus_amount),
-                timestamp: U256::from(block::timestamp()),
+                timestamp: U256::from(env::timestamp()),
                 oracle_address: Address::ZERO, // Consensus from multiple oracles
                 verification_score: U256::from(agreement_percentage),
                 is_disputed: false,
@@ -206,7 +253,7 @@ impl OracleManager {
         source: String,
         evidence_uri: String,
     ) -> Result<()> {
-        let disputer = msg::sender();
+        let disputer = env::sender();
         
         // Check if report exists
         let mut revenue_data = self.revenue_reports.get(project_id).get(source.clone());
@@ -225,6 +272,220 @@ impl OracleManager {
         Ok(())
     }
 
+    #[payable]
+    pub fn stake_oracle(&mut self) -> Result<U256> {
+        let oracle = env::sender();
+        let config = self.oracles.get(oracle);
+        require_valid_input(!config.oracle_address.is_zero(), "Oracle not registered")?;
+        require_valid_input(env::value() > U256::from(0), "Stake must be positive")?;
+        
+        let total_stake = self.oracle_stakes.get(oracle) + env::value();
+        self.oracle_stakes.insert(oracle, total_stake);
+        
+        evm::log(OracleStaked {
+            oracle,
+            amount: env::value(),
+            total_stake,
+        });
+        
+        Ok(total_stake)
+    }
+
+    pub fn request_stake_withdrawal(&mut self, amount: U256) -> Result<U256> {
+        let oracle = env::sender();
+        require_valid_input(amount > U256::from(0), "Amount must be positive")?;
+        require_valid_input(amount <= self.oracle_stakes.get(oracle), "Insufficient stake")?;
+        
+        // The stake stays slashable until it is actually withdrawn
+        let available_at = U256::from(env::timestamp()) + self.unbonding_period.get();
+        self.unbonding_amount.insert(oracle, amount);
+        self.unbonding_available_at.insert(oracle, available_at);
+        
+        evm::log(OracleUnbondingStarted {
+            oracle,
+            amount,
+            available_at,
+        });
+        
+        Ok(available_at)
+    }
+
+    pub fn withdraw_stake(&mut self) -> Result<U256> {
+        let oracle = env::sender();
+        let requested = self.unbonding_amount.get(oracle);
+        require_valid_input(requested > U256::from(0), "No withdrawal requested")?;
+        require_valid_input(
+            U256::from(env::timestamp()) >= self.unbonding_available_at.get(oracle),
+            "Stake still unbonding"
+        )?;
+        require_valid_input(
+            self.open_disputes_against.get(oracle) == U256::from(0),
+            "Oracle has open disputes"
+        )?;
+        
+        // Slashes during unbonding come out of the requested amount
+        let stake = self.oracle_stakes.get(oracle);
+        let amount = core::cmp::min(requested, stake);
+        let remaining_stake = stake - amount;
+        self.oracle_stakes.insert(oracle, remaining_stake);
+        self.unbonding_amount.insert(oracle, U256::from(0));
+        self.unbonding_available_at.insert(oracle, U256::from(0));
+        if amount > U256::from(0) {
+            env::transfer_eth(oracle, amount)?;
+        }
+        
+        evm::log(OracleStakeWithdrawn {
+            oracle,
+            amount,
+            remaining_stake,
+        });
+        
+        Ok(amount)
+    }
+
+    pub fn withdraw_forfeited_funds(&mut self, recipient: Address, amount: U256) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(!recipient.is_zero(), "Invalid recipient")?;
+        let forfeited = self.forfeited_funds.get();
+        require_valid_input(amount <= forfeited, "Insufficient forfeited funds")?;
+        
+        self.forfeited_funds.set(forfeited - amount);
+        env::transfer_eth(recipient, amount)?;
+        Ok(())
+    }
+
+    #[payable]
+    pub fn dispute_oracle_report(
+        &mut self,
+        oracle: Address,
+        project_id: U256,
+        source: String,
+    ) -> Result<U256> {
+        self.require_not_paused()?;
+        
+        let disputer = env::sender();
+        require_valid_input(disputer != oracle, "Cannot dispute own report")?;
+        require_valid_input(env::value() >= self.dispute_bond.get(), "Insufficient dispute bond")?;
+        
+        let disputed_amount = self.oracle_submissions.get(project_id).get(source.clone()).get(oracle);
+        require_valid_input(disputed_amount > U256::from(0), "Oracle report not found")?;
+        require_valid_input(self.oracle_stakes.get(oracle) > U256::from(0), "Oracle has no slashable stake")?;
+        // One dispute per report, so a single report can only be slashed once
+        require_valid_input(
+            self.report_dispute.get(project_id).get(source.clone()).get(oracle) == U256::from(0),
+            "Report already disputed"
+        )?;
+        
+        let dispute_id = self.oracle_dispute_count.get() + U256::from(1);
+        self.oracle_dispute_count.set(dispute_id);
+        self.report_dispute.get_mut(project_id).get_mut(source.clone()).insert(oracle, dispute_id);
+        self.open_disputes_against.insert(oracle, self.open_disputes_against.get(oracle) + U256::from(1));
+        
+        let dispute = OracleDispute {
+            dispute_id,
+            oracle,
+            disputer,
+            project_id,
+            source: source.clone(),
+            disputed_amount,
+            bond: env::value(),
+            confirmations: U256::from(0),
+            created_at: U256::from(env::timestamp()),
+            resolved: false,
+            upheld: false,
+        };
+        self.oracle_disputes.insert(dispute_id, dispute);
+        
+        evm::log(OracleDisputeOpened {
+            dispute_id,
+            oracle,
+            disputer,
+            project_id,
+            source,
+            bond: env::value(),
+        });
+        
+        Ok(dispute_id)
+    }
+
+    pub fn confirm_oracle_dispute(&mut self, dispute_id: U256) -> Result<bool> {
+        let confirmer = env::sender();
+        let mut dispute = self.oracle_disputes.get(dispute_id);
+        require_valid_input(dispute.dispute_id != U256::from(0), "Dispute not found")?;
+        require_valid_input(!dispute.resolved, "Dispute already resolved")?;
+        require_valid_input(!self.is_dispute_expired(&dispute), "Dispute expired")?;
+        
+        // Only other active oracles on the same source can confirm
+        let config = self.oracles.get(confirmer);
+        require_authorized(
+            config.is_active && config.data_source == dispute.source && confirmer != dispute.oracle,
+            "Not an eligible oracle"
+        )?;
+        require_valid_input(
+            !self.dispute_confirmed_by.get(dispute_id).get(confirmer),
+            "Already confirmed"
+        )?;
+        
+        self.dispute_confirmed_by.get_mut(dispute_id).insert(confirmer, true);
+        dispute.confirmations += U256::from(1);
+        self.oracle_disputes.insert(dispute_id, dispute.clone());
+        
+        if dispute.confirmations >= self.dispute_quorum.get() {
+            self.settle_oracle_dispute(dispute_id, true)?;
+            return Ok(true);
+        }
+        
+        Ok(false)
+    }
+
+    pub fn resolve_oracle_dispute(&mut self, dispute_id: U256, upheld: bool) -> Result<()> {
+        self.require_operator()?;
+        
+        let dispute = self.oracle_disputes.get(dispute_id);
+        require_valid_input(dispute.dispute_id != U256::from(0), "Dispute not found")?;
+        require_valid_input(!dispute.resolved, "Dispute already resolved")?;
+        
+        self.settle_oracle_dispute(dispute_id, upheld)
+    }
+
+    pub fn expire_oracle_dispute(&mut self, dispute_id: U256) -> Result<()> {
+        let dispute = self.oracle_disputes.get(dispute_id);
+        require_valid_input(dispute.dispute_id != U256::from(0), "Dispute not found")?;
+        require_valid_input(!dispute.resolved, "Dispute already resolved")?;
+        require_valid_input(self.is_dispute_expired(&dispute), "Dispute still open")?;
+        
+        // A dispute nobody confirmed or ruled on within the resolution period failed to
+        // make its case: it is rejected, forfeiting the bond, and no longer locks the stake
+        self.settle_oracle_dispute(dispute_id, false)
+    }
+
+    pub fn get_oracle_stake(&self, oracle: Address) -> U256 {
+        self.oracle_stakes.get(oracle)
+    }
+
+    pub fn get_oracle_dispute(&self, dispute_id: U256) -> Result<OracleDispute> {
+        let dispute = self.oracle_disputes.get(dispute_id);
+        require_valid_input(dispute.dispute_id != U256::from(0), "Dispute not found")?;
+        Ok(dispute)
+    }
+
+    pub fn get_dispute_parameters(&self) -> (U256, U256, U256, U256) {
+        (
+            self.dispute_bond.get(),
+            self.dispute_quorum.get(),
+            self.oracle_slash_bps.get(),
+            self.disputer_reward_bps.get(),
+        )
+    }
+
+    pub fn get_forfeited_funds(&self) -> U256 {
+        self.forfeited_funds.get()
+    }
+
+    pub fn get_stake_unbonding(&self, oracle: Address) -> (U256, U256) {
+        (self.unbonding_amount.get(oracle), self.unbonding_available_at.get(oracle))
+    }
+
     // View functions
     pub fn get_revenue_data(&self, project_id: U256, source: String) -> Result<RevenueData> {
         let data = self.revenue_reports.get(project_id).get(source);
@@ -263,7 +524,62 @@ impl OracleManager {
         claimed >= reported.saturating_sub(tolerance) && claimed <= reported + tolerance
     }
 
+    pub fn set_token_price(&mut self, token: Address, price: U256, decimals: U256) -> Result<()> {
+        self.require_operator()?;
+        require_valid_input(price > U256::from(0), "Price must be positive")?;
+        require_valid_input(decimals <= U256::from(MAX_TOKEN_DECIMALS), "Too many decimals")?;
+        
+        self.token_prices.insert(token, price);
+        self.token_decimals.insert(token, decimals);
+        self.token_price_updated.insert(token, U256::from(env::timestamp()));
+        
+        // Funding normalizes contributions with its own copy of the price, so keep it in step
+        let funding = self.funding_contract.get();
+        if !funding.is_zero() {
+            let mut calldata = stylus_sdk::function_selector!("syncTokenPrice", Address, U256, U256).to_vec();
+            calldata.extend_from_slice(token.into_word().as_slice());
+            calldata.extend_from_slice(&price.to_be_bytes::<32>());
+            calldata.extend_from_slice(&decimals.to_be_bytes::<32>());
+            env::call_contract(self, U256::from(0), funding, &calldata)
+                .map_err(|_| AfroCreateError::InvalidInput("Funding price sync failed".to_string()))?;
+        }
+        
+        evm::log(TokenPriceUpdated {
+            token,
+            price,
+            decimals,
+        });
+        
+        Ok(())
+    }
+
+    pub fn get_token_price(&self, token: Address) -> (U256, U256, U256) {
+        (
+            self.token_prices.get(token),
+            self.token_decimals.get(token),
+            self.token_price_updated.get(token),
+        )
+    }
+
+    pub fn normalize_amount(&self, token: Address, amount: U256) -> Result<U256> {
+        let price = self.token_prices.get(token);
+        require_valid_input(price > U256::from(0), "No price feed for token")?;
+        Ok(math::normalize_amount(amount, price, self.token_decimals.get(token)))
+    }
+
     // Admin functions
+    pub fn add_operator(&mut self, operator: Address) -> Result<()> {
+        self.require_owner()?;
+        self.authorized_operators.insert(operator, true);
+        Ok(())
+    }
+
+    pub fn set_funding_contract(&mut self, funding_contract: Address) -> Result<()> {
+        self.require_owner()?;
+        self.funding_contract.set(funding_contract);
+        Ok(())
+    }
+
     pub fn deactivate_oracle(&mut self, oracle: Address) -> Result<()> {
         self.require_owner()?;
         
@@ -292,6 +608,27 @@ impl OracleManager {
         Ok(())
     }
 
+    pub fn set_dispute_parameters(
+        &mut self,
+        bond: U256,
+        quorum: U256,
+        slash_bps: U256,
+        reward_bps: U256,
+    ) -> Result<()> {
+        self.require_owner()?;
+        
+        require_valid_input(quorum >= U256::from(1), "Quorum must be positive")?;
+        require_valid_input(slash_bps <= U256::from(MAX_BPS), "Invalid slash share")?;
+        require_valid_input(reward_bps <= U256::from(MAX_BPS), "Invalid reward share")?;
+        
+        self.dispute_bond.set(bond);
+        self.dispute_quorum.set(quorum);
+        self.oracle_slash_bps.set(slash_bps);
+        self.disputer_reward_bps.set(reward_bps);
+        
+        Ok(())
+    }
+
     pub fn pause(&mut self) -> Result<()> {
         self.require_owner()?;
         self.paused.set(true);
@@ -302,13 +639,68 @@ impl OracleManager {
 // Internal helper functions
 impl OracleManager {
     fn require_owner(&self) -> Result<()> {
-        require_authorized(msg::sender() == self.owner.get(), "Only owner")
+        require_authorized(env::sender() == self.owner.get(), "Only owner")
     }
 
     fn require_not_paused(&self) -> Result<()> {
         require_valid_input(!self.paused.get(), "Contract is paused")
     }
 
+    fn require_operator(&self) -> Result<()> {
+        let caller = env::sender();
+        require_authorized(
+            caller == self.owner.get() || self.authorized_operators.get(caller),
+            "Not authorized operator"
+        )
+    }
+
+    fn is_dispute_expired(&self, dispute: &OracleDispute) -> bool {
+        U256::from(env::timestamp()) >= dispute.created_at + self.dispute_resolution_period.get()
+    }
+
+    fn settle_oracle_dispute(&mut self, dispute_id: U256, upheld: bool) -> Result<()> {
+        let mut dispute = self.oracle_disputes.get(dispute_id);
+        dispute.resolved = true;
+        dispute.upheld = upheld;
+        self.oracle_disputes.insert(dispute_id, dispute.clone());
+        let open = self.open_disputes_against.get(dispute.oracle);
+        self.open_disputes_against.insert(dispute.oracle, open.saturating_sub(U256::from(1)));
+        
+        let mut slashed = U256::from(0);
+        let mut reward = U256::from(0);
+        
+        if upheld {
+            // Slash the oracle and pay the disputer a share on top of the returned bond
+            let stake = self.oracle_stakes.get(dispute.oracle);
+            slashed = math::mul_bps(stake, self.oracle_slash_bps.get());
+            reward = math::mul_bps(slashed, self.disputer_reward_bps.get());
+            self.oracle_stakes.insert(dispute.oracle, stake - slashed);
+            self.forfeited_funds.set(self.forfeited_funds.get() + slashed - reward);
+            
+            let mut config = self.oracles.get(dispute.oracle);
+            config.reliability_score = config.reliability_score.saturating_sub(U256::from(10));
+            self.oracles.insert(dispute.oracle, config);
+            
+            let payout = dispute.bond + reward;
+            if payout > U256::from(0) {
+                env::transfer_eth(dispute.disputer, payout)?;
+            }
+        } else {
+            // A rejected dispute forfeits the bond
+            self.forfeited_funds.set(self.forfeited_funds.get() + dispute.bond);
+        }
+        
+        evm::log(OracleDisputeResolved {
+            dispute_id,
+            oracle: dispute.oracle,
+            upheld,
+            slashed,
+            disputer_reward: reward,
+        });
+        
+        Ok(())
+    }
+
     fn count_submissions(&self, submissions: &StorageMap<Address, U256>) -> usize {
         // In a real implementation, would need to iterate through submissions
         // For now, returning a placeholder
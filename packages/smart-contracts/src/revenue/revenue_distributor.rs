@@ -1,17 +1,23 @@
 use alloy_primitives::{Address, U256};
 use stylus_sdk::{
-    block, evm, msg,
+    evm,
     prelude::*,
     storage::{StorageAddress, StorageBool, StorageMap, StorageString, StorageU256, StorageVec},
 };
 
 use crate::types::{
+    env,
     errors::{AfroCreateError, Result, require_authorized, require_valid_input},
     events::*,
     interfaces::{IRevenueOracle, ISuperfluid, IRevenueShareNFT},
+    math::{split_bps_with_dust, BPS_DENOMINATOR},
     RevenueInfo, DistributionEvent,
 };
 
+const MAX_SOURCES_PER_PAGE: usize = 50;
+const NATIVE_DECIMALS: u64 = 18;
+const MAX_TOKEN_DECIMALS: u64 = 36;
+
 #[derive(SolidityType, Clone, Debug)]
 pub struct RevenueSource {
     pub source_name: String,
@@ -30,6 +36,17 @@ pub struct RevenueBreakdown {
     pub community_share: U256,
     pub platform_fee: U256,
     pub last_distribution: U256,
+    pub denomination_token: Address, // zero address for native ETH
+    pub denomination_decimals: U256,
+}
+
+#[derive(SolidityType, Clone, Debug)]
+pub struct RevenueAnalytics {
+    pub total_revenue_processed: U256,
+    pub total_revenue_distributed: U256,
+    pub total_revenue_pending: U256,
+    pub active_revenue_projects: U256,
+    pub average_project_revenue: U256,
 }
 
 #[derive(SolidityType, Clone, Debug)]
@@ -48,6 +65,9 @@ pub struct RevenueDistributor {
     // Revenue tracking per project
     project_revenue: StorageMap<U256, RevenueInfo>,
     project_revenue_sources: StorageMap<U256, StorageMap<String, U256>>, // project -> (source -> amount)
+    revenue_denomination_token: StorageMap<U256, Address>, // recorded on first revenue, zero for native ETH
+    revenue_denomination_decimals: StorageMap<U256, U256>,
+    held_revenue: StorageMap<U256, U256>, // project -> sub-threshold revenue not yet released for splitting
     
     // Revenue sources and oracles
     revenue_sources: StorageMap<String, RevenueSource>,
@@ -59,21 +79,42 @@ pub struct RevenueDistributor {
     superfluid_host: StorageAddress,
     accepted_tokens: StorageMap<Address, bool>,
     streaming_revenues: StorageMap<U256, StorageMap<Address, StreamingRevenue>>,
+    pending_stream_revenue: StorageMap<U256, StorageMap<Address, U256>>, // accrued on rate changes, not yet distributed
     
     // Distribution tracking
     total_distributed: StorageMap<U256, U256>,
     distribution_history: StorageMap<U256, StorageVec<DistributionEvent>>,
     creator_claimed_revenue: StorageMap<U256, StorageMap<Address, U256>>, // project -> creator -> amount
     
+    // Creator payouts
+    project_creators: StorageMap<U256, Address>,
+    auto_payout_enabled: StorageMap<Address, bool>,
+    creator_payout_address: StorageMap<Address, Address>,
+    creator_pending_payouts: StorageMap<U256, U256>, // project -> failed auto-payouts left for the creator to claim
+    
+    // Creator revenue vesting
+    creator_vesting_period: StorageMap<U256, U256>, // project -> linear vesting period (0 = immediately claimable)
+    creator_vesting_start: StorageMap<U256, U256>, // project -> first distribution timestamp
+    creator_vesting_pool: StorageMap<U256, U256>, // project -> creator share locked for vesting
+    creator_vesting_claimed: StorageMap<U256, U256>,
+    
     // Contract integration
     platform_contract: StorageAddress,
     nft_contract: StorageAddress,
+    validator_contract: StorageAddress, // CulturalValidator, consulted for open appeals (zero = no freeze)
+    
+    // Appeal freeze
+    appeal_freeze_disabled: StorageMap<U256, bool>, // project -> distribute even while an appeal is open
+    project_appeal_open: StorageMap<U256, bool>, // project -> appeal status reported by the validator contract
     
     // Distribution settings
     platform_fee_bps: StorageU256,
     min_distribution_amount: StorageU256,
     distribution_frequency: StorageU256, // Minimum time between distributions
     creator_share_default: StorageU256, // Default creator share in BPS
+    min_revenue_for_split: StorageU256, // Held revenue is released for splitting once it reaches this (0 = off)
+    dust_recipient: StorageAddress, // receives rounding dust from splits (zero = owner treasury)
+    accumulated_dust: StorageU256, // rounding dust awaiting a sweep
     
     // Revenue verification
     pending_revenue_claims: StorageMap<U256, StorageMap<String, U256>>, // project -> source -> amount
@@ -89,9 +130,11 @@ pub struct RevenueDistributor {
     total_revenue_processed: StorageU256,
     total_projects_with_revenue: StorageU256,
     average_project_revenue: StorageU256,
+    total_revenue_distributed: StorageU256,
     
     // Emergency controls
     paused: StorageBool,
+    claims_blocked_while_paused: StorageBool, // opt-in: the pause also blocks creator claims
     emergency_withdrawal_enabled: StorageBool,
     
     // Reentrancy guard
@@ -108,7 +151,7 @@ impl RevenueDistributor {
     ) -> Result<()> {
         require_valid_input(self.owner.get().is_zero(), "Already initialized")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         self.owner.set(caller);
         self.platform_contract.set(platform_contract);
         self.nft_contract.set(nft_contract);
@@ -133,6 +176,18 @@ impl RevenueDistributor {
         source: String,
         amount: U256,
         proof_uri: String,
+    ) -> Result<bool> {
+        self.add_token_revenue_source(project_id, source, amount, proof_uri, Address::ZERO, U256::from(NATIVE_DECIMALS))
+    }
+
+    pub fn add_token_revenue_source(
+        &mut self,
+        project_id: U256,
+        source: String,
+        amount: U256,
+        proof_uri: String,
+        token: Address,
+        decimals: U256,
     ) -> Result<bool> {
         self.require_not_paused()?;
         self.require_authorized_reporter()?;
@@ -155,7 +210,18 @@ impl RevenueDistributor {
         
         // Update project revenue info
         let mut revenue_info = self.project_revenue.get(project_id);
-        if revenue_info.total_revenue == U256::from(0) {
+        if revenue_info.revenue_sources.is_empty() {
+            require_valid_input(decimals <= U256::from(MAX_TOKEN_DECIMALS), "Too many decimals")?;
+            if !token.is_zero() {
+                require_valid_input(self.accepted_tokens.get(token), "Token not accepted")?;
+            }
+            self.total_projects_with_revenue.set(self.total_projects_with_revenue.get() + U256::from(1));
+            
+            // The first report fixes the unit every later amount is expressed in
+            self.revenue_denomination_token.insert(project_id, token);
+            self.revenue_denomination_decimals.insert(project_id, decimals);
+            self.sync_nft_revenue_token(project_id, token)?;
+            
             // Initialize new project revenue tracking
             revenue_info = RevenueInfo {
                 total_revenue: U256::from(0),
@@ -165,10 +231,22 @@ impl RevenueDistributor {
                 creator_share_bps: self.creator_share_default.get(),
                 community_share_bps: U256::from(10000) - self.creator_share_default.get() - self.platform_fee_bps.get(),
             };
+        } else {
+            require_valid_input(
+                token == self.revenue_denomination_token.get(project_id)
+                    && decimals == self.revenue_denomination_decimals.get(project_id),
+                "Revenue denomination mismatch"
+            )?;
         }
         
-        // Add revenue amount
-        revenue_info.total_revenue += amount;
+        // Sub-threshold revenue waits in the holding bucket so tiny amounts don't split into dust
+        let held = self.held_revenue.get(project_id) + amount;
+        if held >= self.min_revenue_for_split.get() {
+            revenue_info.total_revenue += held;
+            self.held_revenue.insert(project_id, U256::from(0));
+        } else {
+            self.held_revenue.insert(project_id, held);
+        }
         
         // Update source-specific tracking
         let current_source_amount = self.project_revenue_sources.get(project_id).get(source.clone());
@@ -183,12 +261,13 @@ impl RevenueDistributor {
         
         // Update global metrics
         self.total_revenue_processed.set(self.total_revenue_processed.get() + amount);
+        self.update_average_project_revenue();
 
         evm::log(RevenueAdded {
             project_id,
             source,
             amount,
-            timestamp: U256::from(block::timestamp()),
+            timestamp: U256::from(env::timestamp()),
         });
 
         Ok(true)
@@ -212,39 +291,55 @@ impl RevenueDistributor {
     }
 
     pub fn distribute_revenue(&mut self, project_id: U256) -> Result<U256> {
+        self.require_no_open_appeal(project_id)?;
         self.nonreentrant_guard()?;
         self.require_not_paused()?;
         
         let revenue_info = self.project_revenue.get(project_id);
-        require_valid_input(revenue_info.total_revenue > U256::from(0), "No revenue to distribute")?;
-        
-        // Check minimum distribution amount and frequency
         let total_distributed = self.total_distributed.get(project_id);
-        let available_for_distribution = revenue_info.total_revenue - total_distributed;
-        
-        require_valid_input(
-            available_for_distribution >= self.min_distribution_amount.get(),
-            "Below minimum distribution amount"
-        )?;
-        
-        require_valid_input(
-            U256::from(block::timestamp()) >= revenue_info.last_distribution_timestamp + self.distribution_frequency.get(),
-            "Distribution frequency not met"
-        )?;
+        let available_for_distribution = self.check_distribution_gates(project_id)?;
         
         // Calculate distribution breakdown
-        let platform_fee = (available_for_distribution * self.platform_fee_bps.get()) / U256::from(10000);
-        let creator_share = (available_for_distribution * revenue_info.creator_share_bps) / U256::from(10000);
-        let community_share = available_for_distribution - platform_fee - creator_share;
+        // Rounding dust is set aside for the dust recipient so every share rounds down
+        let platform_fee_bps = self.platform_fee_bps.get();
+        let community_bps = U256::from(BPS_DENOMINATOR)
+            .saturating_sub(platform_fee_bps)
+            .saturating_sub(revenue_info.creator_share_bps);
+        let (parts, dust) = split_bps_with_dust(
+            available_for_distribution,
+            &[platform_fee_bps, revenue_info.creator_share_bps, community_bps],
+        );
+        let (platform_fee, creator_share, community_share) = (parts[0], parts[1], parts[2]);
+        
+        // Payouts go out in the unit the project's revenue was reported in; dust sweeps pay ETH,
+        // so token dust stays in the contract with the platform fee
+        let token = self.revenue_denomination_token.get(project_id);
+        if token.is_zero() {
+            self.accumulated_dust.set(self.accumulated_dust.get() + dust);
+        }
         
         // Distribute to NFT holders (community share)
-        self.distribute_to_nft_holders(project_id, community_share)?;
+        self.distribute_to_nft_holders(project_id, community_share, token)?;
+        
+        if self.creator_vesting_period.get(project_id) > U256::from(0) {
+            // Vesting creator shares are locked and released linearly from the first distribution
+            if self.creator_vesting_start.get(project_id) == U256::from(0) {
+                self.creator_vesting_start.insert(project_id, U256::from(env::timestamp()));
+            }
+            let pool = self.creator_vesting_pool.get(project_id);
+            self.creator_vesting_pool.insert(project_id, pool + creator_share);
+        } else {
+            // Push the creator share if they opted in, otherwise hold it for claiming;
+            // a failed push must not block the distribution
+            self.push_creator_payout(project_id, creator_share);
+        }
         
         // Update distribution tracking
         self.total_distributed.insert(project_id, total_distributed + available_for_distribution);
+        self.total_revenue_distributed.set(self.total_revenue_distributed.get() + available_for_distribution);
         
         let distribution_event = DistributionEvent {
-            timestamp: U256::from(block::timestamp()),
+            timestamp: U256::from(env::timestamp()),
             amount: available_for_distribution,
             recipients_count: self.get_nft_holder_count(project_id),
             source: "batch_distribution".to_string(),
@@ -254,7 +349,7 @@ impl RevenueDistributor {
         
         // Update revenue info
         let mut updated_revenue_info = revenue_info;
-        updated_revenue_info.last_distribution_timestamp = U256::from(block::timestamp());
+        updated_revenue_info.last_distribution_timestamp = U256::from(env::timestamp());
         self.project_revenue.insert(project_id, updated_revenue_info);
 
         evm::log(RevenueDistributed {
@@ -269,6 +364,23 @@ impl RevenueDistributor {
         Ok(available_for_distribution)
     }
 
+    pub fn distribute_revenue_batch(&mut self, project_ids: Vec<U256>) -> Result<Vec<U256>> {
+        self.require_revenue_manager()?;
+        self.require_not_paused()?;
+        
+        // Projects not yet due are skipped rather than reverting the whole batch
+        let mut distributed = Vec::new();
+        for project_id in project_ids {
+            if self.is_revenue_frozen(project_id) || self.check_distribution_gates(project_id).is_err() {
+                continue;
+            }
+            self.distribute_revenue(project_id)?;
+            distributed.push(project_id);
+        }
+        
+        Ok(distributed)
+    }
+
     pub fn setup_superfluid_stream(
         &mut self,
         project_id: U256,
@@ -284,7 +396,7 @@ impl RevenueDistributor {
             token_address: token,
             flow_rate,
             total_streamed: U256::from(0),
-            last_update_timestamp: U256::from(block::timestamp()),
+            last_update_timestamp: U256::from(env::timestamp()),
             is_active: true,
         };
         
@@ -296,29 +408,116 @@ impl RevenueDistributor {
         Ok(())
     }
 
-    pub fn claim_creator_revenue(&mut self, project_id: U256) -> Result<U256> {
+    pub fn update_stream_flow_rate(
+        &mut self,
+        project_id: U256,
+        token: Address,
+        new_flow_rate: i128,
+    ) -> Result<()> {
+        self.require_revenue_manager()?;
+        require_valid_input(new_flow_rate > 0, "Flow rate must be positive")?;
+        
+        let mut streaming = self.streaming_revenues.get(project_id).get(token);
+        require_valid_input(streaming.is_active, "No active stream for this token")?;
+        
+        // Bank accrual at the old rate so the change only affects future flow
+        let current_time = U256::from(env::timestamp());
+        let elapsed = current_time - streaming.last_update_timestamp;
+        let accrued = Self::flow_rate_to_u256(streaming.flow_rate)? * elapsed;
+        
+        streaming.total_streamed += accrued;
+        streaming.last_update_timestamp = current_time;
+        streaming.flow_rate = new_flow_rate;
+        self.streaming_revenues.get_mut(project_id).insert(token, streaming);
+        
+        let pending = self.pending_stream_revenue.get(project_id).get(token);
+        self.pending_stream_revenue.get_mut(project_id).insert(token, pending + accrued);
+        
+        // In production, would call Superfluid contract to update the flow
+        
+        Ok(())
+    }
+
+    pub fn settle_and_distribute_stream(&mut self, project_id: U256, token: Address) -> Result<U256> {
         self.nonreentrant_guard()?;
+        self.require_not_paused()?;
+        
+        let mut streaming = self.streaming_revenues.get(project_id).get(token);
+        require_valid_input(streaming.is_active, "No active stream for this token")?;
         
-        let creator = msg::sender();
-        // In production, would verify creator ownership through platform contract
+        // Accrue everything streamed since the last settlement, plus anything
+        // banked when the flow rate was changed
+        let current_time = U256::from(env::timestamp());
+        let elapsed = current_time - streaming.last_update_timestamp;
+        let newly_accrued = Self::flow_rate_to_u256(streaming.flow_rate)? * elapsed;
+        let accrued = newly_accrued + self.pending_stream_revenue.get(project_id).get(token);
+        require_valid_input(accrued > U256::from(0), "Nothing accrued to settle")?;
         
-        let revenue_info = self.project_revenue.get(project_id);
-        require_valid_input(revenue_info.total_revenue > U256::from(0), "No revenue available")?;
+        streaming.total_streamed += newly_accrued;
+        streaming.last_update_timestamp = current_time;
+        self.streaming_revenues.get_mut(project_id).insert(token, streaming);
+        self.pending_stream_revenue.get_mut(project_id).insert(token, U256::from(0));
         
-        let total_distributed = self.total_distributed.get(project_id);
-        let available_revenue = revenue_info.total_revenue - total_distributed;
-        let creator_share = (available_revenue * revenue_info.creator_share_bps) / U256::from(10000);
+        // Feed the settled amount into the NFT holders' claimable pool
+        self.distribute_to_nft_holders(project_id, accrued, token)?;
         
-        let already_claimed = self.creator_claimed_revenue.get(project_id).get(creator);
-        let claimable = creator_share - already_claimed;
+        let distribution_event = DistributionEvent {
+            timestamp: current_time,
+            amount: accrued,
+            recipients_count: self.get_nft_holder_count(project_id),
+            source: "superfluid_stream".to_string(),
+        };
+        self.distribution_history.get_mut(project_id).push(distribution_event);
         
-        require_valid_input(claimable > U256::from(0), "No claimable revenue")?;
+        self.total_revenue_processed.set(self.total_revenue_processed.get() + accrued);
+        self.total_revenue_distributed.set(self.total_revenue_distributed.get() + accrued);
+        self.update_average_project_revenue();
+
+        evm::log(RevenueDistributed {
+            project_id,
+            total_amount: accrued,
+            creator_share: U256::from(0),
+            community_share: accrued,
+            platform_fee: U256::from(0),
+        });
+
+        self.unlock_guard();
+        Ok(accrued)
+    }
+
+    pub fn claim_creator_revenue(&mut self, project_id: U256) -> Result<U256> {
+        self.require_no_open_appeal(project_id)?;
+        self.require_claims_open()?;
+        self.nonreentrant_guard()?;
         
-        // Transfer revenue to creator
-        stylus_sdk::call::transfer_eth(creator, claimable)?;
+        let creator = env::sender();
+        require_authorized(
+            !creator.is_zero() && creator == self.project_creators.get(project_id),
+            "Only project creator"
+        )?;
+        
+        // Only shares already allocated by distribute_revenue are claimable: payouts held
+        // for pulling, and whatever has vested
+        let pending_payout = self.creator_pending_payouts.get(project_id);
+        let vested = self.claimable_vested_revenue(project_id);
+        let claimable = pending_payout + vested;
+        
+        require_valid_input(claimable > U256::from(0), "No claimable revenue")?;
         
-        // Update claimed amount
+        // Update claimed amounts before transferring
+        let already_claimed = self.creator_claimed_revenue.get(project_id).get(creator);
         self.creator_claimed_revenue.get_mut(project_id).insert(creator, already_claimed + claimable);
+        if pending_payout > U256::from(0) {
+            self.creator_pending_payouts.insert(project_id, U256::from(0));
+        }
+        if vested > U256::from(0) {
+            let vested_claimed = self.creator_vesting_claimed.get(project_id);
+            self.creator_vesting_claimed.insert(project_id, vested_claimed + vested);
+        }
+        
+        // Transfer revenue to creator
+        let token = self.revenue_denomination_token.get(project_id);
+        self.send_revenue(token, creator, claimable)?;
         
         self.unlock_guard();
         Ok(claimable)
@@ -341,7 +540,7 @@ impl RevenueDistributor {
         self.revenue_disputes.get_mut(project_id).push(source.clone());
         
         // In production, would create a formal dispute resolution process
-        let challenge_id = project_id + U256::from(block::timestamp());
+        let challenge_id = project_id + U256::from(env::timestamp());
         
         Ok(challenge_id)
     }
@@ -363,6 +562,8 @@ impl RevenueDistributor {
             community_share,
             platform_fee,
             last_distribution: revenue_info.last_distribution_timestamp,
+            denomination_token: self.revenue_denomination_token.get(project_id),
+            denomination_decimals: self.revenue_denomination_decimals.get(project_id),
         })
     }
 
@@ -382,6 +583,72 @@ impl RevenueDistributor {
         result
     }
 
+    pub fn is_revenue_frozen(&self, project_id: U256) -> bool {
+        !self.appeal_freeze_disabled.get(project_id) && self.has_open_appeal(project_id)
+    }
+
+    pub fn get_held_revenue(&self, project_id: U256) -> U256 {
+        self.held_revenue.get(project_id)
+    }
+
+    pub fn get_project_revenue_source_shares(&self, project_id: U256) -> Vec<(String, U256)> {
+        let total_revenue = self.project_revenue.get(project_id).total_revenue + self.held_revenue.get(project_id);
+        if total_revenue == U256::from(0) {
+            return Vec::new();
+        }
+        
+        self.get_project_revenue_sources(project_id)
+            .into_iter()
+            .map(|(source, amount)| (source, (amount * U256::from(10000)) / total_revenue))
+            .collect()
+    }
+
+    pub fn get_supported_sources(&self) -> Vec<(String, bool, bool)> {
+        self.get_supported_sources_paginated(U256::from(0), U256::from(MAX_SOURCES_PER_PAGE))
+    }
+
+    pub fn get_supported_sources_paginated(&self, offset: U256, limit: U256) -> Vec<(String, bool, bool)> {
+        let total = self.supported_sources.len();
+        let start = core::cmp::min(offset.as_usize(), total);
+        let page_size = core::cmp::min(limit.as_usize(), MAX_SOURCES_PER_PAGE);
+        let end = core::cmp::min(start + page_size, total);
+        
+        let mut result = Vec::new();
+        for i in start..end {
+            if let Some(source) = self.supported_sources.get(i) {
+                let config = self.revenue_sources.get(source.clone());
+                result.push((source, config.is_active, config.verification_required));
+            }
+        }
+        
+        result
+    }
+
+    pub fn get_auto_payout(&self, creator: Address) -> (bool, Address) {
+        (self.auto_payout_enabled.get(creator), self.payout_address_for(creator))
+    }
+
+    pub fn get_accumulated_dust(&self) -> (Address, U256) {
+        (self.effective_dust_recipient(), self.accumulated_dust.get())
+    }
+
+    pub fn get_pending_creator_payout(&self, project_id: U256) -> U256 {
+        self.creator_pending_payouts.get(project_id)
+    }
+
+    pub fn get_creator_vesting(&self, project_id: U256) -> (U256, U256, U256, U256) {
+        (
+            self.creator_vesting_period.get(project_id),
+            self.creator_vesting_start.get(project_id),
+            self.creator_vesting_pool.get(project_id),
+            self.creator_vesting_claimed.get(project_id),
+        )
+    }
+
+    pub fn get_claimable_vested_revenue(&self, project_id: U256) -> U256 {
+        self.claimable_vested_revenue(project_id)
+    }
+
     pub fn get_streaming_revenue(&self, project_id: U256, token: Address) -> Result<StreamingRevenue> {
         let streaming = self.streaming_revenues.get(project_id).get(token);
         require_valid_input(streaming.is_active, "No active stream for this token")?;
@@ -396,6 +663,19 @@ impl RevenueDistributor {
         )
     }
 
+    pub fn get_revenue_analytics(&self) -> RevenueAnalytics {
+        let processed = self.total_revenue_processed.get();
+        let distributed = self.total_revenue_distributed.get();
+        
+        RevenueAnalytics {
+            total_revenue_processed: processed,
+            total_revenue_distributed: distributed,
+            total_revenue_pending: processed - distributed,
+            active_revenue_projects: self.total_projects_with_revenue.get(),
+            average_project_revenue: self.average_project_revenue.get(),
+        }
+    }
+
     // Admin functions
     pub fn add_revenue_source_config(
         &mut self,
@@ -410,7 +690,7 @@ impl RevenueDistributor {
             oracle_address,
             is_active: true,
             verification_required,
-            last_update_timestamp: U256::from(block::timestamp()),
+            last_update_timestamp: U256::from(env::timestamp()),
             total_revenue_reported: U256::from(0),
         };
         
@@ -421,6 +701,31 @@ impl RevenueDistributor {
         Ok(())
     }
 
+    pub fn set_project_creator(&mut self, project_id: U256, creator: Address) -> Result<()> {
+        self.require_revenue_manager()?;
+        require_valid_input(!creator.is_zero(), "Invalid creator")?;
+        self.project_creators.insert(project_id, creator);
+        Ok(())
+    }
+
+    pub fn set_creator_vesting(&mut self, project_id: U256, period: U256) -> Result<()> {
+        self.require_revenue_manager()?;
+        require_valid_input(
+            self.creator_vesting_start.get(project_id) == U256::from(0),
+            "Vesting already started"
+        )?;
+        self.creator_vesting_period.insert(project_id, period);
+        Ok(())
+    }
+
+    pub fn set_auto_payout(&mut self, enabled: bool, payout_address: Address) -> Result<()> {
+        // A zero payout address means the creator's own address
+        let creator = env::sender();
+        self.auto_payout_enabled.insert(creator, enabled);
+        self.creator_payout_address.insert(creator, payout_address);
+        Ok(())
+    }
+
     pub fn add_accepted_token(&mut self, token: Address) -> Result<()> {
         self.require_owner()?;
         self.accepted_tokens.insert(token, true);
@@ -439,6 +744,72 @@ impl RevenueDistributor {
         Ok(())
     }
 
+    pub fn set_claims_allowed_while_paused(&mut self, allowed: bool) -> Result<()> {
+        self.require_owner()?;
+        self.claims_blocked_while_paused.set(!allowed);
+        Ok(())
+    }
+
+    pub fn claims_allowed_while_paused(&self) -> bool {
+        !self.claims_blocked_while_paused.get()
+    }
+
+    pub fn set_distribution_frequency(&mut self, frequency: U256) -> Result<()> {
+        self.require_owner()?;
+        self.distribution_frequency.set(frequency);
+        Ok(())
+    }
+
+    pub fn set_validator_contract(&mut self, validator_contract: Address) -> Result<()> {
+        self.require_owner()?;
+        self.validator_contract.set(validator_contract);
+        Ok(())
+    }
+
+    pub fn set_appeal_freeze(&mut self, project_id: U256, enabled: bool) -> Result<()> {
+        self.require_revenue_manager()?;
+        self.appeal_freeze_disabled.insert(project_id, !enabled);
+        Ok(())
+    }
+
+    pub fn report_appeal_status(&mut self, project_id: U256, open: bool) -> Result<()> {
+        let caller = env::sender();
+        require_authorized(
+            caller == self.validator_contract.get() || caller == self.owner.get(),
+            "Not validator contract"
+        )?;
+        self.project_appeal_open.insert(project_id, open);
+        Ok(())
+    }
+
+    pub fn set_min_revenue_for_split(&mut self, min_amount: U256) -> Result<()> {
+        self.require_owner()?;
+        self.min_revenue_for_split.set(min_amount);
+        Ok(())
+    }
+
+    pub fn set_dust_recipient(&mut self, recipient: Address) -> Result<()> {
+        self.require_owner()?;
+        self.dust_recipient.set(recipient);
+        Ok(())
+    }
+
+    pub fn sweep_dust(&mut self) -> Result<U256> {
+        self.require_owner()?;
+        let amount = self.accumulated_dust.get();
+        require_valid_input(amount > U256::from(0), "No dust to sweep")?;
+        
+        self.nonreentrant_guard()?;
+        let recipient = self.effective_dust_recipient();
+        self.accumulated_dust.set(U256::from(0));
+        env::transfer_eth(recipient, amount)?;
+        
+        evm::log(DustSwept { recipient, amount });
+        
+        self.unlock_guard();
+        Ok(amount)
+    }
+
     pub fn set_platform_fee(&mut self, new_fee_bps: U256) -> Result<()> {
         self.require_owner()?;
         require_valid_input(new_fee_bps <= U256::from(1000), "Fee too high")?; // Max 10%
@@ -450,11 +821,11 @@ impl RevenueDistributor {
 // Internal helper functions
 impl RevenueDistributor {
     fn require_owner(&self) -> Result<()> {
-        require_authorized(msg::sender() == self.owner.get(), "Only owner")
+        require_authorized(env::sender() == self.owner.get(), "Only owner")
     }
 
     fn require_authorized_reporter(&self) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             self.authorized_reporters.get(caller) || 
             caller == self.owner.get(),
@@ -463,7 +834,7 @@ impl RevenueDistributor {
     }
 
     fn require_revenue_manager(&self) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             self.revenue_managers.get(caller) || 
             caller == self.owner.get(),
@@ -475,6 +846,57 @@ impl RevenueDistributor {
         require_valid_input(!self.paused.get(), "Contract is paused")
     }
 
+    fn require_claims_open(&self) -> Result<()> {
+        // Earned revenue stays claimable during a pause unless the owner opts out
+        require_valid_input(
+            !(self.paused.get() && self.claims_blocked_while_paused.get()),
+            "Claims are paused"
+        )
+    }
+
+    fn effective_dust_recipient(&self) -> Address {
+        let recipient = self.dust_recipient.get();
+        if recipient.is_zero() {
+            self.owner.get()
+        } else {
+            recipient
+        }
+    }
+
+    fn check_distribution_gates(&self, project_id: U256) -> Result<U256> {
+        let revenue_info = self.project_revenue.get(project_id);
+        require_valid_input(revenue_info.total_revenue > U256::from(0), "No revenue to distribute")?;
+        
+        // Check minimum distribution amount and frequency
+        let available_for_distribution = revenue_info.total_revenue - self.total_distributed.get(project_id);
+        require_valid_input(
+            available_for_distribution >= self.min_distribution_amount.get(),
+            "Below minimum distribution amount"
+        )?;
+        
+        // The first distribution is exempt: there is no previous one to space it from
+        let first_distribution = revenue_info.last_distribution_timestamp == U256::from(0);
+        require_valid_input(
+            first_distribution ||
+            U256::from(env::timestamp()) >= revenue_info.last_distribution_timestamp + self.distribution_frequency.get(),
+            "Distribution frequency not met"
+        )?;
+        
+        Ok(available_for_distribution)
+    }
+
+    fn require_no_open_appeal(&self, project_id: U256) -> Result<()> {
+        require_valid_input(!self.is_revenue_frozen(project_id), "Revenue frozen during appeal")
+    }
+
+    fn has_open_appeal(&self, project_id: U256) -> bool {
+        if self.validator_contract.get().is_zero() {
+            return false;
+        }
+        // In production, would call ICulturalValidator::has_open_appeal on the validator contract
+        self.project_appeal_open.get(project_id)
+    }
+
     fn nonreentrant_guard(&mut self) -> Result<()> {
         require_valid_input(!self.locked.get(), "Reentrant call")?;
         self.locked.set(true);
@@ -485,6 +907,69 @@ impl RevenueDistributor {
         self.locked.set(false);
     }
 
+    fn update_average_project_revenue(&mut self) {
+        let project_count = self.total_projects_with_revenue.get();
+        if project_count > U256::from(0) {
+            self.average_project_revenue.set(self.total_revenue_processed.get() / project_count);
+        }
+    }
+
+    fn flow_rate_to_u256(flow_rate: i128) -> Result<U256> {
+        // Superfluid flow rates are signed; only inbound (positive) flows accrue revenue
+        require_valid_input(flow_rate > 0, "Flow rate must be positive")?;
+        Ok(U256::from(flow_rate as u128))
+    }
+
+    fn payout_address_for(&self, creator: Address) -> Address {
+        let payout_address = self.creator_payout_address.get(creator);
+        if payout_address.is_zero() { creator } else { payout_address }
+    }
+
+    fn push_creator_payout(&mut self, project_id: U256, creator_share: U256) {
+        if creator_share == U256::from(0) {
+            return;
+        }
+        
+        let creator = self.project_creators.get(project_id);
+        if creator.is_zero() || !self.auto_payout_enabled.get(creator) {
+            // Held for the creator to pull with claim_creator_revenue
+            let pending = self.creator_pending_payouts.get(project_id);
+            self.creator_pending_payouts.insert(project_id, pending + creator_share);
+            return;
+        }
+        
+        let payout_address = self.payout_address_for(creator);
+        let token = self.revenue_denomination_token.get(project_id);
+        let delivered = self.send_revenue(token, payout_address, creator_share).is_ok();
+        if !delivered {
+            // Fall back to pull accounting so a reverting payout address can't grief the distribution
+            let pending = self.creator_pending_payouts.get(project_id);
+            self.creator_pending_payouts.insert(project_id, pending + creator_share);
+        }
+        
+        evm::log(CreatorAutoPayout {
+            project_id,
+            creator,
+            payout_address,
+            amount: creator_share,
+            delivered,
+        });
+    }
+
+    fn claimable_vested_revenue(&self, project_id: U256) -> U256 {
+        let start = self.creator_vesting_start.get(project_id);
+        if start == U256::from(0) {
+            return U256::from(0);
+        }
+        
+        let pool = self.creator_vesting_pool.get(project_id);
+        let period = self.creator_vesting_period.get(project_id);
+        let elapsed = U256::from(env::timestamp()).saturating_sub(start);
+        let vested = if elapsed >= period { pool } else { pool * elapsed / period };
+        
+        vested.saturating_sub(self.creator_vesting_claimed.get(project_id))
+    }
+
     fn is_supported_source(&self, source: &str) -> bool {
         for i in 0..self.supported_sources.len() {
             if let Some(supported_source) = self.supported_sources.get(i) {
@@ -496,9 +981,72 @@ impl RevenueDistributor {
         false
     }
 
-    fn distribute_to_nft_holders(&self, project_id: U256, community_share: U256) -> Result<()> {
-        // In production, would call NFT contract to distribute revenue
-        // This would trigger the NFT contract's batch_distribute_revenue function
+    fn sync_nft_revenue_token(&mut self, project_id: U256, token: Address) -> Result<()> {
+        // Holders claim in whatever token the NFT has on record, which defaults to ETH
+        if token.is_zero() {
+            return Ok(());
+        }
+        
+        let nft = self.nft_contract.get();
+        require_valid_input(!nft.is_zero(), "NFT contract not set")?;
+        let mut calldata = stylus_sdk::function_selector!("setProjectRevenueToken", U256, Address).to_vec();
+        calldata.extend_from_slice(&project_id.to_be_bytes::<32>());
+        calldata.extend_from_slice(token.into_word().as_slice());
+        env::call_contract(self, U256::from(0), nft, &calldata)
+            .map_err(|_| AfroCreateError::InvalidInput("NFT revenue token not set".to_string()))?;
+        Ok(())
+    }
+
+    fn distribute_to_nft_holders(&mut self, project_id: U256, amount: U256, token: Address) -> Result<()> {
+        // A zero share leaves nothing for holders to claim
+        if amount == U256::from(0) {
+            return Ok(());
+        }
+        
+        let nft = self.nft_contract.get();
+        require_valid_input(!nft.is_zero(), "NFT contract not set")?;
+        
+        let mut calldata = stylus_sdk::function_selector!("batchDistributeRevenue", U256, U256).to_vec();
+        calldata.extend_from_slice(&project_id.to_be_bytes::<32>());
+        calldata.extend_from_slice(&amount.to_be_bytes::<32>());
+        
+        // Native revenue travels with the call; token revenue is moved to the NFT contract first
+        if token.is_zero() {
+            env::call_contract(self, amount, nft, &calldata)
+                .map_err(|_| AfroCreateError::TransferFailed("NFT holder distribution failed".to_string()))?;
+        } else {
+            self.push_token_payment(token, nft, amount)?;
+            env::call_contract(self, U256::from(0), nft, &calldata)
+                .map_err(|_| AfroCreateError::InvalidInput("NFT holder distribution failed".to_string()))?;
+        }
+        
+        Ok(())
+    }
+
+    fn send_revenue(&mut self, token: Address, recipient: Address, amount: U256) -> Result<()> {
+        if token.is_zero() {
+            env::transfer_eth(recipient, amount)
+                .map_err(|_| AfroCreateError::TransferFailed("Revenue transfer failed".to_string()))
+        } else {
+            self.push_token_payment(token, recipient, amount)
+        }
+    }
+
+    fn push_token_payment(&mut self, token: Address, recipient: Address, amount: U256) -> Result<()> {
+        require_valid_input(!recipient.is_zero(), "Invalid recipient")?;
+        require_valid_input(!token.is_zero(), "Invalid token")?;
+        require_valid_input(amount > U256::from(0), "Amount must be positive")?;
+        
+        let mut calldata = stylus_sdk::function_selector!("transfer", Address, U256).to_vec();
+        calldata.extend_from_slice(recipient.into_word().as_slice());
+        calldata.extend_from_slice(&amount.to_be_bytes::<32>());
+        let returned = env::call_contract(self, U256::from(0), token, &calldata)
+            .map_err(|_| AfroCreateError::TransferFailed("Token transfer failed".to_string()))?;
+        
+        // Tokens that return nothing from transfer are taken at their word; the rest must return true
+        if !returned.is_empty() && returned.iter().all(|byte| *byte == 0) {
+            return Err(AfroCreateError::TransferFailed("Token transfer failed".to_string()));
+        }
         Ok(())
     }
 
@@ -1,11 +1,12 @@
 use alloy_primitives::{Address, U256};
 use stylus_sdk::{
-    block, evm, msg,
+    evm,
     prelude::*,
     storage::{StorageAddress, StorageBool, StorageMap, StorageU256, StorageVec},
 };
 
 use crate::types::{
+    env,
     errors::{AfroCreateError, Result, require_authorized, require_valid_input},
     events::*,
 };
@@ -65,7 +66,7 @@ impl PlatformTreasury {
     ) -> Result<()> {
         require_valid_input(self.owner.get().is_zero(), "Already initialized")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         self.owner.set(caller);
         self.governance_contract.set(governance_contract);
         self.platform_contract.set(platform_contract);
@@ -85,7 +86,7 @@ impl PlatformTreasury {
 
     #[payable]
     pub fn receive_platform_fees(&mut self) -> Result<()> {
-        let amount = msg::value();
+        let amount = env::value();
         require_valid_input(amount > U256::from(0), "No funds received")?;
         
         // Update total balance
@@ -116,7 +117,7 @@ impl PlatformTreasury {
         require_valid_input(!recipient.is_zero(), "Invalid recipient")?;
         
         // Check daily spending limit
-        let today = U256::from(block::timestamp()) / U256::from(24 * 3600);
+        let today = U256::from(env::timestamp()) / U256::from(24 * 3600);
         let today_spent = self.daily_spent.get(today);
         require_valid_input(
             today_spent + amount <= self.daily_spending_limit.get(),
@@ -129,8 +130,8 @@ impl PlatformTreasury {
             purpose,
             amount,
             recipient,
-            approved_by: msg::sender(),
-            timestamp: U256::from(block::timestamp()),
+            approved_by: env::sender(),
+            timestamp: U256::from(env::timestamp()),
             executed: false,
         };
         
@@ -152,14 +153,14 @@ impl PlatformTreasury {
         require_valid_input(!allocation.executed, "Already executed")?;
         
         // Transfer funds to recipient
-        stylus_sdk::call::transfer_eth(allocation.recipient, allocation.amount)?;
+        env::transfer_eth(allocation.recipient, allocation.amount)?;
         
         // Mark as executed
         allocation.executed = true;
         self.allocations.insert(allocation_id, allocation.clone());
         
         // Update daily spending
-        let today = U256::from(block::timestamp()) / U256::from(24 * 3600);
+        let today = U256::from(env::timestamp()) / U256::from(24 * 3600);
         let today_spent = self.daily_spent.get(today);
         self.daily_spent.insert(today, today_spent + allocation.amount);
 
@@ -184,7 +185,7 @@ impl PlatformTreasury {
         require_valid_input(!recipient.is_zero(), "Invalid recipient")?;
         
         // Transfer funds
-        stylus_sdk::call::transfer_eth(recipient, amount)?;
+        env::transfer_eth(recipient, amount)?;
         
         // Update balance
         self.cultural_fund_balance.set(self.cultural_fund_balance.get() - amount);
@@ -209,7 +210,7 @@ impl PlatformTreasury {
     }
 
     pub fn get_daily_spending(&self) -> (U256, U256) {
-        let today = U256::from(block::timestamp()) / U256::from(24 * 3600);
+        let today = U256::from(env::timestamp()) / U256::from(24 * 3600);
         let spent_today = self.daily_spent.get(today);
         (spent_today, self.daily_spending_limit.get())
     }
@@ -266,7 +267,7 @@ impl PlatformTreasury {
         require_valid_input(self.emergency_withdrawal_enabled.get(), "Emergency withdrawal not enabled")?;
         require_valid_input(amount <= self.total_balance.get(), "Insufficient balance")?;
         
-        stylus_sdk::call::transfer_eth(self.owner.get(), amount)?;
+        env::transfer_eth(self.owner.get(), amount)?;
         self.total_balance.set(self.total_balance.get() - amount);
         
         evm::log(EmergencyWithdrawal {
@@ -282,11 +283,11 @@ impl PlatformTreasury {
 // Internal helper functions
 impl PlatformTreasury {
     fn require_owner(&self) -> Result<()> {
-        require_authorized(msg::sender() == self.owner.get(), "Only owner")
+        require_authorized(env::sender() == self.owner.get(), "Only owner")
     }
 
     fn require_treasury_manager(&self) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             caller == self.owner.get() || self.treasury_managers.get(caller),
             "Only treasury manager"
@@ -294,7 +295,7 @@ impl PlatformTreasury {
     }
 
     fn require_governance_approval(&self) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             caller == self.governance_contract.get() || caller == self.owner.get(),
             "Requires governance approval"
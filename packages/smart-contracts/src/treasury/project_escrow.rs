@@ -1,11 +1,12 @@
 use alloy_primitives::{Address, U256};
 use stylus_sdk::{
-    block, evm, msg,
+    evm,
     prelude::*,
     storage::{StorageAddress, StorageBool, StorageMap, StorageU256},
 };
 
 use crate::types::{
+    env,
     errors::{AfroCreateError, Result, require_authorized, require_valid_input},
     events::*,
 };
@@ -53,7 +54,7 @@ impl ProjectEscrow {
     ) -> Result<()> {
         require_valid_input(self.owner.get().is_zero(), "Already initialized")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         self.owner.set(caller);
         self.platform_contract.set(platform_contract);
         self.funding_contract.set(funding_contract);
@@ -69,7 +70,7 @@ impl ProjectEscrow {
         self.require_authorized_contract()?;
         self.require_not_paused()?;
         
-        let amount = msg::value();
+        let amount = env::value();
         require_valid_input(amount > U256::from(0), "No funds provided")?;
         require_valid_input(!creator.is_zero(), "Invalid creator address")?;
         
@@ -83,7 +84,7 @@ impl ProjectEscrow {
                 total_funded: amount,
                 released_amount: U256::from(0),
                 is_active: true,
-                creation_timestamp: U256::from(block::timestamp()),
+                creation_timestamp: U256::from(env::timestamp()),
             };
             
             self.active_escrow_count.set(self.active_escrow_count.get() + U256::from(1));
@@ -116,7 +117,7 @@ impl ProjectEscrow {
         require_valid_input(amount <= available, "Insufficient escrowed funds")?;
         
         // Transfer funds to creator
-        stylus_sdk::call::transfer_eth(escrow.creator, amount)?;
+        env::transfer_eth(escrow.creator, amount)?;
         
         // Update escrow
         escrow.released_amount += amount;
@@ -139,7 +140,7 @@ impl ProjectEscrow {
         require_valid_input(amount <= available, "Insufficient funds for refund")?;
         
         // Transfer refund to backer
-        stylus_sdk::call::transfer_eth(backer, amount)?;
+        env::transfer_eth(backer, amount)?;
         
         // Update escrow (treat as release for accounting)
         let mut updated_escrow = escrow;
@@ -222,7 +223,7 @@ impl ProjectEscrow {
         require_valid_input(available > U256::from(0), "No funds available")?;
         
         // Emergency release all available funds
-        stylus_sdk::call::transfer_eth(recipient, available)?;
+        env::transfer_eth(recipient, available)?;
         
         // Update escrow
         let mut updated_escrow = escrow;
@@ -245,11 +246,11 @@ impl ProjectEscrow {
 // Internal helper functions
 impl ProjectEscrow {
     fn require_owner(&self) -> Result<()> {
-        require_authorized(msg::sender() == self.owner.get(), "Only owner")
+        require_authorized(env::sender() == self.owner.get(), "Only owner")
     }
 
     fn require_authorized_contract(&self) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             caller == self.funding_contract.get() || 
             caller == self.platform_contract.get() ||
@@ -259,7 +260,7 @@ impl ProjectEscrow {
     }
 
     fn require_authorized_releaser(&self) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             self.authorized_releasers.get(caller) || caller == self.owner.get(),
             "Not authorized releaser"
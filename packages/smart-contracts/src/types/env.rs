@@ -0,0 +1,173 @@
+// Call context read by every contract. On-chain these forward to the Stylus VM. Native
+// builds (the test suite) have no VM, and the SDK caches msg::sender and msg::value for
+// the whole process, so they read a per-thread context that tests drive explicitly.
+// Cross-contract calls are recorded there too, and answer with whatever a test mocked,
+// or run a test callback against the calling contract to simulate re-entry.
+
+#[cfg(target_arch = "wasm32")]
+mod vm {
+    use alloy_primitives::{Address, U256};
+    use stylus_sdk::{
+        block,
+        call::{self, Call},
+        contract, msg,
+        storage::TopLevelStorage,
+    };
+
+    pub fn sender() -> Address {
+        msg::sender()
+    }
+
+    pub fn value() -> U256 {
+        msg::value()
+    }
+
+    pub fn timestamp() -> u64 {
+        block::timestamp()
+    }
+
+    pub fn contract_address() -> Address {
+        contract::address()
+    }
+
+    pub fn transfer_eth(to: Address, amount: U256) -> Result<(), Vec<u8>> {
+        call::transfer_eth(to, amount)
+    }
+
+    pub fn call_contract<S: TopLevelStorage>(
+        storage: &mut S,
+        value: U256,
+        to: Address,
+        calldata: &[u8],
+    ) -> Result<Vec<u8>, Vec<u8>> {
+        call::call(Call::new_in(storage).value(value), to, calldata).map_err(Vec::from)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod vm {
+    use alloy_primitives::{Address, U256};
+    use std::any::Any;
+    use std::cell::{Cell, RefCell};
+    use std::collections::HashMap;
+
+    // (target, selector) -> mocked outcome; unmocked calls succeed with empty return data
+    type MockedCalls = HashMap<(Address, [u8; 4]), Result<Vec<u8>, Vec<u8>>>;
+    // (target, selector) -> code run with the calling contract, as a malicious callee would
+    type CallCallback = Box<dyn FnMut(&mut dyn Any) -> Result<Vec<u8>, Vec<u8>>>;
+
+    thread_local! {
+        static SENDER: Cell<Address> = const { Cell::new(Address::ZERO) };
+        static VALUE: Cell<U256> = const { Cell::new(U256::ZERO) };
+        static TIMESTAMP: Cell<u64> = const { Cell::new(0) };
+        static CONTRACT_ADDRESS: Cell<Address> = const { Cell::new(Address::ZERO) };
+        static REJECTING_RECEIVERS: RefCell<Vec<Address>> = const { RefCell::new(Vec::new()) };
+        static MOCKED_CALLS: RefCell<MockedCalls> = RefCell::new(HashMap::new());
+        static CALL_CALLBACKS: RefCell<HashMap<(Address, [u8; 4]), CallCallback>> = RefCell::new(HashMap::new());
+        static RECORDED_CALLS: RefCell<Vec<(Address, U256, Vec<u8>)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    pub fn sender() -> Address {
+        SENDER.with(|sender| sender.get())
+    }
+
+    pub fn value() -> U256 {
+        VALUE.with(|value| value.get())
+    }
+
+    pub fn timestamp() -> u64 {
+        TIMESTAMP.with(|timestamp| timestamp.get())
+    }
+
+    pub fn contract_address() -> Address {
+        CONTRACT_ADDRESS.with(|address| address.get())
+    }
+
+    // Payments always land unless the receiver was marked as reverting on incoming ETH
+    pub fn transfer_eth(to: Address, _amount: U256) -> Result<(), Vec<u8>> {
+        if REJECTING_RECEIVERS.with(|receivers| receivers.borrow().contains(&to)) {
+            return Err(Vec::new());
+        }
+        Ok(())
+    }
+
+    pub fn call_contract<S: 'static>(
+        storage: &mut S,
+        value: U256,
+        to: Address,
+        calldata: &[u8],
+    ) -> Result<Vec<u8>, Vec<u8>> {
+        RECORDED_CALLS.with(|calls| calls.borrow_mut().push((to, value, calldata.to_vec())));
+        if value > U256::ZERO {
+            transfer_eth(to, value)?;
+        }
+        let mut selector = [0u8; 4];
+        selector.copy_from_slice(&calldata[..4]);
+        
+        // Taken out while it runs so the callback can make calls of its own
+        let callback = CALL_CALLBACKS.with(|callbacks| callbacks.borrow_mut().remove(&(to, selector)));
+        if let Some(mut callback) = callback {
+            let outcome = callback(storage);
+            CALL_CALLBACKS.with(|callbacks| callbacks.borrow_mut().insert((to, selector), callback));
+            return outcome;
+        }
+        MOCKED_CALLS.with(|mocks| mocks.borrow().get(&(to, selector)).cloned().unwrap_or(Ok(Vec::new())))
+    }
+
+    pub fn set_sender(sender: Address) {
+        SENDER.with(|current| current.set(sender));
+    }
+
+    pub fn set_value(value: U256) {
+        VALUE.with(|current| current.set(value));
+    }
+
+    pub fn set_timestamp(timestamp: u64) {
+        TIMESTAMP.with(|current| current.set(timestamp));
+    }
+
+    pub fn set_contract_address(address: Address) {
+        CONTRACT_ADDRESS.with(|current| current.set(address));
+    }
+
+    pub fn set_rejects_value(account: Address, rejects: bool) {
+        REJECTING_RECEIVERS.with(|receivers| {
+            let mut receivers = receivers.borrow_mut();
+            receivers.retain(|receiver| *receiver != account);
+            if rejects {
+                receivers.push(account);
+            }
+        });
+    }
+
+    pub fn mock_call(to: Address, selector: [u8; 4], outcome: Result<Vec<u8>, Vec<u8>>) {
+        MOCKED_CALLS.with(|mocks| mocks.borrow_mut().insert((to, selector), outcome));
+    }
+
+    // Runs `callback` with the calling contract whenever it calls `selector` on `to`
+    pub fn on_call(
+        to: Address,
+        selector: [u8; 4],
+        callback: impl FnMut(&mut dyn Any) -> Result<Vec<u8>, Vec<u8>> + 'static,
+    ) {
+        CALL_CALLBACKS.with(|callbacks| callbacks.borrow_mut().insert((to, selector), Box::new(callback)));
+    }
+
+    // Every cross-contract call made so far on this thread, as (target, value, calldata)
+    pub fn recorded_calls() -> Vec<(Address, U256, Vec<u8>)> {
+        RECORDED_CALLS.with(|calls| calls.borrow().clone())
+    }
+
+    pub fn reset() {
+        set_sender(Address::ZERO);
+        set_value(U256::ZERO);
+        set_timestamp(0);
+        set_contract_address(Address::ZERO);
+        REJECTING_RECEIVERS.with(|receivers| receivers.borrow_mut().clear());
+        MOCKED_CALLS.with(|mocks| mocks.borrow_mut().clear());
+        CALL_CALLBACKS.with(|callbacks| callbacks.borrow_mut().clear());
+        RECORDED_CALLS.with(|calls| calls.borrow_mut().clear());
+    }
+}
+
+pub use vm::*;
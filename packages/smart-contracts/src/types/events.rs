@@ -18,9 +18,55 @@ sol! {
         string title,
         string cultural_category,
         uint256 funding_target,
+        uint256 deadline,
+        uint256 block_number,
+        uint256 created_at
+    );
+
+    #[derive(Debug)]
+    event CategoryRemoved(
+        string category,
+        string fallback_category,
+        uint256 projects_reassigned
+    );
+
+    #[derive(Debug)]
+    event ProjectDraftProposed(
+        uint256 indexed project_id,
+        address indexed creator,
+        address indexed proposer
+    );
+
+    #[derive(Debug)]
+    event ProjectRelisted(
+        uint256 indexed project_id,
+        uint256 funding_target,
         uint256 deadline
     );
 
+    #[derive(Debug)]
+    event FundingTargetAdjusted(
+        uint256 indexed project_id,
+        uint256 old_target,
+        uint256 new_target,
+        bool validation_reset
+    );
+
+    #[derive(Debug)]
+    event CoCreatorInvited(
+        uint256 indexed project_id,
+        address indexed invitee,
+        uint256 share_bps
+    );
+
+    #[derive(Debug)]
+    event CoCreatorInviteResolved(
+        uint256 indexed project_id,
+        address indexed invitee,
+        uint256 share_bps,
+        bool accepted
+    );
+
     #[derive(Debug)]
     event ProjectFunded(
         uint256 indexed project_id,
@@ -29,6 +75,14 @@ sol! {
         uint256 total_raised
     );
 
+    #[derive(Debug)]
+    event ContributionWithdrawn(
+        uint256 indexed project_id,
+        address indexed backer,
+        uint256 amount,
+        uint256 fee
+    );
+
     #[derive(Debug)]
     event ProjectValidated(
         uint256 indexed project_id,
@@ -45,6 +99,20 @@ sol! {
         uint256 timestamp
     );
 
+    #[derive(Debug)]
+    event ValidationDeadlineMissed(
+        uint256 indexed project_id,
+        uint256 submissions,
+        uint256 deadline
+    );
+
+    #[derive(Debug)]
+    event ProjectAutoValidated(
+        uint256 indexed project_id,
+        address indexed creator,
+        uint256 funding_target
+    );
+
     // Revenue Events
     #[derive(Debug)]
     event RevenueAdded(
@@ -63,6 +131,30 @@ sol! {
         uint256 platform_fee
     );
 
+    #[derive(Debug)]
+    event CreatorAutoPayout(
+        uint256 indexed project_id,
+        address indexed creator,
+        address payout_address,
+        uint256 amount,
+        bool delivered
+    );
+
+    #[derive(Debug)]
+    event RevenueHeldUnallocated(
+        uint256 indexed project_id,
+        uint256 amount,
+        uint256 total_unallocated
+    );
+
+    #[derive(Debug)]
+    event TransferFeeCharged(
+        uint256 indexed token_id,
+        uint256 indexed project_id,
+        uint256 sale_price,
+        uint256 fee
+    );
+
     #[derive(Debug)]
     event RevenueClaimed(
         uint256 indexed token_id,
@@ -70,6 +162,68 @@ sol! {
         uint256 amount
     );
 
+    #[derive(Debug)]
+    event RefundCredited(
+        uint256 indexed project_id,
+        address indexed backer,
+        uint256 amount
+    );
+
+    #[derive(Debug)]
+    event RevenueReinvested(
+        uint256 indexed token_id,
+        address indexed holder,
+        uint256 indexed target_project_id,
+        uint256 amount
+    );
+
+    #[derive(Debug)]
+    event TokenPriceUpdated(
+        address indexed token,
+        uint256 price,
+        uint256 decimals
+    );
+
+    #[derive(Debug)]
+    event OracleStaked(
+        address indexed oracle,
+        uint256 amount,
+        uint256 total_stake
+    );
+
+    #[derive(Debug)]
+    event OracleUnbondingStarted(
+        address indexed oracle,
+        uint256 amount,
+        uint256 available_at
+    );
+
+    #[derive(Debug)]
+    event OracleStakeWithdrawn(
+        address indexed oracle,
+        uint256 amount,
+        uint256 remaining_stake
+    );
+
+    #[derive(Debug)]
+    event OracleDisputeOpened(
+        uint256 indexed dispute_id,
+        address indexed oracle,
+        address indexed disputer,
+        uint256 project_id,
+        string source,
+        uint256 bond
+    );
+
+    #[derive(Debug)]
+    event OracleDisputeResolved(
+        uint256 indexed dispute_id,
+        address indexed oracle,
+        bool upheld,
+        uint256 slashed,
+        uint256 disputer_reward
+    );
+
     // NFT Events
     #[derive(Debug)]
     event RevenueNFTMinted(
@@ -80,6 +234,29 @@ sol! {
         uint256 revenue_share_bps
     );
 
+    #[derive(Debug)]
+    event RevenueNFTBurned(
+        uint256 indexed token_id,
+        uint256 indexed project_id,
+        address indexed holder
+    );
+
+    #[derive(Debug)]
+    event CatalogCreated(
+        uint256 indexed catalog_id,
+        address indexed creator,
+        uint256 project_count
+    );
+
+    #[derive(Debug)]
+    event CatalogShareMinted(
+        uint256 indexed token_id,
+        uint256 indexed catalog_id,
+        address indexed recipient,
+        uint256 funding_amount,
+        uint256 revenue_share_bps
+    );
+
     #[derive(Debug)]
     event Transfer(
         address indexed from,
@@ -125,6 +302,14 @@ sol! {
         uint256 new_score
     );
 
+    #[derive(Debug)]
+    event ReputationChanged(
+        address indexed creator,
+        uint256 old_score,
+        uint256 new_score,
+        string reason
+    );
+
     // Validator Events
     #[derive(Debug)]
     event ValidatorRegistered(
@@ -141,6 +326,41 @@ sol! {
         string reason
     );
 
+    #[derive(Debug)]
+    event ValidatorReputationChanged(
+        address indexed validator,
+        uint256 old_score,
+        uint256 new_score,
+        uint256 indexed project_id
+    );
+
+    #[derive(Debug)]
+    event AppealWindowExtended(
+        uint256 indexed project_id,
+        uint256 additional_seconds,
+        uint256 new_deadline
+    );
+
+    #[derive(Debug)]
+    event ValidatorRewardRateChanged(
+        uint256 old_amount,
+        uint256 new_amount
+    );
+
+    #[derive(Debug)]
+    event ValidatorRewardsClaimed(
+        address indexed validator,
+        uint256 amount,
+        uint256 timestamp
+    );
+
+    #[derive(Debug)]
+    event ProjectValidationRewardsFunded(
+        uint256 indexed project_id,
+        uint256 amount,
+        uint256 validator_count
+    );
+
     // Governance Events
     #[derive(Debug)]
     event ProposalCreated(
@@ -165,6 +385,34 @@ sol! {
         bool success
     );
 
+    #[derive(Debug)]
+    event ProposalDepositSettled(
+        uint256 indexed proposal_id,
+        address indexed proposer,
+        uint256 amount,
+        bool refunded
+    );
+
+    #[derive(Debug)]
+    event RegionalEarmarkClaimed(
+        string region,
+        address indexed coordinator,
+        uint256 amount
+    );
+
+    #[derive(Debug)]
+    event ApprovedRecipientUpdated(
+        address indexed recipient,
+        bool approved,
+        address indexed updated_by
+    );
+
+    #[derive(Debug)]
+    event TreasuryWithdrawn(
+        address indexed recipient,
+        uint256 amount
+    );
+
     // Platform Management Events
     #[derive(Debug)]
     event PlatformPaused(uint256 timestamp);
@@ -172,9 +420,41 @@ sol! {
     #[derive(Debug)]
     event PlatformUnpaused(uint256 timestamp);
 
+    #[derive(Debug)]
+    event FundingPauseUpdated(bool paused, uint256 timestamp);
+
+    #[derive(Debug)]
+    event OwnershipTransferStarted(address indexed previous_owner, address indexed new_owner);
+
+    #[derive(Debug)]
+    event OwnershipTransferred(address indexed previous_owner, address indexed new_owner);
+
     #[derive(Debug)]
     event PlatformFeeUpdated(uint256 old_fee_bps, uint256 new_fee_bps);
 
+    #[derive(Debug)]
+    event CulturalFundAutoContributed(uint256 indexed project_id, uint256 amount);
+
+    #[derive(Debug)]
+    event ValidationRewardReservePaid(uint256 indexed project_id, uint256 amount);
+
+    #[derive(Debug)]
+    event ValidationRewardReserveReturned(uint256 indexed project_id, uint256 amount);
+
+    #[derive(Debug)]
+    event PlatformFeeRebated(
+        uint256 indexed project_id,
+        address indexed creator,
+        uint256 validation_score,
+        uint256 rebate
+    );
+
+    #[derive(Debug)]
+    event MatchedFundsAdded(uint256 indexed project_id, uint256 amount, uint256 total_matched);
+
+    #[derive(Debug)]
+    event MatchedFundsReturned(uint256 indexed project_id, address indexed treasury, uint256 amount);
+
     #[derive(Debug)]
     event EmergencyWithdrawal(
         address indexed token,
@@ -182,6 +462,24 @@ sol! {
         uint256 amount
     );
 
+    #[derive(Debug)]
+    event ProjectEmergencyHalted(
+        uint256 indexed project_id,
+        uint256 amount_withdrawn,
+        uint256 outstanding_claims,
+        uint256 timestamp
+    );
+
+    #[derive(Debug)]
+    event TreasuryWithdrawal(
+        address indexed recipient,
+        uint256 amount,
+        uint256 remaining_treasury
+    );
+
+    #[derive(Debug)]
+    event DustSwept(address indexed recipient, uint256 amount);
+
     // Milestone Events
     #[derive(Debug)]
     event MilestoneCompleted(
@@ -196,4 +494,21 @@ sol! {
         uint256 indexed milestone_id,
         address indexed challenger
     );
+
+    #[derive(Debug)]
+    event CreatorDisputeFlagged(
+        uint256 indexed project_id,
+        address indexed backer,
+        uint256 contribution,
+        uint256 flagged_total,
+        string reason_uri,
+        bool releases_frozen
+    );
+
+    #[derive(Debug)]
+    event CreatorDisputeResolved(
+        uint256 indexed project_id,
+        address indexed resolver,
+        uint256 flagged_total
+    );
 }
\ No newline at end of file
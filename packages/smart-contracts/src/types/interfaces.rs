@@ -1,6 +1,13 @@
 use alloy_primitives::{Address, FixedBytes, U256};
 use stylus_sdk::sol_interface;
 
+// ERC165 interface IDs
+pub const ERC165_INTERFACE_ID: FixedBytes<4> = FixedBytes([0x01, 0xff, 0xc9, 0xa7]);
+pub const ERC721_INTERFACE_ID: FixedBytes<4> = FixedBytes([0x80, 0xac, 0x58, 0xcd]);
+pub const ERC721_METADATA_INTERFACE_ID: FixedBytes<4> = FixedBytes([0x5b, 0x5e, 0x13, 0x9f]);
+pub const ERC721_ENUMERABLE_INTERFACE_ID: FixedBytes<4> = FixedBytes([0x78, 0x0e, 0x9d, 0x63]);
+pub const ERC2981_INTERFACE_ID: FixedBytes<4> = FixedBytes([0x2a, 0x55, 0x20, 0x5a]);
+
 #[sol_interface]
 pub trait ENSRegistry {
     fn set_subnode_owner(node: FixedBytes<32>, label: FixedBytes<32>, owner: Address) -> FixedBytes<32>;
@@ -72,6 +79,7 @@ pub trait IProjectFunding {
     fn fund_project(project_id: U256, backer_ens_name: String) -> U256;
     fn release_milestone_funds(project_id: U256, milestone_id: U256);
     fn process_refunds(project_id: U256);
+    fn claim_refund(project_id: U256) -> U256;
     fn get_funding_stats(project_id: U256) -> Vec<u8>;
     fn get_backer_contributions(project_id: U256, backer: Address) -> U256;
 }
@@ -93,4 +101,5 @@ pub trait ICulturalValidator {
     fn challenge_validation(project_id: U256, reason: String) -> U256;
     fn get_validation_status(project_id: U256) -> Vec<u8>;
     fn get_qualified_validators(cultural_region: String) -> Vec<Address>;
+    fn has_open_appeal(project_id: U256) -> bool;
 }
\ No newline at end of file
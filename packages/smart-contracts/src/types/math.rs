@@ -0,0 +1,57 @@
+use alloy_primitives::U256;
+
+pub const BPS_DENOMINATOR: u64 = 10000;
+
+// Applies a basis-point rate to an amount, rounding down.
+pub fn mul_bps(amount: U256, bps: U256) -> U256 {
+    (amount * bps) / U256::from(BPS_DENOMINATOR)
+}
+
+// Splits `amount` into one component per share, in basis points.
+//
+// Each component is rounded down and capped at what is left of `amount`, so
+// shares that over-allocate never overdraw. Whatever is left after every share
+// (rounding dust or unallocated bps) goes to `remainder_bucket`, which means
+// the returned components always sum exactly to `amount`. `remainder_bucket`
+// must index into `shares_bps`.
+pub fn split_bps(amount: U256, shares_bps: &[U256], remainder_bucket: usize) -> Vec<U256> {
+    let (mut parts, remaining) = split_bps_with_dust(amount, shares_bps);
+
+    if let Some(bucket) = parts.get_mut(remainder_bucket) {
+        *bucket += remaining;
+    }
+
+    parts
+}
+
+// Splits `amount` like `split_bps`, but returns whatever is left after every
+// share separately instead of folding it into a component, so callers can
+// route rounding dust explicitly. Components plus dust sum exactly to `amount`.
+pub fn split_bps_with_dust(amount: U256, shares_bps: &[U256]) -> (Vec<U256>, U256) {
+    let mut parts = Vec::with_capacity(shares_bps.len());
+    let mut remaining = amount;
+
+    for bps in shares_bps {
+        let part = core::cmp::min(mul_bps(amount, *bps), remaining);
+        remaining -= part;
+        parts.push(part);
+    }
+
+    (parts, remaining)
+}
+
+// Splits `amount` into a fee and the net amount left after it.
+pub fn split_fee(amount: U256, fee_bps: U256) -> (U256, U256) {
+    let parts = split_bps(
+        amount,
+        &[fee_bps, U256::from(BPS_DENOMINATOR).saturating_sub(fee_bps)],
+        1,
+    );
+    (parts[0], parts[1])
+}
+
+// Converts a raw token amount into the common 18-decimal denomination, given
+// the price of one whole token (18 decimals) and the token's own decimals.
+pub fn normalize_amount(amount: U256, price: U256, decimals: U256) -> U256 {
+    (amount * price) / U256::from(10).pow(decimals)
+}
@@ -2,9 +2,11 @@ use alloy_primitives::{Address, U256, FixedBytes};
 use alloy_sol_types::{sol, SolType};
 use stylus_sdk::prelude::*;
 
+pub mod env;
 pub mod events;
 pub mod errors;
 pub mod interfaces;
+pub mod math;
 
 sol! {
     #[derive(Debug, PartialEq, Eq)]
@@ -29,10 +31,11 @@ sol! {
         uint256 funding_target;
         uint256 funding_raised;
         uint256 deadline;
-        uint8 status; // 0: Active, 1: Successful, 2: Failed, 3: Cancelled
+        uint8 status; // 0: Active, 1: Successful, 2: Failed, 3: Cancelled, 4: EmergencyHalted
         uint8 validation_status; // 0: Pending, 1: Approved, 2: Rejected
         uint256 validation_score;
         string metadata_uri; // IPFS hash
+        string[] regions; // cultural regions validators are drawn from
     }
 
     #[derive(Debug, PartialEq, Eq)]
@@ -67,6 +70,7 @@ sol! {
         address creator;
         uint256 backer_count;
         uint8 funding_model; // 0: AllOrNothing, 1: FlexibleFunding, 2: MilestoneBased
+        address funding_token; // Address::ZERO = native ETH
     }
 
     #[derive(Debug, PartialEq, Eq)]
@@ -109,7 +113,9 @@ sol! {
         uint256 for_votes;
         uint256 against_votes;
         uint8 status; // 0: Active, 1: Succeeded, 2: Failed, 3: Executed
+        uint8 proposal_type; // ProposalType
         bytes execution_data;
+        uint256 deposit; // refunded once quorum is reached, forfeited otherwise
     }
 
     #[derive(Debug, PartialEq, Eq)]
@@ -126,6 +132,7 @@ pub enum ProjectStatus {
     Successful,
     Failed,
     Cancelled,
+    EmergencyHalted,
 }
 
 #[derive(SolidityType, Debug, Clone, PartialEq, Eq)]
@@ -150,6 +157,21 @@ pub enum ProposalStatus {
     Executed,
 }
 
+#[derive(SolidityType, Debug, Clone, PartialEq, Eq)]
+pub enum ProposalType {
+    Text,
+    ParameterChange,
+    TreasurySpend,
+    CulturalGrant,
+}
+
+#[derive(SolidityType, Debug, Clone, PartialEq, Eq)]
+pub enum VotingRole {
+    Creator,
+    Backer,
+    Validator,
+}
+
 #[derive(SolidityType, Debug, Clone, PartialEq, Eq)]
 pub enum Role {
     Owner,
@@ -181,6 +203,7 @@ impl PackedProjectInfo {
             1 => ProjectStatus::Successful,
             2 => ProjectStatus::Failed,
             3 => ProjectStatus::Cancelled,
+            4 => ProjectStatus::EmergencyHalted,
             _ => ProjectStatus::Active,
         }
     }
@@ -191,6 +214,7 @@ pub const MAX_VALIDATION_SCORE: u8 = 100;
 pub const MIN_VALIDATION_SCORE: u8 = 0;
 pub const VALIDATION_THRESHOLD: u8 = 70;
 pub const MIN_VALIDATORS_REQUIRED: u8 = 3;
+pub const MAX_CREATOR_REPUTATION: u64 = 1000;
 
 pub const AFROCREATE_ENS_NODE: FixedBytes<32> = FixedBytes([
     0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0,
@@ -1,11 +1,12 @@
 use alloy_primitives::{Address, U256};
 use stylus_sdk::{
-    block, evm, msg,
+    evm,
     prelude::*,
     storage::{StorageAddress, StorageBool, StorageMap, StorageU256, StorageVec},
 };
 
 use crate::types::{
+    env,
     errors::{AfroCreateError, Result, require_authorized, require_valid_input},
     events::*,
 };
@@ -78,7 +79,7 @@ impl CommunityValidator {
     pub fn initialize(&mut self, platform_contract: Address, cultural_validator: Address) -> Result<()> {
         require_valid_input(self.owner.get().is_zero(), "Already initialized")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         self.owner.set(caller);
         self.platform_contract.set(platform_contract);
         self.cultural_validator.set(cultural_validator);
@@ -102,7 +103,7 @@ impl CommunityValidator {
         score: U256,
         feedback: String,
     ) -> Result<()> {
-        let voter = msg::sender();
+        let voter = env::sender();
         
         // Check if user is verified community member
         require_valid_input(
@@ -119,12 +120,12 @@ impl CommunityValidator {
         // Check cooldown
         let last_vote = self.vote_cooldown.get(voter);
         require_valid_input(
-            U256::from(block::timestamp()) >= last_vote + self.cooldown_period.get(),
+            U256::from(env::timestamp()) >= last_vote + self.cooldown_period.get(),
             "Cooldown period not elapsed"
         )?;
         
         // Check daily vote limit
-        let current_period = U256::from(block::timestamp()) / U256::from(24 * 3600);
+        let current_period = U256::from(env::timestamp()) / U256::from(24 * 3600);
         let votes_today = self.user_vote_count.get(voter).get(current_period);
         require_valid_input(
             votes_today < self.max_votes_per_period.get(),
@@ -145,7 +146,7 @@ impl CommunityValidator {
             score,
             feedback,
             voting_power,
-            timestamp: U256::from(block::timestamp()),
+            timestamp: U256::from(env::timestamp()),
         };
         
         // Store vote
@@ -153,7 +154,7 @@ impl CommunityValidator {
         self.user_project_votes.get_mut(voter).insert(project_id, true);
         
         // Update cooldown and vote count
-        self.vote_cooldown.insert(voter, U256::from(block::timestamp()));
+        self.vote_cooldown.insert(voter, U256::from(env::timestamp()));
         self.user_vote_count.get_mut(voter).insert(current_period, votes_today + U256::from(1));
         
         // Check if we have enough votes to finalize
@@ -214,7 +215,7 @@ impl CommunityValidator {
             approval_percentage,
             average_score,
             community_consensus,
-            completion_timestamp: U256::from(block::timestamp()),
+            completion_timestamp: U256::from(env::timestamp()),
         };
         
         self.community_results.insert(project_id, result);
@@ -241,7 +242,7 @@ impl CommunityValidator {
     }
 
     pub fn claim_voting_rewards(&mut self) -> Result<U256> {
-        let claimer = msg::sender();
+        let claimer = env::sender();
         let rewards = self.voting_rewards.get(claimer);
         
         require_valid_input(rewards > U256::from(0), "No rewards to claim")?;
@@ -353,6 +354,6 @@ impl CommunityValidator {
 // Internal helper functions
 impl CommunityValidator {
     fn require_owner(&self) -> Result<()> {
-        require_authorized(msg::sender() == self.owner.get(), "Only owner")
+        require_authorized(env::sender() == self.owner.get(), "Only owner")
     }
 }
\ No newline at end of file
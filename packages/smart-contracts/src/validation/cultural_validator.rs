@@ -1,17 +1,26 @@
 use alloy_primitives::{Address, U256};
 use stylus_sdk::{
-    block, evm, msg,
+    evm,
     prelude::*,
     storage::{StorageAddress, StorageBool, StorageMap, StorageString, StorageU256, StorageVec},
 };
 
 use crate::types::{
+    env,
     errors::{AfroCreateError, Result, require_authorized, require_valid_input},
     events::*,
-    ValidatorProfile, ValidationSubmission, ValidationStatus,
+    math::mul_bps,
+    ValidatorProfile, ValidationSubmission, ValidationStatus, VotingRole,
     VALIDATION_THRESHOLD, MIN_VALIDATORS_REQUIRED,
 };
 
+const MAX_VALIDATOR_REWARD: u64 = 1000000000000000000; // 1 ETH
+const DEFAULT_START_REPUTATION: u64 = 100;
+const MAX_START_REPUTATION: u64 = 150;
+const PRIMARY_REGION_MULTIPLIER: u64 = 150; // 1.5x weight when the project is in the validator's primary region
+const MAX_STAKE_SHARE_BPS: u64 = 3000; // stake share counted toward the boost is capped at 30%
+const DEFAULT_MAX_REGIONS_PER_VALIDATOR: u64 = 3;
+
 #[derive(SolidityType, Clone, Debug)]
 pub struct ValidationResult {
     pub project_id: U256,
@@ -41,37 +50,68 @@ pub struct CulturalValidator {
     // Validator management
     validators: StorageMap<Address, ValidatorProfile>,
     validator_regions: StorageMap<Address, StorageVec<String>>,
+    validator_primary_region: StorageMap<Address, String>, // defaults to the first registered region
     validator_stakes: StorageMap<Address, U256>,
+    total_validator_stake: StorageU256,
+    stake_boost_bps: StorageU256, // max extra weight, relative to reputation, earned by stake share (0 = off)
     validator_count: StorageU256,
     
     // Regional authorities (validators with special permissions for specific regions)
     regional_authorities: StorageMap<String, StorageVec<Address>>,
     regional_authority_count: StorageMap<String, U256>,
+    region_awaiting_projects: StorageMap<String, U256>, // region -> projects with declared regions not yet finalized
+    region_capacity: StorageMap<String, U256>, // region -> max validators (0 = unlimited)
+    max_regions_per_validator: StorageU256, // expertise regions a single validator may claim
     
     // Project validations
     project_validations: StorageMap<U256, ValidationResult>,
+    project_regions: StorageMap<U256, StorageVec<String>>, // project -> declared cultural regions, set by the platform
+    region_declared_projects: StorageVec<U256>, // projects in order of first region declaration
+    project_languages: StorageMap<U256, StorageVec<String>>, // project -> declared content languages
+    language_projects: StorageMap<String, StorageVec<U256>>, // language -> projects, for discovery
     project_submissions: StorageMap<U256, StorageVec<ValidationSubmission>>,
     validator_project_submissions: StorageMap<U256, StorageMap<Address, ValidationSubmission>>,
+    validation_queue: StorageVec<U256>, // projects in order of first submission
+    pending_validation: StorageMap<U256, bool>, // project -> has submissions but not finalized
+    validation_deadlines: StorageMap<U256, U256>, // project -> when validators must have submitted (0 = none)
+    validation_window: StorageU256, // time validators get once validation opens (0 = no deadline)
+    emergency_min_validators: StorageU256, // submissions needed to force-finalize after the deadline
     
     // Validator performance tracking
     validator_reputation: StorageMap<Address, U256>,
+    validator_credential_tier: StorageMap<Address, U256>, // attested credential tier (0 = none)
+    credential_start_reputation: StorageMap<U256, U256>, // tier -> starting reputation
     validator_accuracy_history: StorageMap<Address, StorageVec<U256>>, // Success rates over time
     validation_history: StorageMap<Address, StorageVec<U256>>, // Projects validated
+    last_validation_at: StorageMap<Address, U256>, // validator -> timestamp of latest submitted validation
+    min_activity_period: StorageU256, // validators idle longer than this are dormant (0 = no requirement)
     
     // Appeals system
     appeals: StorageMap<U256, Appeal>,
     project_appeals: StorageMap<U256, StorageVec<U256>>, // project -> appeal_ids
+    open_appeals: StorageMap<U256, StorageMap<Address, bool>>, // project -> challenger -> has pending appeal
+    open_appeal_count: StorageMap<U256, U256>, // project -> pending appeals across all challengers
+    challenger_appeals: StorageMap<Address, StorageVec<U256>>, // challenger -> appeal_ids
     next_appeal_id: StorageU256,
     
     // Platform integration
     platform_contract: StorageAddress,
+    governance_contract: StorageAddress,
     
     // Validation settings
     min_validators_required: StorageU256,
     validation_threshold_score: StorageU256,
     validator_reward_amount: StorageU256,
+    participation_reward_bps: StorageU256, // share of each reward paid just for submitting
+    accuracy_reward_bps: StorageU256, // share of each reward scaled by accuracy against consensus
+    validator_rewards_earned: StorageMap<Address, U256>,
+    accrued_rewards: StorageMap<Address, U256>, // validator -> rewards credited but not yet claimed
+    project_funded_rewards: StorageMap<U256, U256>, // project -> rewards paid in from the project's own escrow
     stake_requirement: StorageU256,
+    registration_fee: StorageU256, // Non-refundable, routed to the reward pool
+    reward_pool: StorageU256,
     appeal_period: StorageU256, // Time window for appeals
+    appeal_extensions: StorageMap<U256, U256>, // projectId -> one-time extra appeal window
     dispute_resolution_period: StorageU256,
     
     // Access control
@@ -91,8 +131,12 @@ pub struct CulturalValidator {
     
     // Slashing and penalties
     slashing_penalties: StorageMap<Address, U256>, // validator -> penalty amount
+    slashing_recipient: StorageAddress, // Treasury receiving slashed stake (zero = reward pool)
     validator_suspension_status: StorageMap<Address, bool>,
     suspension_end_times: StorageMap<Address, U256>,
+    
+    // Reentrancy guard
+    locked: StorageBool,
 }
 
 #[public]
@@ -100,7 +144,7 @@ impl CulturalValidator {
     pub fn initialize(&mut self, platform_contract: Address) -> Result<()> {
         require_valid_input(self.owner.get().is_zero(), "Already initialized")?;
         
-        let caller = msg::sender();
+        let caller = env::sender();
         self.owner.set(caller);
         self.platform_contract.set(platform_contract);
         
@@ -108,10 +152,14 @@ impl CulturalValidator {
         self.min_validators_required.set(U256::from(MIN_VALIDATORS_REQUIRED));
         self.validation_threshold_score.set(U256::from(VALIDATION_THRESHOLD));
         self.validator_reward_amount.set(U256::from(10000000000000000u64)); // 0.01 ETH
+        self.participation_reward_bps.set(U256::from(5000));
+        self.accuracy_reward_bps.set(U256::from(5000));
         self.stake_requirement.set(U256::from(100000000000000000u64)); // 0.1 ETH
         self.appeal_period.set(U256::from(7 * 24 * 3600)); // 7 days
         self.dispute_resolution_period.set(U256::from(14 * 24 * 3600)); // 14 days
         self.next_appeal_id.set(U256::from(1));
+        self.max_regions_per_validator.set(U256::from(DEFAULT_MAX_REGIONS_PER_VALIDATOR));
+        self.emergency_min_validators.set(U256::from(1));
         
         // Initialize cultural database
         self.initialize_cultural_database();
@@ -126,42 +174,55 @@ impl CulturalValidator {
         regions: Vec<String>,
         credentials_uri: String,
     ) -> Result<bool> {
-        let validator = msg::sender();
-        let stake = msg::value();
+        let validator = env::sender();
+        let value = env::value();
+        let fee = self.registration_fee.get();
         
         require_valid_input(
-            stake >= self.stake_requirement.get(),
+            value >= self.stake_requirement.get() + fee,
             "Insufficient stake amount"
         )?;
+        let stake = value - fee;
         require_valid_input(
             self.validators.get(validator).validator_address.is_zero(),
             "Validator already registered"
         )?;
         require_valid_input(!regions.is_empty(), "Must specify at least one region")?;
+        require_valid_input(
+            U256::from(regions.len()) <= self.max_regions_per_validator.get(),
+            "Too many regions"
+        )?;
         
-        // Validate regions are supported
+        // Validate regions are supported and have room
         for region in &regions {
             require_valid_input(
                 self.is_supported_region(region),
                 "Unsupported region"
             )?;
+            self.require_region_capacity(region)?;
         }
         
+        let starting_reputation = self.starting_reputation_for(validator);
+        
         let profile = ValidatorProfile {
             validator_address: validator,
             ens_name: ens_name.clone(),
             expertise_regions: regions.clone(),
             credentials_uri,
-            reputation_score: U256::from(100), // Starting reputation
+            reputation_score: starting_reputation,
             validations_completed: U256::from(0),
             is_active: true,
             stake_amount: stake,
-            registration_timestamp: U256::from(block::timestamp()),
+            registration_timestamp: U256::from(env::timestamp()),
         };
         
         self.validators.insert(validator, profile);
         self.validator_stakes.insert(validator, stake);
-        self.validator_reputation.insert(validator, U256::from(100));
+        self.total_validator_stake.set(self.total_validator_stake.get() + stake);
+        self.validator_reputation.insert(validator, starting_reputation);
+        self.validator_primary_region.insert(validator, regions[0].clone());
+        // Validators vote with their stake
+        self.sync_validator_voting_power(validator, stake)?;
         
         // Add to regional expertise
         let mut validator_regions_storage = self.validator_regions.get_mut(validator);
@@ -173,6 +234,7 @@ impl CulturalValidator {
         }
         
         self.validator_count.set(self.validator_count.get() + U256::from(1));
+        self.reward_pool.set(self.reward_pool.get() + fee);
 
         evm::log(ValidatorRegistered {
             validator,
@@ -184,6 +246,119 @@ impl CulturalValidator {
         Ok(true)
     }
 
+    pub fn add_expertise_region(&mut self, region: String) -> Result<()> {
+        let validator = env::sender();
+        
+        let mut profile = self.validators.get(validator);
+        require_valid_input(!profile.validator_address.is_zero(), "Validator not registered")?;
+        require_valid_input(self.is_supported_region(&region), "Unsupported region")?;
+        require_valid_input(
+            !profile.expertise_regions.contains(&region),
+            "Region already added"
+        )?;
+        require_valid_input(
+            U256::from(profile.expertise_regions.len()) < self.max_regions_per_validator.get(),
+            "Too many regions"
+        )?;
+        self.require_region_capacity(&region)?;
+        
+        profile.expertise_regions.push(region.clone());
+        self.validators.insert(validator, profile);
+        
+        self.validator_regions.get_mut(validator).push(region.clone());
+        self.regional_authorities.get_mut(region.clone()).push(validator);
+        let count = self.regional_authority_count.get(region.clone());
+        self.regional_authority_count.insert(region, count + U256::from(1));
+        
+        Ok(())
+    }
+
+    pub fn set_primary_region(&mut self, region: String) -> Result<()> {
+        let validator = env::sender();
+        
+        let profile = self.validators.get(validator);
+        require_valid_input(!profile.validator_address.is_zero(), "Validator not registered")?;
+        require_valid_input(
+            profile.expertise_regions.contains(&region),
+            "Region not in validator expertise"
+        )?;
+        
+        self.validator_primary_region.insert(validator, region);
+        Ok(())
+    }
+
+    pub fn set_project_regions(&mut self, project_id: U256, regions: Vec<String>) -> Result<()> {
+        let caller = env::sender();
+        require_authorized(
+            caller == self.platform_contract.get() || caller == self.owner.get() || self.admins.get(caller),
+            "Not authorized"
+        )?;
+        require_valid_input(!regions.is_empty(), "Must specify at least one region")?;
+        require_valid_input(
+            !self.pending_validation.get(project_id)
+                && self.project_validations.get(project_id).project_id == U256::from(0),
+            "Validation already started"
+        )?;
+        for region in &regions {
+            require_valid_input(self.is_supported_region(region), "Unsupported region")?;
+        }
+        
+        if self.project_regions.get(project_id).is_empty() {
+            self.region_declared_projects.push(project_id);
+        }
+        
+        // Re-declaring regions moves the project's demand to the new regions
+        self.adjust_region_demand(project_id, false);
+        let mut project_regions = self.project_regions.get_mut(project_id);
+        project_regions.erase();
+        for region in regions {
+            project_regions.push(region);
+        }
+        self.adjust_region_demand(project_id, true);
+        Ok(())
+    }
+
+    pub fn request_validation(&mut self, project_id: U256) -> Result<U256> {
+        let caller = env::sender();
+        require_authorized(
+            caller == self.platform_contract.get() || caller == self.owner.get() || self.admins.get(caller),
+            "Not authorized"
+        )?;
+        require_valid_input(
+            !self.pending_validation.get(project_id)
+                && self.project_validations.get(project_id).project_id == U256::from(0),
+            "Validation already started"
+        )?;
+        
+        // The deadline runs from the request, so a project nobody picks up can still be settled
+        self.open_validation(project_id);
+        Ok(self.validation_deadlines.get(project_id))
+    }
+
+    pub fn declare_project_language(&mut self, project_id: U256, language: String) -> Result<()> {
+        let caller = env::sender();
+        require_authorized(
+            caller == self.platform_contract.get() || caller == self.owner.get() || self.admins.get(caller),
+            "Not authorized"
+        )?;
+        
+        // The language must belong to one of the project's declared regions
+        let regions = Self::collect_entries(&self.project_regions.get(project_id));
+        require_valid_input(!regions.is_empty(), "Project regions not set")?;
+        let in_region = regions.iter().any(|region| {
+            Self::collect_entries(&self.language_families.get(region.clone())).contains(&language)
+        });
+        require_valid_input(in_region, "Language not spoken in project regions")?;
+        require_valid_input(
+            !Self::collect_entries(&self.project_languages.get(project_id)).contains(&language),
+            "Language already declared"
+        )?;
+        
+        self.project_languages.get_mut(project_id).push(language.clone());
+        self.language_projects.get_mut(language).push(project_id);
+        Ok(())
+    }
+
     pub fn submit_validation(
         &mut self,
         project_id: U256,
@@ -191,7 +366,7 @@ impl CulturalValidator {
         feedback_uri: String,
         cultural_elements: Vec<String>,
     ) -> Result<()> {
-        let validator = msg::sender();
+        let validator = env::sender();
         
         // Verify validator is registered and active
         let validator_profile = self.validators.get(validator);
@@ -214,6 +389,10 @@ impl CulturalValidator {
             existing_submission.validator.is_zero(),
             "Validation already submitted"
         )?;
+        require_valid_input(
+            self.project_submissions.get(project_id).is_empty() || self.pending_validation.get(project_id),
+            "Validation already finalized"
+        )?;
         
         // Verify validator has expertise in project's cultural region
         // (Would check with platform contract in production)
@@ -224,7 +403,7 @@ impl CulturalValidator {
             score,
             feedback_uri,
             cultural_elements,
-            timestamp: U256::from(block::timestamp()),
+            timestamp: U256::from(env::timestamp()),
             is_final: false,
         };
         
@@ -234,10 +413,18 @@ impl CulturalValidator {
         
         // Add to validator's history
         self.validation_history.get_mut(validator).push(project_id);
+        self.last_validation_at.insert(validator, U256::from(env::timestamp()));
+        
+        // Projects not explicitly requested open on their first submission
+        if self.project_submissions.get(project_id).len() == 1 && !self.pending_validation.get(project_id) {
+            self.open_validation(project_id);
+        }
         
         // Check if we have enough validations to finalize
         let submissions = self.project_submissions.get(project_id);
-        if submissions.len() >= self.min_validators_required.get().as_usize() {
+        if submissions.len() >= self.min_validators_required.get().as_usize()
+            && self.uncovered_regions(project_id).is_empty()
+        {
             self.finalize_validation(project_id)?;
         }
 
@@ -251,79 +438,188 @@ impl CulturalValidator {
         Ok(())
     }
 
-    pub fn finalize_validation(&mut self, project_id: U256) -> Result<U256> {
-        let submissions = self.project_submissions.get(project_id);
+    pub fn update_validation_submission(
+        &mut self,
+        project_id: U256,
+        new_score: U256,
+        new_feedback_uri: String,
+        new_elements: Vec<String>,
+    ) -> Result<()> {
+        let validator = env::sender();
+        
+        require_valid_input(new_score <= U256::from(100), "Score must be 0-100")?;
+        
+        let existing_submission = self.validator_project_submissions.get(project_id).get(validator);
         require_valid_input(
-            submissions.len() >= self.min_validators_required.get().as_usize(),
-            "Insufficient validator submissions"
+            !existing_submission.validator.is_zero(),
+            "No submission to update"
+        )?;
+        require_valid_input(
+            self.pending_validation.get(project_id),
+            "Validation already finalized"
         )?;
         
-        // Calculate weighted average score
-        let mut total_score = U256::from(0);
-        let mut total_weight = U256::from(0);
-        let mut validator_count = 0;
+        let submission = ValidationSubmission {
+            validator,
+            score: new_score,
+            feedback_uri: new_feedback_uri,
+            cultural_elements: new_elements,
+            timestamp: U256::from(env::timestamp()),
+            is_final: false,
+        };
+        
+        // Rebuild the project's submission list with the edited entry in place
+        let submissions = self.project_submissions.get(project_id);
+        let mut updated = Vec::new();
+        for i in 0..submissions.len() {
+            if let Some(existing) = submissions.get(i) {
+                if existing.validator == validator {
+                    updated.push(submission.clone());
+                } else {
+                    updated.push(existing);
+                }
+            }
+        }
+        
+        self.project_submissions.get_mut(project_id).erase();
+        for entry in updated {
+            self.project_submissions.get_mut(project_id).push(entry);
+        }
+        self.validator_project_submissions.get_mut(project_id).insert(validator, submission);
+
+        evm::log(ProjectValidated {
+            project_id,
+            validator,
+            score: new_score,
+            approved: new_score >= self.validation_threshold_score.get(),
+        });
+
+        Ok(())
+    }
+
+    pub fn claim_validator_rewards(&mut self) -> Result<U256> {
+        self.nonreentrant_guard()?;
+        
+        let validator = env::sender();
+        let amount = self.accrued_rewards.get(validator);
+        require_valid_input(amount > U256::from(0), "No rewards to claim")?;
+        
+        // Clear the accrual before transferring
+        self.accrued_rewards.insert(validator, U256::from(0));
+        env::transfer_eth(validator, amount)?;
+
+        evm::log(ValidatorRewardsClaimed {
+            validator,
+            amount,
+            timestamp: U256::from(env::timestamp()),
+        });
+
+        self.unlock_guard();
+        Ok(amount)
+    }
+
+    #[payable]
+    pub fn fund_project_validation_rewards(&mut self, project_id: U256) -> Result<U256> {
+        let amount = env::value();
+        require_valid_input(amount > U256::from(0), "Reward amount must be positive")?;
+        require_valid_input(
+            self.project_validations.get(project_id).project_id != U256::from(0),
+            "Validation not finalized"
+        )?;
         
+        let submissions = self.project_submissions.get(project_id);
+        let mut validators = Vec::new();
         for i in 0..submissions.len() {
             if let Some(submission) = submissions.get(i) {
-                let validator_reputation = self.validator_reputation.get(submission.validator);
-                let weight = validator_reputation; // Use reputation as weight
-                
-                total_score += submission.score * weight;
-                total_weight += weight;
-                validator_count += 1;
+                validators.push(submission.validator);
             }
         }
+        require_valid_input(!validators.is_empty(), "No validators to reward")?;
         
-        let final_score = if total_weight > U256::from(0) {
-            total_score / total_weight
-        } else {
-            U256::from(0)
-        };
+        // Split evenly across the project's validators; the remainder tops up the pool
+        let share = amount / U256::from(validators.len());
+        for validator in &validators {
+            let earned = self.validator_rewards_earned.get(*validator);
+            self.validator_rewards_earned.insert(*validator, earned + share);
+            self.accrued_rewards.insert(*validator, self.accrued_rewards.get(*validator) + share);
+        }
+        let remainder = amount - share * U256::from(validators.len());
+        self.reward_pool.set(self.reward_pool.get() + remainder);
+        self.project_funded_rewards.insert(project_id, self.project_funded_rewards.get(project_id) + amount);
+
+        evm::log(ProjectValidationRewardsFunded {
+            project_id,
+            amount,
+            validator_count: U256::from(validators.len()),
+        });
+
+        Ok(share)
+    }
+
+    pub fn finalize_validation(&mut self, project_id: U256) -> Result<U256> {
+        // Finalizing pays rewards out of the pool, so a settled project cannot be finalized again
+        require_valid_input(self.pending_validation.get(project_id), "Validation not pending")?;
         
-        // Determine validation status
-        let approved = final_score >= self.validation_threshold_score.get();
-        let status = if approved { 1u8 } else { 2u8 }; // Approved or Rejected
+        // Every declared region needs at least one submitting validator with expertise in it
+        require_valid_input(
+            self.uncovered_regions(project_id).is_empty(),
+            "Not all project regions covered"
+        )?;
+        
+        require_valid_input(
+            self.project_submissions.get(project_id).len() >= self.min_validators_required.get().as_usize(),
+            "Insufficient validator submissions"
+        )?;
+        
+        self.complete_validation(project_id)
+    }
+
+    pub fn force_finalize_validation(&mut self, project_id: U256) -> Result<U256> {
+        self.require_admin()?;
+        require_valid_input(self.pending_validation.get(project_id), "Validation not pending")?;
+        
+        let deadline = self.validation_deadlines.get(project_id);
+        require_valid_input(deadline > U256::from(0), "No validation deadline")?;
+        require_valid_input(
+            U256::from(env::timestamp()) > deadline,
+            "Validation deadline not reached"
+        )?;
+        
+        // Past the deadline, a smaller emergency quorum is enough to settle the project,
+        // but as in finalize_validation every declared region must still be covered
+        let submission_count = self.project_submissions.get(project_id).len();
+        if submission_count >= self.emergency_min_validators.get().as_usize()
+            && self.uncovered_regions(project_id).is_empty()
+        {
+            return self.complete_validation(project_id);
+        }
         
+        // Too few validators, or none for some region: the project fails validation outright
         let result = ValidationResult {
             project_id,
-            final_score,
-            status,
-            validator_count: U256::from(validator_count),
-            completed_timestamp: U256::from(block::timestamp()),
+            final_score: U256::from(0),
+            status: 2, // Rejected
+            validator_count: U256::from(submission_count),
+            completed_timestamp: U256::from(env::timestamp()),
             can_appeal: true,
         };
-        
         self.project_validations.insert(project_id, result);
-        
-        // Update metrics
+        self.pending_validation.insert(project_id, false);
+        self.adjust_region_demand(project_id, false);
         self.total_validations_completed.set(self.total_validations_completed.get() + U256::from(1));
-        if approved {
-            self.total_projects_approved.set(self.total_projects_approved.get() + U256::from(1));
-        } else {
-            self.total_projects_rejected.set(self.total_projects_rejected.get() + U256::from(1));
-        }
-        
-        // Update average score
-        self.update_average_validation_score(final_score);
-        
-        // Reward validators
-        self.distribute_validator_rewards(project_id)?;
-        
-        // Update validator reputations based on consensus
-        self.update_validator_reputations(project_id, final_score)?;
+        self.total_projects_rejected.set(self.total_projects_rejected.get() + U256::from(1));
 
-        evm::log(ValidationCompleted {
+        evm::log(ValidationDeadlineMissed {
             project_id,
-            final_score,
-            approved,
-            timestamp: U256::from(block::timestamp()),
+            submissions: U256::from(submission_count),
+            deadline,
         });
 
-        Ok(final_score)
+        Ok(U256::from(0))
     }
 
     pub fn challenge_validation(&mut self, project_id: U256, reason: String, evidence_uri: String) -> Result<U256> {
-        let challenger = msg::sender();
+        let challenger = env::sender();
         
         let validation_result = self.project_validations.get(project_id);
         require_valid_input(
@@ -332,9 +628,17 @@ impl CulturalValidator {
         )?;
         require_valid_input(validation_result.can_appeal, "Appeals not allowed")?;
         require_valid_input(
-            U256::from(block::timestamp()) <= validation_result.completed_timestamp + self.appeal_period.get(),
+            U256::from(env::timestamp()) <= self.appeal_deadline(project_id, &validation_result),
             "Appeal period expired"
         )?;
+        require_valid_input(
+            self.validator_project_submissions.get(project_id).get(challenger).validator.is_zero(),
+            "Validator cannot appeal own validation"
+        )?;
+        require_valid_input(
+            !self.open_appeals.get(project_id).get(challenger),
+            "Appeal already pending"
+        )?;
         
         let appeal_id = self.next_appeal_id.get();
         
@@ -345,19 +649,28 @@ impl CulturalValidator {
             reason,
             evidence_uri,
             status: 0, // Pending
-            created_timestamp: U256::from(block::timestamp()),
+            created_timestamp: U256::from(env::timestamp()),
             resolution_timestamp: U256::from(0),
             resolution_notes: String::new(),
         };
         
         self.appeals.insert(appeal_id, appeal);
         self.project_appeals.get_mut(project_id).push(appeal_id);
+        self.open_appeals.get_mut(project_id).insert(challenger, true);
+        self.open_appeal_count.insert(project_id, self.open_appeal_count.get(project_id) + U256::from(1));
+        self.challenger_appeals.get_mut(challenger).push(appeal_id);
         self.next_appeal_id.set(appeal_id + U256::from(1));
         
         Ok(appeal_id)
     }
 
-    pub fn resolve_appeal(&mut self, appeal_id: U256, upheld: bool, resolution_notes: String) -> Result<()> {
+    pub fn resolve_appeal(
+        &mut self,
+        appeal_id: U256,
+        upheld: bool,
+        reopen: bool,
+        resolution_notes: String,
+    ) -> Result<()> {
         self.require_admin()?;
         
         let mut appeal = self.appeals.get(appeal_id);
@@ -365,19 +678,26 @@ impl CulturalValidator {
         require_valid_input(appeal.status == 0, "Appeal already resolved")?;
         
         appeal.status = if upheld { 1 } else { 2 }; // Upheld or Rejected
-        appeal.resolution_timestamp = U256::from(block::timestamp());
+        appeal.resolution_timestamp = U256::from(env::timestamp());
         appeal.resolution_notes = resolution_notes;
         
         self.appeals.insert(appeal_id, appeal.clone());
+        self.open_appeals.get_mut(appeal.project_id).insert(appeal.challenger, false);
+        self.open_appeal_count.insert(appeal.project_id, self.open_appeal_count.get(appeal.project_id) - U256::from(1));
         
-        // If upheld, reverse the validation decision
         if upheld {
-            let mut validation_result = self.project_validations.get(appeal.project_id);
-            validation_result.status = if validation_result.status == 1 { 2 } else { 1 }; // Flip decision
-            self.project_validations.insert(appeal.project_id, validation_result);
-            
-            // Penalize validators who were wrong
+            // Penalize validators who were wrong, before any submissions are cleared
             self.penalize_inaccurate_validators(appeal.project_id)?;
+            
+            if reopen {
+                // Send the project back for a fresh round of validation
+                self.reopen_validation(appeal.project_id);
+            } else {
+                // Reverse the validation decision
+                let mut validation_result = self.project_validations.get(appeal.project_id);
+                validation_result.status = if validation_result.status == 1 { 2 } else { 1 }; // Flip decision
+                self.project_validations.insert(appeal.project_id, validation_result);
+            }
         }
         
         Ok(())
@@ -390,14 +710,122 @@ impl CulturalValidator {
         Ok(result)
     }
 
+    pub fn has_open_appeal(&self, project_id: U256) -> bool {
+        self.open_appeal_count.get(project_id) > U256::from(0)
+    }
+
+    pub fn get_pending_validation(&self, project_id: U256) -> (U256, U256, U256) {
+        let submissions = self.project_submissions.get(project_id);
+        
+        // Count distinct expertise regions represented by the submitting validators
+        let mut regions: Vec<String> = Vec::new();
+        for i in 0..submissions.len() {
+            if let Some(submission) = submissions.get(i) {
+                let validator_regions = self.validator_regions.get(submission.validator);
+                for j in 0..validator_regions.len() {
+                    if let Some(region) = validator_regions.get(j) {
+                        if !regions.contains(&region) {
+                            regions.push(region);
+                        }
+                    }
+                }
+            }
+        }
+        
+        (
+            U256::from(submissions.len()),
+            self.min_validators_required.get(),
+            U256::from(regions.len()),
+        )
+    }
+
+    pub fn get_projects_awaiting_validation(&self, offset: U256, limit: U256) -> Vec<U256> {
+        let offset = offset.as_usize();
+        let limit = limit.as_usize();
+        let mut skipped = 0;
+        let mut result = Vec::new();
+        
+        for i in 0..self.validation_queue.len() {
+            if result.len() >= limit {
+                break;
+            }
+            if let Some(project_id) = self.validation_queue.get(i) {
+                if !self.pending_validation.get(project_id) {
+                    continue;
+                }
+                if skipped < offset {
+                    skipped += 1;
+                    continue;
+                }
+                result.push(project_id);
+            }
+        }
+        
+        result
+    }
+
+    pub fn get_validator_assignments(&self, validator: Address) -> Vec<U256> {
+        let mut result = Vec::new();
+        // Dormant validators aren't qualified, so nothing is assigned to them either
+        if !self.is_listed_validator(validator) || self.is_dormant(validator) {
+            return result;
+        }
+        
+        // Projects with declared regions plus any that entered the queue without them
+        let mut candidates = Vec::new();
+        for i in 0..self.region_declared_projects.len() {
+            if let Some(project_id) = self.region_declared_projects.get(i) {
+                candidates.push(project_id);
+            }
+        }
+        for i in 0..self.validation_queue.len() {
+            if let Some(project_id) = self.validation_queue.get(i) {
+                if !candidates.contains(&project_id) {
+                    candidates.push(project_id);
+                }
+            }
+        }
+        
+        let expertise = Self::collect_entries(&self.validator_regions.get(validator));
+        for project_id in candidates {
+            let finalized = !self.pending_validation.get(project_id)
+                && self.project_validations.get(project_id).project_id != U256::from(0);
+            let scored = !self.validator_project_submissions.get(project_id).get(validator).validator.is_zero();
+            if finalized || scored {
+                continue;
+            }
+            
+            let regions = Self::collect_entries(&self.project_regions.get(project_id));
+            if regions.is_empty() || regions.iter().any(|region| expertise.contains(region)) {
+                result.push(project_id);
+            }
+        }
+        
+        result
+    }
+
+    pub fn get_projects_by_language(&self, language: String, offset: U256, limit: U256) -> Vec<U256> {
+        let projects = self.language_projects.get(language);
+        let start = offset.as_usize();
+        let end = core::cmp::min(start.saturating_add(limit.as_usize()), projects.len());
+        let mut result = Vec::new();
+        
+        for i in start..end {
+            if let Some(project_id) = projects.get(i) {
+                result.push(project_id);
+            }
+        }
+        
+        result
+    }
+
     pub fn get_qualified_validators(&self, cultural_region: String) -> Vec<Address> {
         let authorities = self.regional_authorities.get(cultural_region);
         let mut result = Vec::new();
         
         for i in 0..authorities.len() {
             if let Some(validator) = authorities.get(i) {
-                let profile = self.validators.get(validator);
-                if profile.is_active && !self.validator_suspension_status.get(validator) {
+                if self.is_listed_validator(validator) && !self.is_dormant(validator) {
                     result.push(validator);
                 }
             }
@@ -406,6 +834,47 @@ impl CulturalValidator {
         result
     }
 
+    pub fn get_region_validators(&self, region: String, offset: U256, limit: U256) -> Vec<ValidatorProfile> {
+        let authorities = self.regional_authorities.get(region);
+        let offset = offset.as_usize();
+        let limit = limit.as_usize();
+        let mut skipped = 0;
+        let mut result = Vec::new();
+        
+        for i in 0..authorities.len() {
+            if result.len() >= limit {
+                break;
+            }
+            if let Some(validator) = authorities.get(i) {
+                if !self.is_listed_validator(validator) {
+                    continue;
+                }
+                if skipped < offset {
+                    skipped += 1;
+                    continue;
+                }
+                result.push(self.validators.get(validator));
+            }
+        }
+        
+        result
+    }
+
+    pub fn region_validator_count(&self, region: String) -> U256 {
+        let authorities = self.regional_authorities.get(region);
+        let mut count = 0u64;
+        
+        for i in 0..authorities.len() {
+            if let Some(validator) = authorities.get(i) {
+                if self.is_listed_validator(validator) {
+                    count += 1;
+                }
+            }
+        }
+        
+        U256::from(count)
+    }
+
     pub fn get_validator_profile(&self, validator: Address) -> Result<ValidatorProfile> {
         let profile = self.validators.get(validator);
         require_valid_input(!profile.validator_address.is_zero(), "Validator not found")?;
@@ -425,27 +894,343 @@ impl CulturalValidator {
         result
     }
 
-    pub fn validator_stats(&self) -> (U256, U256, U256, U256) {
-        (
-            self.validator_count.get(),
-            self.total_validations_completed.get(),
-            self.total_projects_approved.get(),
-            self.average_validation_score.get(),
-        )
+    pub fn get_cultural_elements(&self, region: String) -> Vec<String> {
+        Self::collect_entries(&self.cultural_elements_db.get(region))
     }
 
-    // Admin functions
+    pub fn get_traditional_practices(&self, region: String) -> Vec<String> {
+        Self::collect_entries(&self.traditional_practices.get(region))
+    }
+
+    pub fn get_language_families(&self, region: String) -> Vec<String> {
+        Self::collect_entries(&self.language_families.get(region))
+    }
+
+    pub fn get_challenger_appeals(&self, challenger: Address) -> Vec<U256> {
+        let appeal_ids = self.challenger_appeals.get(challenger);
+        let mut result = Vec::new();
+        for i in 0..appeal_ids.len() {
+            if let Some(appeal_id) = appeal_ids.get(i) {
+                result.push(appeal_id);
+            }
+        }
+        result
+    }
+
+    pub fn get_appeal_deadline(&self, project_id: U256) -> Result<U256> {
+        let validation_result = self.project_validations.get(project_id);
+        require_valid_input(validation_result.project_id != U256::from(0), "Project not validated")?;
+        Ok(self.appeal_deadline(project_id, &validation_result))
+    }
+
+    pub fn get_region_demand(&self, region: String) -> (U256, U256, U256) {
+        let awaiting = self.region_awaiting_projects.get(region.clone());
+        let validators = self.regional_authority_count.get(region);
+        
+        // Awaiting projects per qualified validator, scaled by 100; a region with
+        // demand but no validators reports the maximum shortage
+        let ratio = if validators > U256::from(0) {
+            awaiting * U256::from(100) / validators
+        } else if awaiting > U256::from(0) {
+            U256::MAX
+        } else {
+            U256::from(0)
+        };
+        
+        (awaiting, validators, ratio)
+    }
+
+    pub fn get_region_capacity(&self, region: String) -> (U256, U256) {
+        (
+            self.regional_authority_count.get(region.clone()),
+            self.region_capacity.get(region),
+        )
+    }
+
+    pub fn validator_reward_amount(&self) -> U256 {
+        self.validator_reward_amount.get()
+    }
+
+    pub fn get_reward_split(&self) -> (U256, U256) {
+        (self.participation_reward_bps.get(), self.accuracy_reward_bps.get())
+    }
+
+    pub fn preview_validator_reward(&self, score: U256, consensus_score: U256) -> U256 {
+        self.validator_reward_for(score, consensus_score)
+    }
+
+    pub fn get_validator_rewards_earned(&self, validator: Address) -> U256 {
+        self.validator_rewards_earned.get(validator)
+    }
+
+    pub fn get_project_funded_rewards(&self, project_id: U256) -> U256 {
+        self.project_funded_rewards.get(project_id)
+    }
+
+    pub fn get_accrued_rewards(&self, validator: Address) -> U256 {
+        self.accrued_rewards.get(validator)
+    }
+
+    pub fn registration_requirements(&self) -> (U256, U256) {
+        (self.stake_requirement.get(), self.registration_fee.get())
+    }
+
+    pub fn reward_pool_balance(&self) -> U256 {
+        self.reward_pool.get()
+    }
+
+    pub fn get_validator_reputation(&self, validator: Address) -> U256 {
+        self.validator_reputation.get(validator)
+    }
+
+    pub fn get_project_regions(&self, project_id: U256) -> Vec<String> {
+        Self::collect_entries(&self.project_regions.get(project_id))
+    }
+
+    pub fn get_project_languages(&self, project_id: U256) -> Vec<String> {
+        Self::collect_entries(&self.project_languages.get(project_id))
+    }
+
+    pub fn get_uncovered_regions(&self, project_id: U256) -> Vec<String> {
+        self.uncovered_regions(project_id)
+    }
+
+    pub fn get_primary_region(&self, validator: Address) -> String {
+        self.validator_primary_region.get(validator)
+    }
+
+    pub fn get_validator_weight(&self, validator: Address, project_id: U256) -> U256 {
+        self.validator_weight(validator, project_id)
+    }
+
+    pub fn is_validator_dormant(&self, validator: Address) -> bool {
+        self.is_dormant(validator)
+    }
+
+    pub fn min_activity_period(&self) -> U256 {
+        self.min_activity_period.get()
+    }
+
+    pub fn get_validator_stake(&self, validator: Address) -> U256 {
+        self.validator_stakes.get(validator)
+    }
+
+    pub fn get_stake_boost_bps(&self) -> U256 {
+        self.stake_boost_bps.get()
+    }
+
+    pub fn get_total_validator_stake(&self) -> U256 {
+        self.total_validator_stake.get()
+    }
+
+    pub fn validator_stats(&self) -> (U256, U256, U256, U256) {
+        (
+            self.validator_count.get(),
+            self.total_validations_completed.get(),
+            self.total_projects_approved.get(),
+            self.average_validation_score.get(),
+        )
+    }
+
+    // Admin functions
     pub fn add_admin(&mut self, admin: Address) -> Result<()> {
         self.require_owner()?;
         self.admins.insert(admin, true);
         Ok(())
     }
 
+    pub fn set_governance_contract(&mut self, governance: Address) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(!governance.is_zero(), "Invalid governance address")?;
+        self.governance_contract.set(governance);
+        Ok(())
+    }
+
+    pub fn set_validator_reward_amount(&mut self, amount: U256) -> Result<()> {
+        self.require_governance()?;
+        require_valid_input(
+            amount <= U256::from(MAX_VALIDATOR_REWARD),
+            "Reward amount too high"
+        )?;
+        
+        let old_amount = self.validator_reward_amount.get();
+        self.validator_reward_amount.set(amount);
+
+        evm::log(ValidatorRewardRateChanged {
+            old_amount,
+            new_amount: amount,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_reward_split(&mut self, participation_bps: U256, accuracy_bps: U256) -> Result<()> {
+        self.require_governance()?;
+        require_valid_input(
+            participation_bps + accuracy_bps == U256::from(10000),
+            "Reward split must total 10000 bps"
+        )?;
+        self.participation_reward_bps.set(participation_bps);
+        self.accuracy_reward_bps.set(accuracy_bps);
+        Ok(())
+    }
+
+    pub fn set_credential_start_reputation(&mut self, tier: U256, reputation: U256) -> Result<()> {
+        self.require_owner()?;
+        require_valid_input(tier > U256::from(0), "Tier 0 is reserved for uncredentialed validators")?;
+        require_valid_input(
+            reputation <= U256::from(MAX_START_REPUTATION),
+            "Starting reputation too high"
+        )?;
+        self.credential_start_reputation.insert(tier, reputation);
+        Ok(())
+    }
+
+    pub fn attest_validator_credentials(&mut self, validator: Address, tier: U256) -> Result<()> {
+        self.require_admin()?;
+        require_valid_input(
+            self.validators.get(validator).validator_address.is_zero(),
+            "Validator already registered"
+        )?;
+        self.validator_credential_tier.insert(validator, tier);
+        Ok(())
+    }
+
+    pub fn set_stake_boost_bps(&mut self, boost_bps: U256) -> Result<()> {
+        self.require_admin()?;
+        require_valid_input(boost_bps <= U256::from(10000), "Stake boost too high")?;
+        self.stake_boost_bps.set(boost_bps);
+        Ok(())
+    }
+
+    pub fn set_min_activity_period(&mut self, period: U256) -> Result<()> {
+        self.require_admin()?;
+        self.min_activity_period.set(period);
+        Ok(())
+    }
+
+    pub fn set_slashing_recipient(&mut self, recipient: Address) -> Result<()> {
+        self.require_owner()?;
+        self.slashing_recipient.set(recipient);
+        Ok(())
+    }
+
+    pub fn set_registration_fee(&mut self, fee: U256) -> Result<()> {
+        self.require_owner()?;
+        self.registration_fee.set(fee);
+        Ok(())
+    }
+
+    pub fn add_cultural_element(&mut self, region: String, element: String) -> Result<()> {
+        self.require_admin()?;
+        require_valid_input(!region.is_empty() && !element.is_empty(), "Empty entry")?;
+        require_valid_input(
+            !Self::collect_entries(&self.cultural_elements_db.get(region.clone())).contains(&element),
+            "Entry already exists"
+        )?;
+        // Adding the first element for a region makes it a supported region
+        self.cultural_elements_db.get_mut(region).push(element);
+        Ok(())
+    }
+
+    pub fn add_traditional_practice(&mut self, region: String, practice: String) -> Result<()> {
+        self.require_admin()?;
+        require_valid_input(self.is_supported_region(&region), "Unsupported region")?;
+        require_valid_input(!practice.is_empty(), "Empty entry")?;
+        require_valid_input(
+            !Self::collect_entries(&self.traditional_practices.get(region.clone())).contains(&practice),
+            "Entry already exists"
+        )?;
+        self.traditional_practices.get_mut(region).push(practice);
+        Ok(())
+    }
+
+    pub fn add_language_family(&mut self, region: String, language: String) -> Result<()> {
+        self.require_admin()?;
+        require_valid_input(self.is_supported_region(&region), "Unsupported region")?;
+        require_valid_input(!language.is_empty(), "Empty entry")?;
+        require_valid_input(
+            !Self::collect_entries(&self.language_families.get(region.clone())).contains(&language),
+            "Entry already exists"
+        )?;
+        self.language_families.get_mut(region).push(language);
+        Ok(())
+    }
+
+    pub fn extend_appeal_window(&mut self, project_id: U256, additional_seconds: U256) -> Result<U256> {
+        self.require_admin()?;
+        
+        let validation_result = self.project_validations.get(project_id);
+        require_valid_input(validation_result.project_id != U256::from(0), "Project not validated")?;
+        require_valid_input(additional_seconds > U256::from(0), "Extension must be positive")?;
+        require_valid_input(
+            additional_seconds <= self.appeal_period.get(),
+            "Extension exceeds appeal period"
+        )?;
+        require_valid_input(
+            self.appeal_extensions.get(project_id) == U256::from(0),
+            "Appeal window already extended"
+        )?;
+        require_valid_input(
+            U256::from(env::timestamp()) <= self.appeal_deadline(project_id, &validation_result),
+            "Appeal period expired"
+        )?;
+        
+        self.appeal_extensions.insert(project_id, additional_seconds);
+        let new_deadline = self.appeal_deadline(project_id, &validation_result);
+        
+        evm::log(AppealWindowExtended {
+            project_id,
+            additional_seconds,
+            new_deadline,
+        });
+        
+        Ok(new_deadline)
+    }
+
+    pub fn set_region_capacity(&mut self, region: String, max_validators: U256) -> Result<()> {
+        self.require_admin()?;
+        require_valid_input(self.is_supported_region(&region), "Unsupported region")?;
+        self.region_capacity.insert(region, max_validators);
+        Ok(())
+    }
+
+    pub fn set_validation_window(&mut self, window: U256) -> Result<()> {
+        self.require_admin()?;
+        self.validation_window.set(window);
+        Ok(())
+    }
+
+    pub fn set_emergency_min_validators(&mut self, min_validators: U256) -> Result<()> {
+        self.require_admin()?;
+        require_valid_input(
+            min_validators >= U256::from(1) && min_validators <= self.min_validators_required.get(),
+            "Invalid emergency minimum"
+        )?;
+        self.emergency_min_validators.set(min_validators);
+        Ok(())
+    }
+
+    pub fn get_validation_deadline(&self, project_id: U256) -> U256 {
+        self.validation_deadlines.get(project_id)
+    }
+
+    pub fn set_max_regions_per_validator(&mut self, max_regions: U256) -> Result<()> {
+        self.require_admin()?;
+        require_valid_input(max_regions >= U256::from(1), "Must allow at least one region")?;
+        self.max_regions_per_validator.set(max_regions);
+        Ok(())
+    }
+
+    pub fn get_max_regions_per_validator(&self) -> U256 {
+        self.max_regions_per_validator.get()
+    }
+
     pub fn suspend_validator(&mut self, validator: Address, duration_days: U256) -> Result<()> {
         self.require_admin()?;
         
         self.validator_suspension_status.insert(validator, true);
-        let end_time = U256::from(block::timestamp()) + (duration_days * U256::from(86400));
+        let end_time = U256::from(env::timestamp()) + (duration_days * U256::from(86400));
         self.suspension_end_times.insert(validator, end_time);
         
         Ok(())
@@ -455,14 +1240,17 @@ impl CulturalValidator {
         self.require_admin()?;
         
         let current_stake = self.validator_stakes.get(validator);
-        let penalty = core::cmp::min(penalty_amount.as_u64(), current_stake.as_u64());
+        let penalty = core::cmp::min(penalty_amount, current_stake);
         
-        self.validator_stakes.insert(validator, current_stake - U256::from(penalty));
-        self.slashing_penalties.insert(validator, self.slashing_penalties.get(validator) + U256::from(penalty));
+        self.validator_stakes.insert(validator, current_stake - penalty);
+        self.total_validator_stake.set(self.total_validator_stake.get() - penalty);
+        self.sync_validator_voting_power(validator, current_stake - penalty)?;
+        self.slashing_penalties.insert(validator, self.slashing_penalties.get(validator) + penalty);
+        self.route_slashed_funds(penalty)?;
 
         evm::log(ValidatorSlashed {
             validator,
-            amount: U256::from(penalty),
+            amount: penalty,
             reason,
         });
 
@@ -472,18 +1260,41 @@ impl CulturalValidator {
 
 // Internal helper functions
 impl CulturalValidator {
+    fn sync_validator_voting_power(&mut self, validator: Address, power: U256) -> Result<()> {
+        let governance = self.governance_contract.get();
+        if governance.is_zero() {
+            return Ok(());
+        }
+        
+        let mut calldata = stylus_sdk::function_selector!("updateVotingPower", Address, u8, U256).to_vec();
+        calldata.extend_from_slice(validator.into_word().as_slice());
+        calldata.extend_from_slice(&U256::from(VotingRole::Validator as u8).to_be_bytes::<32>());
+        calldata.extend_from_slice(&power.to_be_bytes::<32>());
+        env::call_contract(self, U256::from(0), governance, &calldata)
+            .map_err(|_| AfroCreateError::InvalidInput("Voting power update failed".to_string()))?;
+        Ok(())
+    }
+
     fn require_owner(&self) -> Result<()> {
-        require_authorized(msg::sender() == self.owner.get(), "Only owner")
+        require_authorized(env::sender() == self.owner.get(), "Only owner")
     }
 
     fn require_admin(&self) -> Result<()> {
-        let caller = msg::sender();
+        let caller = env::sender();
         require_authorized(
             caller == self.owner.get() || self.admins.get(caller),
             "Only admin"
         )
     }
 
+    fn require_governance(&self) -> Result<()> {
+        let governance = self.governance_contract.get();
+        require_authorized(
+            !governance.is_zero() && env::sender() == governance,
+            "Only governance"
+        )
+    }
+
     fn verify_validator_expertise(&self, validator: Address, project_id: U256) -> Result<()> {
         // In production, would check project's cultural category against validator's regions
         // For now, just verify validator exists and is active
@@ -493,28 +1304,290 @@ impl CulturalValidator {
         Ok(())
     }
 
+    fn is_listed_validator(&self, validator: Address) -> bool {
+        self.validators.get(validator).is_active && !self.validator_suspension_status.get(validator)
+    }
+
+    fn is_dormant(&self, validator: Address) -> bool {
+        let period = self.min_activity_period.get();
+        if period == U256::from(0) {
+            return false;
+        }
+        
+        // A validator must have validated at least once, and recently enough
+        if self.validation_history.get(validator).len() == 0 {
+            return true;
+        }
+        self.last_validation_at.get(validator) + period < U256::from(env::timestamp())
+    }
+
+    fn validator_weight(&self, validator: Address, project_id: U256) -> U256 {
+        // Reputation is the base weight; stake share and primary-region expertise boost it
+        let reputation = self.validator_reputation.get(validator) + self.stake_boost(validator);
+        let primary_region = self.validator_primary_region.get(validator);
+        if self.get_project_regions(project_id).contains(&primary_region) {
+            reputation * U256::from(PRIMARY_REGION_MULTIPLIER) / U256::from(100)
+        } else {
+            reputation
+        }
+    }
+
+    fn nonreentrant_guard(&mut self) -> Result<()> {
+        require_valid_input(!self.locked.get(), "Reentrant call")?;
+        self.locked.set(true);
+        Ok(())
+    }
+
+    fn unlock_guard(&mut self) {
+        self.locked.set(false);
+    }
+
+    fn stake_boost(&self, validator: Address) -> U256 {
+        let total_stake = self.total_validator_stake.get();
+        if self.stake_boost_bps.get() == U256::from(0) || total_stake == U256::from(0) {
+            return U256::from(0);
+        }
+        
+        // Clamp the stake share so a single large staker can't dominate consensus
+        let share_bps = core::cmp::min(
+            self.validator_stakes.get(validator) * U256::from(10000) / total_stake,
+            U256::from(MAX_STAKE_SHARE_BPS)
+        );
+        let max_boost = mul_bps(self.validator_reputation.get(validator), self.stake_boost_bps.get());
+        mul_bps(max_boost, share_bps)
+    }
+
+    fn uncovered_regions(&self, project_id: U256) -> Vec<String> {
+        let submissions = self.project_submissions.get(project_id);
+        let mut uncovered = Vec::new();
+        
+        for region in self.get_project_regions(project_id) {
+            let mut covered = false;
+            for i in 0..submissions.len() {
+                if let Some(submission) = submissions.get(i) {
+                    let validator_regions = Self::collect_entries(&self.validator_regions.get(submission.validator));
+                    if validator_regions.contains(&region) {
+                        covered = true;
+                        break;
+                    }
+                }
+            }
+            if !covered {
+                uncovered.push(region);
+            }
+        }
+        uncovered
+    }
+
     fn is_supported_region(&self, region: &str) -> bool {
         let elements = self.cultural_elements_db.get(region.to_string());
         elements.len() > 0
     }
 
-    fn distribute_validator_rewards(&self, project_id: U256) -> Result<()> {
+    fn open_validation(&mut self, project_id: U256) {
+        // Re-opened projects already have a queue entry from their first round
+        if self.project_validations.get(project_id).project_id == U256::from(0) {
+            self.validation_queue.push(project_id);
+        }
+        self.pending_validation.insert(project_id, true);
+        let window = self.validation_window.get();
+        if window > U256::from(0) {
+            self.validation_deadlines.insert(project_id, U256::from(env::timestamp()) + window);
+        }
+    }
+
+    fn complete_validation(&mut self, project_id: U256) -> Result<U256> {
         let submissions = self.project_submissions.get(project_id);
-        let reward_per_validator = self.validator_reward_amount.get();
+        
+        // Calculate weighted average score
+        let mut total_score = U256::from(0);
+        let mut total_weight = U256::from(0);
+        let mut validator_count = 0;
         
         for i in 0..submissions.len() {
             if let Some(submission) = submissions.get(i) {
-                // In production, would transfer rewards to validators
-                // For now, just validate the operation
-                if reward_per_validator > U256::from(0) {
-                    // call::transfer_eth(submission.validator, reward_per_validator)?;
-                }
+                let weight = self.validator_weight(submission.validator, project_id);
+                
+                total_score += submission.score * weight;
+                total_weight += weight;
+                validator_count += 1;
+            }
+        }
+        
+        let final_score = if total_weight > U256::from(0) {
+            total_score / total_weight
+        } else {
+            U256::from(0)
+        };
+        
+        // Determine validation status
+        let approved = final_score >= self.validation_threshold_score.get();
+        let status = if approved { 1u8 } else { 2u8 }; // Approved or Rejected
+        
+        let result = ValidationResult {
+            project_id,
+            final_score,
+            status,
+            validator_count: U256::from(validator_count),
+            completed_timestamp: U256::from(env::timestamp()),
+            can_appeal: true,
+        };
+        
+        self.project_validations.insert(project_id, result);
+        self.pending_validation.insert(project_id, false);
+        self.adjust_region_demand(project_id, false);
+        
+        // Update metrics
+        self.total_validations_completed.set(self.total_validations_completed.get() + U256::from(1));
+        if approved {
+            self.total_projects_approved.set(self.total_projects_approved.get() + U256::from(1));
+        } else {
+            self.total_projects_rejected.set(self.total_projects_rejected.get() + U256::from(1));
+        }
+        
+        // Update average score
+        self.update_average_validation_score(final_score);
+        
+        // Reward validators
+        self.distribute_validator_rewards(project_id, final_score)?;
+        
+        // Update validator reputations based on consensus
+        self.update_validator_reputations(project_id, final_score)?;
+
+        evm::log(ValidationCompleted {
+            project_id,
+            final_score,
+            approved,
+            timestamp: U256::from(env::timestamp()),
+        });
+
+        Ok(final_score)
+    }
+
+    fn reopen_validation(&mut self, project_id: U256) {
+        let submissions = self.project_submissions.get(project_id);
+        let mut validators = Vec::new();
+        for i in 0..submissions.len() {
+            if let Some(submission) = submissions.get(i) {
+                validators.push(submission.validator);
+            }
+        }
+        
+        let cleared = ValidationSubmission {
+            validator: Address::ZERO,
+            score: U256::from(0),
+            feedback_uri: String::new(),
+            cultural_elements: Vec::new(),
+            timestamp: U256::from(0),
+            is_final: false,
+        };
+        for validator in validators {
+            self.validator_project_submissions.get_mut(project_id).insert(validator, cleared.clone());
+        }
+        self.project_submissions.get_mut(project_id).erase();
+        
+        let mut validation_result = self.project_validations.get(project_id);
+        validation_result.status = 0; // Pending
+        validation_result.can_appeal = false;
+        self.project_validations.insert(project_id, validation_result);
+        self.appeal_extensions.insert(project_id, U256::from(0));
+        self.adjust_region_demand(project_id, true);
+    }
+
+    fn adjust_region_demand(&mut self, project_id: U256, awaiting: bool) {
+        for region in self.get_project_regions(project_id) {
+            let count = self.region_awaiting_projects.get(region.clone());
+            let updated = if awaiting { count + U256::from(1) } else { count.saturating_sub(U256::from(1)) };
+            self.region_awaiting_projects.insert(region, updated);
+        }
+    }
+
+    fn appeal_deadline(&self, project_id: U256, validation_result: &ValidationResult) -> U256 {
+        validation_result.completed_timestamp
+            + self.appeal_period.get()
+            + self.appeal_extensions.get(project_id)
+    }
+
+    fn collect_entries(entries: &StorageVec<String>) -> Vec<String> {
+        let mut result = Vec::new();
+        for i in 0..entries.len() {
+            if let Some(entry) = entries.get(i) {
+                result.push(entry);
+            }
+        }
+        result
+    }
+
+    fn starting_reputation_for(&self, validator: Address) -> U256 {
+        let tier = self.validator_credential_tier.get(validator);
+        let reputation = self.credential_start_reputation.get(tier);
+        if tier == U256::from(0) || reputation == U256::from(0) {
+            U256::from(DEFAULT_START_REPUTATION)
+        } else {
+            reputation
+        }
+    }
+
+    fn require_region_capacity(&self, region: &str) -> Result<()> {
+        let capacity = self.region_capacity.get(region.to_string());
+        if capacity == U256::from(0) {
+            return Ok(()); // Unlimited
+        }
+        require_valid_input(
+            self.regional_authority_count.get(region.to_string()) < capacity,
+            "Region validator capacity reached"
+        )
+    }
+
+    fn distribute_validator_rewards(&mut self, project_id: U256, consensus_score: U256) -> Result<()> {
+        let submissions = self.project_submissions.get(project_id);
+        let mut rewards = Vec::new();
+        
+        for i in 0..submissions.len() {
+            if let Some(submission) = submissions.get(i) {
+                let reward = self.validator_reward_for(submission.score, consensus_score);
+                rewards.push((submission.validator, reward));
+            }
+        }
+        
+        // Rewards come out of the pool; when it runs short everyone is scaled down alike
+        let total = rewards.iter().fold(U256::from(0), |acc, (_, reward)| acc + *reward);
+        let pool = self.reward_pool.get();
+        let mut credited = U256::from(0);
+        
+        // Credit only; validators pull their rewards with claim_validator_rewards
+        for (validator, reward) in rewards {
+            let reward = if total > pool { reward * pool / total } else { reward };
+            if reward > U256::from(0) {
+                let earned = self.validator_rewards_earned.get(validator);
+                self.validator_rewards_earned.insert(validator, earned + reward);
+                self.accrued_rewards.insert(validator, self.accrued_rewards.get(validator) + reward);
+                credited += reward;
             }
         }
+        self.reward_pool.set(pool - credited);
         
         Ok(())
     }
 
+    fn validator_reward_for(&self, score: U256, consensus_score: U256) -> U256 {
+        let budget = self.validator_reward_amount.get();
+        
+        // Guaranteed participation portion plus an accuracy-scaled bonus
+        let participation = mul_bps(budget, self.participation_reward_bps.get());
+        let accuracy_budget = mul_bps(budget, self.accuracy_reward_bps.get());
+        participation + accuracy_budget * Self::accuracy(score, consensus_score) / U256::from(100)
+    }
+
+    fn accuracy(score: U256, consensus_score: U256) -> U256 {
+        let deviation = if consensus_score > score {
+            consensus_score - score
+        } else {
+            score - consensus_score
+        };
+        U256::from(100) - core::cmp::min(deviation, U256::from(100))
+    }
+
     fn update_validator_reputations(&mut self, project_id: U256, consensus_score: U256) -> Result<()> {
         let submissions = self.project_submissions.get(project_id);
         
@@ -524,19 +1597,14 @@ impl CulturalValidator {
                 let validator_score = submission.score;
                 
                 // Calculate accuracy based on deviation from consensus
-                let deviation = if consensus_score > validator_score {
-                    consensus_score - validator_score
-                } else {
-                    validator_score - consensus_score
-                };
+                let accuracy = Self::accuracy(validator_score, consensus_score);
                 
                 // Adjust reputation based on accuracy
                 let current_reputation = self.validator_reputation.get(validator);
-                let accuracy = U256::from(100) - core::cmp::min(deviation.as_u64(), 100);
                 
                 // Simple reputation update: move toward accuracy score
                 let new_reputation = (current_reputation * U256::from(9) + accuracy) / U256::from(10);
-                self.validator_reputation.insert(validator, new_reputation);
+                self.set_validator_reputation(validator, new_reputation, project_id);
                 
                 // Update accuracy history
                 self.validator_accuracy_history.get_mut(validator).push(accuracy);
@@ -546,9 +1614,22 @@ impl CulturalValidator {
         Ok(())
     }
 
+    fn set_validator_reputation(&mut self, validator: Address, new_score: U256, project_id: U256) {
+        let old_score = self.validator_reputation.get(validator);
+        self.validator_reputation.insert(validator, new_score);
+        
+        evm::log(ValidatorReputationChanged {
+            validator,
+            old_score,
+            new_score,
+            project_id,
+        });
+    }
+
     fn penalize_inaccurate_validators(&mut self, project_id: U256) -> Result<()> {
         let submissions = self.project_submissions.get(project_id);
         let penalty_amount = self.validator_reward_amount.get();
+        let mut total_slashed = U256::from(0);
         
         for i in 0..submissions.len() {
             if let Some(submission) = submissions.get(i) {
@@ -557,12 +1638,31 @@ impl CulturalValidator {
                 
                 if current_stake >= penalty_amount {
                     self.validator_stakes.insert(validator, current_stake - penalty_amount);
+                    self.total_validator_stake.set(self.total_validator_stake.get() - penalty_amount);
+                    self.sync_validator_voting_power(validator, current_stake - penalty_amount)?;
                     self.slashing_penalties.insert(validator, 
                         self.slashing_penalties.get(validator) + penalty_amount);
+                    total_slashed += penalty_amount;
                 }
             }
         }
         
+        self.route_slashed_funds(total_slashed)
+    }
+
+    fn route_slashed_funds(&mut self, amount: U256) -> Result<()> {
+        if amount == U256::from(0) {
+            return Ok(());
+        }
+        
+        // Stake has already been debited, so the funds are accounted for exactly once
+        let recipient = self.slashing_recipient.get();
+        if recipient.is_zero() {
+            self.reward_pool.set(self.reward_pool.get() + amount);
+        } else {
+            env::transfer_eth(recipient, amount)?;
+        }
+        
         Ok(())
     }
 
@@ -420,4 +420,30 @@ mod creator_tests {
             "Creator not found"
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_projects_resolved_by_ens_name() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let first_project = context.create_test_project().expect("Project creation failed");
+        let second_project = context.create_test_project().expect("Project creation failed");
+        
+        let projects = context.platform.get_projects_by_ens("testcreator".to_string())
+            .expect("ENS lookup failed");
+        assert_eq!(projects, vec![first_project, second_project]);
+        
+        let full_name_projects = context.platform.get_projects_by_ens("testcreator.afrocreate.eth".to_string())
+            .expect("Full ENS lookup failed");
+        assert_eq!(full_name_projects, projects);
+    }
+
+    #[test]
+    fn test_unknown_ens_name_lookup_reverts() {
+        let context = TestContext::new();
+        
+        expect_error(
+            context.platform.get_projects_by_ens("nobody".to_string()),
+            "ENS name not registered"
+        );
+    }
+}
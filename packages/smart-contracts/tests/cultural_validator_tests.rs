@@ -0,0 +1,1297 @@
+use alloy_primitives::{Address, U256};
+use afrocreate_contracts::CulturalValidator;
+use crate::test_utils::*;
+
+#[cfg(test)]
+mod cultural_validator_tests {
+    use super::*;
+
+    fn setup_validator(accounts: &[Address]) -> CulturalValidator {
+        let mut validator = CulturalValidator::default();
+        validator.initialize(accounts[5]).expect("Validator initialization failed");
+        validator
+    }
+
+    fn register(validator: &mut CulturalValidator, ens_name: &str, region: &str) -> Result<bool, String> {
+        validator.register_validator(
+            ens_name.to_string(),
+            vec![region.to_string()],
+            "ipfs://credentials".to_string(),
+        ).map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_region_capacity_blocks_registration_when_full() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        
+        validator.set_region_capacity("West Africa".to_string(), U256::from(2))
+            .expect("Set capacity failed");
+        
+        register(&mut validator, "validator-one", "West Africa").expect("First registration failed");
+        register(&mut validator, "validator-two", "West Africa").expect("Second registration failed");
+        
+        expect_error(
+            register(&mut validator, "validator-three", "West Africa"),
+            "Region validator capacity reached"
+        );
+        
+        let (count, capacity) = validator.get_region_capacity("West Africa".to_string());
+        assert_eq!(count, U256::from(2));
+        assert_eq!(capacity, U256::from(2));
+    }
+
+    #[test]
+    fn test_region_capacity_does_not_affect_other_regions() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        
+        validator.set_region_capacity("West Africa".to_string(), U256::from(0))
+            .expect("Set capacity failed");
+        validator.set_region_capacity("East Africa".to_string(), U256::from(1))
+            .expect("Set capacity failed");
+        
+        register(&mut validator, "validator-east", "East Africa").expect("Registration failed");
+        register(&mut validator, "validator-west", "West Africa").expect("Uncapped region rejected");
+        
+        let (count, _) = validator.get_region_capacity("West Africa".to_string());
+        assert_eq!(count, U256::from(1));
+    }
+
+    #[test]
+    fn test_registration_fee_requires_stake_plus_fee() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        
+        // Registration value only covers the stake, not the additional fee
+        let (stake_requirement, _) = validator.registration_requirements();
+        validator.set_registration_fee(stake_requirement).expect("Set fee failed");
+        
+        expect_error(
+            register(&mut validator, "validator-one", "West Africa"),
+            "Insufficient stake amount"
+        );
+    }
+
+    #[test]
+    fn test_registration_fee_credits_reward_pool() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let fee = U256::from(1000000000000000u64); // 0.001 ETH
+        
+        validator.set_registration_fee(fee).expect("Set fee failed");
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        
+        let (stake_requirement, registration_fee) = validator.registration_requirements();
+        assert_eq!(registration_fee, fee);
+        assert_eq!(validator.reward_pool_balance(), fee);
+        assert!(validator.get_validator_stake(context.test_accounts[0]) >= stake_requirement);
+    }
+
+    #[test]
+    fn test_governance_sets_validator_reward_amount() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let governance = context.test_accounts[8];
+        let new_reward = U256::from(20000000000000000u64); // 0.02 ETH
+        
+        validator.set_governance_contract(governance).expect("Set governance failed");
+        
+        // The owner configured governance but cannot set the rate itself
+        expect_error(validator.set_validator_reward_amount(new_reward), "Only governance");
+        
+        context.set_sender(governance);
+        validator.set_validator_reward_amount(new_reward).expect("Reward update failed");
+        
+        // finalize_validation reads the reward straight from this setting
+        assert_eq!(validator.validator_reward_amount(), new_reward);
+    }
+
+    #[test]
+    fn test_validator_reward_amount_requires_governance() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        
+        // No governance contract configured, so nobody may change the rate
+        expect_error(
+            validator.set_validator_reward_amount(U256::from(1)),
+            "Only governance"
+        );
+    }
+
+    #[test]
+    fn test_validator_reward_amount_ceiling() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let governance = context.test_accounts[8];
+        
+        validator.set_governance_contract(governance).expect("Set governance failed");
+        context.set_sender(governance);
+        expect_error(
+            validator.set_validator_reward_amount(U256::from(2000000000000000000u64)),
+            "Reward amount too high"
+        );
+    }
+
+    #[test]
+    fn test_pending_validation_listed_until_finalized() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        let members = register_quorum(&mut context, &mut validator);
+        context.set_sender(members[0]);
+        validator.submit_validation(
+            project_id,
+            U256::from(80),
+            "ipfs://feedback".to_string(),
+            vec!["Kente Weaving".to_string()],
+        ).expect("Submission failed");
+        
+        let (submissions, required, region_coverage) = validator.get_pending_validation(project_id);
+        assert_eq!(submissions, U256::from(1));
+        assert_eq!(required, U256::from(3));
+        assert_eq!(region_coverage, U256::from(1));
+        assert_eq!(
+            validator.get_projects_awaiting_validation(U256::from(0), U256::from(10)),
+            vec![project_id]
+        );
+        
+        for member in &members[1..] {
+            context.set_sender(*member);
+            validator.submit_validation(
+                project_id, U256::from(80), "ipfs://feedback".to_string(), Vec::new()
+            ).expect("Submission failed");
+        }
+        
+        // The third submission reaches quorum and finalizes the project
+        assert!(validator.get_validation_status(project_id).is_ok());
+        assert!(validator.get_projects_awaiting_validation(U256::from(0), U256::from(10)).is_empty());
+    }
+
+    #[test]
+    fn test_slashing_routes_penalty_to_treasury() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let treasury = context.test_accounts[8];
+        let validator_address = context.test_accounts[0];
+        let penalty = U256::from(10000000000000000u64); // 0.01 ETH
+        
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        validator.set_slashing_recipient(treasury).expect("Set recipient failed");
+        
+        let stake_before = validator.get_validator_stake(validator_address);
+        let pool_before = validator.reward_pool_balance();
+        validator.slash_validator(validator_address, penalty, "Plagiarised feedback".to_string())
+            .expect("Slashing failed");
+        
+        // Penalty leaves via transfer, so the reward pool is untouched
+        assert_eq!(validator.get_validator_stake(validator_address), stake_before - penalty);
+        assert_eq!(validator.reward_pool_balance(), pool_before);
+    }
+
+    #[test]
+    fn test_slashing_without_recipient_credits_reward_pool() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let validator_address = context.test_accounts[0];
+        
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        
+        // Penalty larger than the stake is capped at the stake
+        let stake_before = validator.get_validator_stake(validator_address);
+        validator.slash_validator(validator_address, stake_before + U256::from(1), "Collusion".to_string())
+            .expect("Slashing failed");
+        
+        assert_eq!(validator.get_validator_stake(validator_address), U256::from(0));
+        assert_eq!(validator.reward_pool_balance(), stake_before);
+    }
+
+    #[test]
+    fn test_submitting_validator_cannot_appeal() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        let members = register_quorum(&mut context, &mut validator);
+        submit_and_finalize(&mut context, &mut validator, &members, project_id);
+        
+        context.set_sender(members[0]);
+        expect_error(
+            validator.challenge_validation(
+                project_id, "Score too low".to_string(), "ipfs://evidence".to_string()
+            ),
+            "Validator cannot appeal own validation"
+        );
+        assert!(!validator.has_open_appeal(project_id));
+    }
+
+    #[test]
+    fn test_duplicate_open_appeal_rejected() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        let members = register_quorum(&mut context, &mut validator);
+        submit_and_finalize(&mut context, &mut validator, &members, project_id);
+        
+        context.set_sender(context.creator());
+        validator.challenge_validation(
+            project_id, "Missing context".to_string(), "ipfs://evidence".to_string()
+        ).expect("First appeal failed");
+        expect_error(
+            validator.challenge_validation(
+                project_id, "Missing context".to_string(), "ipfs://evidence".to_string()
+            ),
+            "Appeal already pending"
+        );
+        
+        // Another community member may still appeal independently
+        context.set_sender(context.test_accounts[9]);
+        validator.challenge_validation(
+            project_id, "Missing context".to_string(), "ipfs://evidence-2".to_string()
+        ).expect("Independent appeal failed");
+    }
+
+    #[test]
+    fn test_credentialed_validator_starts_with_higher_reputation() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let validator_address = context.test_accounts[0];
+        
+        validator.set_credential_start_reputation(U256::from(2), U256::from(130))
+            .expect("Set tier reputation failed");
+        validator.attest_validator_credentials(validator_address, U256::from(2))
+            .expect("Attestation failed");
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        
+        let profile = validator.get_validator_profile(validator_address).unwrap();
+        assert_eq!(profile.reputation_score, U256::from(130));
+    }
+
+    #[test]
+    fn test_uncredentialed_validator_starts_at_default_reputation() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        
+        validator.set_credential_start_reputation(U256::from(2), U256::from(130))
+            .expect("Set tier reputation failed");
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        
+        let profile = validator.get_validator_profile(context.test_accounts[0]).unwrap();
+        assert_eq!(profile.reputation_score, U256::from(100));
+    }
+
+    #[test]
+    fn test_credential_start_reputation_is_capped() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        
+        expect_error(
+            validator.set_credential_start_reputation(U256::from(3), U256::from(500)),
+            "Starting reputation too high"
+        );
+    }
+
+    #[test]
+    fn test_reputation_change_matches_consensus_accuracy() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        let members = register_quorum(&mut context, &mut validator);
+        let old_score = validator.get_validator_reputation(members[0]);
+        
+        // Equal-weight scores of 80, 90 and 100 settle on a consensus of 90
+        for (member, score) in members.iter().zip([80u64, 90, 100]) {
+            context.set_sender(*member);
+            validator.submit_validation(
+                project_id, U256::from(score), "ipfs://feedback".to_string(), Vec::new()
+            ).expect("Submission failed");
+        }
+        
+        let consensus = validator.get_validation_status(project_id).unwrap().final_score;
+        assert_eq!(consensus, U256::from(90));
+        
+        // The 80 scorer was 10 points off consensus, so accuracy is 90
+        let expected = (old_score * U256::from(9) + U256::from(90)) / U256::from(10);
+        assert_eq!(validator.get_validator_reputation(members[0]), expected);
+        let expected_exact = (old_score * U256::from(9) + U256::from(100)) / U256::from(10);
+        assert_eq!(validator.get_validator_reputation(members[1]), expected_exact);
+    }
+
+    #[test]
+    fn test_cultural_database_seeded_elements_readable() {
+        let context = TestContext::new();
+        let validator = setup_validator(&context.test_accounts);
+        
+        let elements = validator.get_cultural_elements("West Africa".to_string());
+        assert!(elements.contains(&"Kente Weaving".to_string()));
+        assert!(validator.get_traditional_practices("West Africa".to_string()).is_empty());
+        assert!(validator.get_language_families("West Africa".to_string()).is_empty());
+    }
+
+    #[test]
+    fn test_populate_practices_and_languages() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        
+        validator.add_traditional_practice("East Africa".to_string(), "Harambee".to_string())
+            .expect("Add practice failed");
+        validator.add_language_family("East Africa".to_string(), "Bantu".to_string())
+            .expect("Add language failed");
+        
+        assert_eq!(
+            validator.get_traditional_practices("East Africa".to_string()),
+            vec!["Harambee".to_string()]
+        );
+        assert_eq!(
+            validator.get_language_families("East Africa".to_string()),
+            vec!["Bantu".to_string()]
+        );
+        expect_error(
+            validator.add_language_family("East Africa".to_string(), "Bantu".to_string()),
+            "Entry already exists"
+        );
+        expect_error(
+            validator.add_traditional_practice("Atlantis".to_string(), "Harambee".to_string()),
+            "Unsupported region"
+        );
+    }
+
+    #[test]
+    fn test_new_region_becomes_supported() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        
+        expect_error(register(&mut validator, "validator-diaspora", "Diaspora"), "Unsupported region");
+        
+        validator.add_cultural_element("Diaspora".to_string(), "Afrobeats".to_string())
+            .expect("Add element failed");
+        assert_eq!(
+            validator.get_cultural_elements("Diaspora".to_string()),
+            vec!["Afrobeats".to_string()]
+        );
+        register(&mut validator, "validator-diaspora", "Diaspora").expect("New region rejected");
+    }
+
+    fn submit_and_finalize(
+        context: &mut TestContext,
+        validator: &mut CulturalValidator,
+        members: &[Address],
+        project_id: U256,
+    ) {
+        for member in members {
+            context.set_sender(*member);
+            validator.submit_validation(
+                project_id, U256::from(90), "ipfs://feedback".to_string(), Vec::new()
+            ).expect("Submission failed");
+        }
+        context.set_sender(context.test_accounts[0]);
+        
+        // The last submission reaches quorum and finalizes; finalizing again is rejected
+        assert!(validator.get_validation_status(project_id).is_ok());
+        expect_error(validator.finalize_validation(project_id), "Validation not pending");
+    }
+
+    #[test]
+    fn test_extended_appeal_window_allows_late_appeal() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        let members = register_quorum(&mut context, &mut validator);
+        submit_and_finalize(&mut context, &mut validator, &members, project_id);
+        
+        let original_deadline = validator.get_appeal_deadline(project_id).unwrap();
+        let new_deadline = validator.extend_appeal_window(project_id, U256::from(3 * 24 * 3600))
+            .expect("Extension failed");
+        assert_eq!(new_deadline, original_deadline + U256::from(3 * 24 * 3600));
+        
+        // Past the original 7-day window but inside the extension
+        context.advance_time(8 * 24 * 3600);
+        context.set_sender(context.creator());
+        validator.challenge_validation(
+            project_id, "New oral history evidence".to_string(), "ipfs://late-evidence".to_string()
+        ).expect("Late appeal rejected");
+        assert!(validator.has_open_appeal(project_id));
+    }
+
+    #[test]
+    fn test_second_appeal_extension_rejected() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        let members = register_quorum(&mut context, &mut validator);
+        submit_and_finalize(&mut context, &mut validator, &members, project_id);
+        
+        validator.extend_appeal_window(project_id, U256::from(24 * 3600)).expect("Extension failed");
+        expect_error(
+            validator.extend_appeal_window(project_id, U256::from(24 * 3600)),
+            "Appeal window already extended"
+        );
+    }
+
+    fn finalize_and_appeal(
+        context: &mut TestContext,
+        validator: &mut CulturalValidator,
+        project_id: U256,
+    ) -> (Vec<Address>, U256) {
+        let members = register_quorum(context, validator);
+        submit_and_finalize(context, validator, &members, project_id);
+        
+        // A community member who did not submit challenges the result
+        context.set_sender(context.creator());
+        let appeal_id = validator.challenge_validation(
+            project_id, "Misattributed tradition".to_string(), "ipfs://evidence".to_string()
+        ).expect("Appeal failed");
+        context.set_sender(context.test_accounts[0]);
+        (members, appeal_id)
+    }
+
+    #[test]
+    fn test_upheld_appeal_flip_path() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        let (_, appeal_id) = finalize_and_appeal(&mut context, &mut validator, project_id);
+        let original_status = validator.get_validation_status(project_id).unwrap().status;
+        assert_eq!(original_status, 1); // Approved
+        
+        validator.resolve_appeal(appeal_id, true, false, "Decision reversed".to_string())
+            .expect("Resolve failed");
+        
+        let result = validator.get_validation_status(project_id).unwrap();
+        assert_eq!(result.status, 2); // Rejected
+        assert!(!validator.has_open_appeal(project_id));
+    }
+
+    #[test]
+    fn test_upheld_appeal_reopen_path_clears_submissions() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        let (members, appeal_id) = finalize_and_appeal(&mut context, &mut validator, project_id);
+        
+        validator.resolve_appeal(appeal_id, true, true, "Revalidate with new evidence".to_string())
+            .expect("Resolve failed");
+        
+        let result = validator.get_validation_status(project_id).unwrap();
+        assert_eq!(result.status, 0); // Pending
+        let (submissions, _, _) = validator.get_pending_validation(project_id);
+        assert_eq!(submissions, U256::from(0));
+        
+        // An original validator may submit again in the new round
+        context.set_sender(members[0]);
+        validator.submit_validation(
+            project_id, U256::from(60), "ipfs://second-round".to_string(), Vec::new()
+        ).expect("Resubmission after reopen failed");
+        let (submissions, _, _) = validator.get_pending_validation(project_id);
+        assert_eq!(submissions, U256::from(1));
+    }
+
+    #[test]
+    fn test_validator_updates_submission_before_finalization() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        expect_error(
+            validator.update_validation_submission(
+                project_id, U256::from(85), "ipfs://corrected".to_string(), Vec::new()
+            ),
+            "No submission to update"
+        );
+        
+        validator.submit_validation(
+            project_id, U256::from(58), "ipfs://feedback".to_string(), vec!["Kente Weaving".to_string()]
+        ).expect("Submission failed");
+        validator.update_validation_submission(
+            project_id,
+            U256::from(85),
+            "ipfs://corrected".to_string(),
+            vec!["Kente Weaving".to_string(), "Adinkra Symbols".to_string()],
+        ).expect("Update failed");
+        
+        let submissions = validator.get_project_submissions(project_id);
+        assert_eq!(submissions.len(), 1);
+        assert_eq!(submissions[0].score, U256::from(85));
+        assert_eq!(submissions[0].feedback_uri, "ipfs://corrected");
+        assert_eq!(submissions[0].cultural_elements.len(), 2);
+        
+        let (count, _, _) = validator.get_pending_validation(project_id);
+        assert_eq!(count, U256::from(1));
+    }
+
+    #[test]
+    fn test_submission_update_rejected_after_finalization() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        let members = register_quorum(&mut context, &mut validator);
+        submit_and_finalize(&mut context, &mut validator, &members, project_id);
+        
+        context.set_sender(members[0]);
+        expect_error(
+            validator.update_validation_submission(
+                project_id, U256::from(40), "ipfs://late-change".to_string(), Vec::new()
+            ),
+            "Validation already finalized"
+        );
+        
+        // The finalized submission and score are left untouched
+        let submissions = validator.get_project_submissions(project_id);
+        assert_eq!(submissions[0].score, U256::from(90));
+        assert_eq!(validator.get_validation_status(project_id).unwrap().final_score, U256::from(90));
+    }
+
+    #[test]
+    fn test_challenger_lists_appeals_across_projects() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let challenger = context.creator();
+        
+        assert!(validator.get_challenger_appeals(challenger).is_empty());
+        
+        let members = register_quorum(&mut context, &mut validator);
+        let mut appeal_ids = Vec::new();
+        for project_id in [U256::from(1), U256::from(2)] {
+            submit_and_finalize(&mut context, &mut validator, &members, project_id);
+            context.set_sender(challenger);
+            let appeal_id = validator.challenge_validation(
+                project_id, "Misattributed tradition".to_string(), "ipfs://evidence".to_string()
+            ).expect("Appeal failed");
+            appeal_ids.push(appeal_id);
+        }
+        
+        assert_eq!(appeal_ids.len(), 2);
+        assert_eq!(validator.get_challenger_appeals(challenger), appeal_ids);
+        assert!(validator.get_challenger_appeals(context.test_accounts[9]).is_empty());
+    }
+
+    #[test]
+    fn test_primary_region_validator_weighs_more() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let member = context.validator();
+        let others = [context.admin(), context.backer()];
+        let primary_project = U256::from(1);
+        let secondary_project = U256::from(2);
+        let (stake, fee) = validator.registration_requirements();
+        
+        // The member's primary region is West Africa; the other two lead with East Africa
+        context.set_value(stake + fee);
+        context.set_sender(member);
+        validator.register_validator(
+            "validator-one".to_string(),
+            vec!["West Africa".to_string(), "East Africa".to_string()],
+            "ipfs://credentials".to_string(),
+        ).expect("Registration failed");
+        for (i, other) in others.iter().enumerate() {
+            context.set_sender(*other);
+            validator.register_validator(
+                format!("validator-east-{}", i),
+                vec!["East Africa".to_string(), "West Africa".to_string()],
+                "ipfs://credentials".to_string(),
+            ).expect("Registration failed");
+        }
+        context.set_value(U256::from(0));
+        assert_eq!(validator.get_primary_region(member), "West Africa");
+        
+        context.set_sender(context.test_accounts[0]);
+        validator.set_project_regions(primary_project, vec!["West Africa".to_string()]).expect("Set region failed");
+        validator.set_project_regions(secondary_project, vec!["East Africa".to_string()]).expect("Set region failed");
+        
+        let reputation = validator.get_validator_reputation(member);
+        assert_eq!(validator.get_validator_weight(member, primary_project), reputation * U256::from(150) / U256::from(100));
+        assert_eq!(validator.get_validator_weight(member, secondary_project), reputation);
+        
+        // Same scores on both projects: the member says 80, the others 50
+        let mut final_scores = Vec::new();
+        for project_id in [primary_project, secondary_project] {
+            context.set_sender(member);
+            validator.submit_validation(
+                project_id, U256::from(80), "ipfs://feedback".to_string(), Vec::new()
+            ).expect("Submission failed");
+            for other in &others {
+                context.set_sender(*other);
+                validator.submit_validation(
+                    project_id, U256::from(50), "ipfs://feedback".to_string(), Vec::new()
+                ).expect("Submission failed");
+            }
+            
+            let member_weight = validator.get_validator_weight(member, project_id);
+            let other_weight = validator.get_validator_weight(others[0], project_id);
+            let expected = (U256::from(80) * member_weight + U256::from(100) * other_weight)
+                / (member_weight + U256::from(2) * other_weight);
+            
+            // The last submission reaches quorum and finalizes the project
+            let final_score = validator.get_validation_status(project_id).unwrap().final_score;
+            assert_eq!(final_score, expected);
+            final_scores.push(final_score);
+        }
+        
+        // The member's score pulls the primary-region average further towards 80
+        assert!(final_scores[0] > final_scores[1]);
+    }
+
+    #[test]
+    fn test_primary_region_must_be_an_expertise_region() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        expect_error(
+            validator.set_primary_region("North Africa".to_string()),
+            "Region not in validator expertise"
+        );
+        expect_error(
+            validator.set_project_regions(U256::from(1), vec!["Atlantis".to_string()]),
+            "Unsupported region"
+        );
+    }
+
+    fn fusion_regions() -> Vec<String> {
+        vec!["West Africa".to_string(), "East Africa".to_string()]
+    }
+
+    #[test]
+    fn test_multi_region_project_fails_finalization_when_region_uncovered() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        validator.set_project_regions(project_id, fusion_regions()).expect("Set regions failed");
+        assert_eq!(validator.get_project_regions(project_id), fusion_regions());
+        
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        validator.submit_validation(
+            project_id, U256::from(85), "ipfs://feedback".to_string(), Vec::new()
+        ).expect("Submission failed");
+        
+        assert_eq!(validator.get_uncovered_regions(project_id), vec!["East Africa".to_string()]);
+        expect_error(validator.finalize_validation(project_id), "Not all project regions covered");
+    }
+
+    #[test]
+    fn test_multi_region_project_covered_by_validators_from_both_regions() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        let (stake, fee) = validator.registration_requirements();
+        
+        validator.set_project_regions(project_id, fusion_regions()).expect("Set regions failed");
+        
+        // One validator per region plus a third to make quorum
+        let members = [
+            (context.validator(), "West Africa"),
+            (context.admin(), "East Africa"),
+            (context.backer(), "West Africa"),
+        ];
+        for (i, (member, region)) in members.iter().enumerate() {
+            context.set_sender(*member);
+            context.set_value(stake + fee);
+            register(&mut validator, &format!("validator-{}", i), region).expect("Registration failed");
+            context.set_value(U256::from(0));
+            validator.submit_validation(
+                project_id, U256::from(85), "ipfs://feedback".to_string(), Vec::new()
+            ).expect("Submission failed");
+            
+            // East Africa stays uncovered until its validator has submitted
+            if i == 0 {
+                assert_eq!(validator.get_uncovered_regions(project_id), vec!["East Africa".to_string()]);
+            }
+        }
+        
+        assert!(validator.get_uncovered_regions(project_id).is_empty());
+        assert_eq!(validator.get_validation_status(project_id).unwrap().validator_count, U256::from(3));
+        
+        context.set_sender(context.test_accounts[0]);
+        expect_error(
+            validator.set_project_regions(project_id, vec!["North Africa".to_string()]),
+            "Validation already started"
+        );
+    }
+
+    #[test]
+    fn test_validator_without_recent_validation_is_not_qualified() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let validator_address = context.test_accounts[0];
+        
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        // Without an activity requirement every active validator qualifies
+        assert!(validator.get_qualified_validators("West Africa".to_string()).contains(&validator_address));
+        
+        validator.set_min_activity_period(U256::from(30 * 86400)).expect("Set activity period failed");
+        
+        assert!(validator.is_validator_dormant(validator_address));
+        assert!(!validator.get_qualified_validators("West Africa".to_string()).contains(&validator_address));
+        
+        // Nor is any project assigned to them
+        validator.set_project_regions(U256::from(1), vec!["West Africa".to_string()]).expect("Set region failed");
+        assert!(validator.get_validator_assignments(validator_address).is_empty());
+    }
+
+    #[test]
+    fn test_recently_active_validator_stays_qualified() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let validator_address = context.test_accounts[0];
+        
+        validator.set_min_activity_period(U256::from(30 * 86400)).expect("Set activity period failed");
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        validator.submit_validation(
+            U256::from(1), U256::from(85), "ipfs://feedback".to_string(), Vec::new()
+        ).expect("Submission failed");
+        
+        assert!(!validator.is_validator_dormant(validator_address));
+        assert!(validator.get_qualified_validators("West Africa".to_string()).contains(&validator_address));
+    }
+
+    #[test]
+    fn test_inaccurate_validator_still_earns_participation_reward() {
+        let context = TestContext::new();
+        let validator = setup_validator(&context.test_accounts);
+        let (participation_bps, _) = validator.get_reward_split();
+        let participation = validator.validator_reward_amount() * participation_bps / U256::from(10000);
+        
+        // Scoring 0 against a consensus of 100 earns no accuracy bonus
+        let inaccurate = validator.preview_validator_reward(U256::from(0), U256::from(100));
+        assert_eq!(inaccurate, participation);
+        
+        let accurate = validator.preview_validator_reward(U256::from(90), U256::from(92));
+        assert!(accurate > inaccurate);
+        assert!(accurate <= validator.validator_reward_amount());
+    }
+
+    #[test]
+    fn test_governance_sets_reward_split() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let governance = context.test_accounts[8];
+        
+        validator.set_governance_contract(governance).expect("Set governance failed");
+        expect_error(validator.set_reward_split(U256::from(2000), U256::from(8000)), "Only governance");
+        
+        context.set_sender(governance);
+        expect_error(
+            validator.set_reward_split(U256::from(3000), U256::from(6000)),
+            "Reward split must total 10000 bps"
+        );
+        validator.set_reward_split(U256::from(2000), U256::from(8000)).expect("Set split failed");
+        
+        // A perfectly accurate validator earns the full reward
+        let reward = validator.validator_reward_amount();
+        assert_eq!(validator.preview_validator_reward(U256::from(80), U256::from(80)), reward);
+        assert_eq!(
+            validator.preview_validator_reward(U256::from(0), U256::from(100)),
+            reward * U256::from(2000) / U256::from(10000)
+        );
+    }
+
+    #[test]
+    fn test_region_validator_directory_paginates_profiles() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        
+        let members = register_quorum(&mut context, &mut validator);
+        
+        assert_eq!(validator.region_validator_count("West Africa".to_string()), U256::from(3));
+        let first_page = validator.get_region_validators("West Africa".to_string(), U256::from(0), U256::from(2));
+        assert_eq!(first_page.len(), 2);
+        assert_eq!(first_page[0].validator_address, members[0]);
+        assert_eq!(first_page[0].ens_name, "validator-0");
+        assert_eq!(first_page[1].validator_address, members[1]);
+        
+        let second_page = validator.get_region_validators("West Africa".to_string(), U256::from(2), U256::from(2));
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page[0].validator_address, members[2]);
+        
+        assert!(validator.get_region_validators("West Africa".to_string(), U256::from(3), U256::from(10)).is_empty());
+        assert!(validator.get_region_validators("West Africa".to_string(), U256::from(0), U256::from(0)).is_empty());
+        assert!(validator.get_region_validators("East Africa".to_string(), U256::from(0), U256::from(10)).is_empty());
+    }
+
+    #[test]
+    fn test_region_validator_directory_skips_suspended() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        validator.suspend_validator(context.test_accounts[0], U256::from(7)).expect("Suspension failed");
+        
+        assert_eq!(validator.region_validator_count("West Africa".to_string()), U256::from(0));
+        assert!(validator.get_region_validators("West Africa".to_string(), U256::from(0), U256::from(10)).is_empty());
+    }
+
+    fn setup_language_project(validator: &mut CulturalValidator, project_id: U256) {
+        validator.add_language_family("West Africa".to_string(), "Yoruba".to_string())
+            .expect("Add language failed");
+        validator.add_language_family("East Africa".to_string(), "Swahili".to_string())
+            .expect("Add language failed");
+        validator.set_project_regions(project_id, vec!["West Africa".to_string()])
+            .expect("Set regions failed");
+    }
+
+    #[test]
+    fn test_declare_project_language_from_region() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_language_project(&mut validator, project_id);
+        
+        validator.declare_project_language(project_id, "Yoruba".to_string()).expect("Declare failed");
+        
+        assert_eq!(validator.get_project_languages(project_id), vec!["Yoruba".to_string()]);
+        expect_error(
+            validator.declare_project_language(project_id, "Yoruba".to_string()),
+            "Language already declared"
+        );
+    }
+
+    #[test]
+    fn test_declare_off_region_language_rejected() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_language_project(&mut validator, project_id);
+        
+        expect_error(
+            validator.declare_project_language(project_id, "Swahili".to_string()),
+            "Language not spoken in project regions"
+        );
+        expect_error(
+            validator.declare_project_language(U256::from(2), "Yoruba".to_string()),
+            "Project regions not set"
+        );
+    }
+
+    #[test]
+    fn test_query_projects_by_language() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        setup_language_project(&mut validator, U256::from(1));
+        validator.set_project_regions(U256::from(2), vec!["West Africa".to_string()])
+            .expect("Set regions failed");
+        
+        validator.declare_project_language(U256::from(1), "Yoruba".to_string()).expect("Declare failed");
+        validator.declare_project_language(U256::from(2), "Yoruba".to_string()).expect("Declare failed");
+        
+        assert_eq!(
+            validator.get_projects_by_language("Yoruba".to_string(), U256::from(0), U256::from(10)),
+            vec![U256::from(1), U256::from(2)]
+        );
+        assert_eq!(
+            validator.get_projects_by_language("Yoruba".to_string(), U256::from(1), U256::from(10)),
+            vec![U256::from(2)]
+        );
+        assert!(validator.get_projects_by_language("Swahili".to_string(), U256::from(0), U256::from(10)).is_empty());
+    }
+
+    #[test]
+    fn test_stake_boost_raises_validator_weight() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let large = context.validator();
+        let small = context.admin();
+        let project_id = U256::from(1);
+        let (stake, fee) = validator.registration_requirements();
+        
+        // Same reputation, but the large staker puts up three times the minimum stake
+        context.set_sender(large);
+        context.set_value(stake * U256::from(3) + fee);
+        register(&mut validator, "validator-large", "East Africa").expect("Registration failed");
+        context.set_sender(small);
+        context.set_value(stake + fee);
+        register(&mut validator, "validator-small", "East Africa").expect("Registration failed");
+        context.set_value(U256::from(0));
+        context.set_sender(context.test_accounts[0]);
+        validator.set_project_regions(project_id, vec!["West Africa".to_string()]).expect("Set region failed");
+        
+        let reputation = validator.get_validator_reputation(large);
+        assert_eq!(validator.get_validator_reputation(small), reputation);
+        assert_eq!(validator.get_validator_weight(large, project_id), reputation);
+        assert_eq!(validator.get_validator_weight(small, project_id), reputation);
+        
+        // The large staker's 75% share is clamped to 30%; the small staker's 25% counts in full
+        validator.set_stake_boost_bps(U256::from(5000)).expect("Set boost failed");
+        let total = validator.get_total_validator_stake();
+        let small_share = validator.get_validator_stake(small) * U256::from(10000) / total;
+        assert_eq!(small_share, U256::from(2500));
+        let large_boost = reputation * U256::from(5000) / U256::from(10000) * U256::from(3000) / U256::from(10000);
+        let small_boost = reputation * U256::from(5000) / U256::from(10000) * small_share / U256::from(10000);
+        assert_eq!(validator.get_validator_weight(large, project_id), reputation + large_boost);
+        assert_eq!(validator.get_validator_weight(small, project_id), reputation + small_boost);
+        assert!(large_boost > small_boost);
+        
+        validator.set_stake_boost_bps(U256::from(0)).expect("Set boost failed");
+        assert_eq!(validator.get_validator_weight(large, project_id), reputation);
+        assert_eq!(validator.get_validator_weight(small, project_id), reputation);
+    }
+
+    #[test]
+    fn test_stake_boost_bounds_and_slashing() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let member = context.test_accounts[0];
+        
+        expect_error(validator.set_stake_boost_bps(U256::from(10001)), "Stake boost too high");
+        
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        let stake = validator.get_validator_stake(member);
+        validator.slash_validator(member, stake / U256::from(2), "Misconduct".to_string())
+            .expect("Slash failed");
+        
+        assert_eq!(validator.get_total_validator_stake(), stake - stake / U256::from(2));
+    }
+
+    #[test]
+    fn test_finalization_accrues_rewards_without_transfer() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        // Registration fees leave the pool with enough to pay every reward in full
+        validator.set_registration_fee(U256::from(50_000_000_000_000_000u64)).expect("Set fee failed");
+        let members = register_quorum(&mut context, &mut validator);
+        let pool_before = validator.reward_pool_balance();
+        
+        for member in &members {
+            context.set_sender(*member);
+            validator.submit_validation(
+                project_id, U256::from(80), "ipfs://feedback".to_string(), Vec::new()
+            ).expect("Submission failed");
+        }
+        let consensus = validator.get_validation_status(project_id).unwrap().final_score;
+        
+        let reward = validator.preview_validator_reward(U256::from(80), consensus);
+        assert_eq!(reward, validator.validator_reward_amount());
+        for member in &members {
+            assert_eq!(validator.get_accrued_rewards(*member), reward);
+            assert_eq!(validator.get_validator_rewards_earned(*member), reward);
+        }
+        
+        // Accrued rewards are reserved out of the pool rather than paid out
+        assert_eq!(validator.reward_pool_balance(), pool_before - reward * U256::from(3));
+        
+        // A settled project cannot be finalized again to credit the same rewards twice
+        expect_error(validator.finalize_validation(project_id), "Validation not pending");
+        let (stake, fee) = validator.registration_requirements();
+        context.set_sender(context.creator());
+        context.set_value(stake + fee);
+        register(&mut validator, "late-validator", "West Africa").expect("Registration failed");
+        context.set_value(U256::from(0));
+        expect_error(
+            validator.submit_validation(project_id, U256::from(80), "ipfs://feedback".to_string(), Vec::new()),
+            "Validation already finalized"
+        );
+        context.set_sender(context.test_accounts[0]);
+        assert_eq!(validator.get_accrued_rewards(members[0]), reward);
+        assert_eq!(validator.reward_pool_balance(), pool_before - reward * U256::from(3));
+    }
+
+    #[test]
+    fn test_claim_validator_rewards_across_projects() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let projects = [U256::from(1), U256::from(2)];
+        
+        validator.set_registration_fee(U256::from(50_000_000_000_000_000u64)).expect("Set fee failed");
+        let members = register_quorum(&mut context, &mut validator);
+        for project_id in projects {
+            submit_and_finalize(&mut context, &mut validator, &members, project_id);
+        }
+        
+        // Both finalizations accrue into a single claim
+        let claimant = members[0];
+        let accrued = validator.get_accrued_rewards(claimant);
+        assert_eq!(accrued, validator.validator_reward_amount() * U256::from(2));
+        
+        context.set_sender(claimant);
+        assert_eq!(validator.claim_validator_rewards().expect("Claim failed"), accrued);
+        assert_eq!(validator.get_accrued_rewards(claimant), U256::from(0));
+        assert_eq!(validator.get_validator_rewards_earned(claimant), accrued);
+        expect_error(validator.claim_validator_rewards(), "No rewards to claim");
+    }
+
+    #[test]
+    fn test_region_demand_reflects_validator_shortage() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        for project_id in 1..=3u64 {
+            validator.set_project_regions(U256::from(project_id), vec!["West Africa".to_string()])
+                .expect("Set regions failed");
+        }
+        
+        // Three projects for a single West African validator
+        assert_eq!(
+            validator.get_region_demand("West Africa".to_string()),
+            (U256::from(3), U256::from(1), U256::from(300))
+        );
+        
+        // Re-declaring a project's region moves its demand
+        validator.set_project_regions(U256::from(3), vec!["East Africa".to_string()])
+            .expect("Set regions failed");
+        assert_eq!(
+            validator.get_region_demand("West Africa".to_string()),
+            (U256::from(2), U256::from(1), U256::from(200))
+        );
+        assert_eq!(
+            validator.get_region_demand("East Africa".to_string()),
+            (U256::from(1), U256::from(0), U256::MAX)
+        );
+        assert_eq!(
+            validator.get_region_demand("North Africa".to_string()),
+            (U256::from(0), U256::from(0), U256::from(0))
+        );
+    }
+
+    #[test]
+    fn test_registration_rejects_too_many_regions() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        
+        validator.set_max_regions_per_validator(U256::from(1)).expect("Set cap failed");
+        assert_eq!(validator.get_max_regions_per_validator(), U256::from(1));
+        
+        expect_error(
+            validator.register_validator(
+                "validator-one".to_string(),
+                vec!["West Africa".to_string(), "East Africa".to_string()],
+                "ipfs://credentials".to_string(),
+            ),
+            "Too many regions"
+        );
+        expect_error(validator.set_max_regions_per_validator(U256::from(0)), "Must allow at least one region");
+    }
+
+    #[test]
+    fn test_adding_region_past_cap_reverts() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        
+        validator.set_max_regions_per_validator(U256::from(2)).expect("Set cap failed");
+        validator.register_validator(
+            "validator-one".to_string(),
+            vec!["West Africa".to_string(), "East Africa".to_string()],
+            "ipfs://credentials".to_string(),
+        ).expect("Registration failed");
+        
+        expect_error(validator.add_expertise_region("North Africa".to_string()), "Too many regions");
+        
+        validator.set_max_regions_per_validator(U256::from(3)).expect("Raise cap failed");
+        validator.add_expertise_region("North Africa".to_string()).expect("Add region failed");
+    }
+
+    #[test]
+    fn test_validator_assignments_track_unscored_projects() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let member = context.test_accounts[0];
+        let west_project = U256::from(1);
+        let east_project = U256::from(2);
+        
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        validator.set_project_regions(west_project, vec!["West Africa".to_string()]).expect("Set region failed");
+        validator.set_project_regions(east_project, vec!["East Africa".to_string()]).expect("Set region failed");
+        
+        // Only projects in the validator's regions are assigned
+        assert_eq!(validator.get_validator_assignments(member), vec![west_project]);
+        
+        validator.submit_validation(
+            west_project, U256::from(85), "ipfs://feedback".to_string(), Vec::new()
+        ).expect("Submission failed");
+        assert!(validator.get_validator_assignments(member).is_empty());
+        
+        // Unregistered accounts have no assignments
+        assert!(validator.get_validator_assignments(context.backer()).is_empty());
+    }
+
+    #[test]
+    fn test_validator_assignments_drop_finalized_projects() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        let members = register_quorum(&mut context, &mut validator);
+        context.set_sender(context.test_accounts[0]);
+        validator.set_project_regions(project_id, vec!["West Africa".to_string()]).expect("Set region failed");
+        for member in &members {
+            assert_eq!(validator.get_validator_assignments(*member), vec![project_id]);
+        }
+        
+        submit_and_finalize(&mut context, &mut validator, &members, project_id);
+        for member in &members {
+            assert!(validator.get_validator_assignments(*member).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_project_funded_rewards_credit_its_validators() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        let funding_contract = context.test_accounts[8];
+        let reserve = U256::from(3001);
+        
+        expect_error(validator.fund_project_validation_rewards(project_id), "Reward amount must be positive");
+        
+        let members = register_quorum(&mut context, &mut validator);
+        context.set_sender(funding_contract);
+        context.set_value(reserve);
+        expect_error(validator.fund_project_validation_rewards(project_id), "Validation not finalized");
+        context.set_value(U256::from(0));
+        
+        submit_and_finalize(&mut context, &mut validator, &members, project_id);
+        let before: Vec<U256> = members.iter().map(|m| validator.get_accrued_rewards(*m)).collect();
+        let pool_before = validator.reward_pool_balance();
+        
+        // The funding contract sends the project's reserve along with the call
+        context.set_sender(funding_contract);
+        context.set_value(reserve);
+        let share = validator.fund_project_validation_rewards(project_id).expect("Funding rewards failed");
+        context.set_value(U256::from(0));
+        
+        assert_eq!(share, U256::from(1000));
+        for (member, accrued_before) in members.iter().zip(before) {
+            assert_eq!(validator.get_accrued_rewards(*member), accrued_before + share);
+        }
+        assert_eq!(validator.get_project_funded_rewards(project_id), reserve);
+        assert_eq!(validator.reward_pool_balance(), pool_before + U256::from(1));
+    }
+
+    #[test]
+    fn test_force_finalize_after_deadline_with_emergency_quorum() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        validator.set_validation_window(U256::from(86400)).expect("Set window failed");
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        validator.submit_validation(
+            project_id, U256::from(85), "ipfs://feedback".to_string(), Vec::new()
+        ).expect("Submission failed");
+        
+        // One submission is below the normal quorum of three, but meets the emergency minimum
+        assert!(validator.get_validation_deadline(project_id) > U256::from(0));
+        expect_error(validator.finalize_validation(project_id), "Insufficient validator submissions");
+        
+        context.advance_time(2 * 86400);
+        let final_score = validator.force_finalize_validation(project_id).expect("Force finalize failed");
+        assert_eq!(final_score, U256::from(85));
+        let result = validator.get_validation_status(project_id).expect("Result missing");
+        assert_eq!(result.status, 1); // Approved
+        assert_eq!(result.validator_count, U256::from(1));
+    }
+
+    #[test]
+    fn test_requested_validation_expires_without_submissions() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        validator.set_validation_window(U256::from(86400)).expect("Set window failed");
+        let deadline = validator.request_validation(project_id).expect("Request failed");
+        assert_eq!(deadline, U256::from(context.current_timestamp + 86400));
+        expect_error(validator.request_validation(project_id), "Validation already started");
+        
+        // Nobody picked the project up, so it is rejected once the deadline passes
+        context.advance_time(2 * 86400);
+        assert_eq!(validator.force_finalize_validation(project_id).expect("Force finalize failed"), U256::from(0));
+        let result = validator.get_validation_status(project_id).expect("Result missing");
+        assert_eq!(result.status, 2); // Rejected
+        assert_eq!(result.validator_count, U256::from(0));
+    }
+
+    #[test]
+    fn test_force_finalize_still_needs_region_coverage() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        validator.set_validation_window(U256::from(86400)).expect("Set window failed");
+        validator.set_project_regions(project_id, fusion_regions()).expect("Set regions failed");
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        validator.submit_validation(
+            project_id, U256::from(85), "ipfs://feedback".to_string(), Vec::new()
+        ).expect("Submission failed");
+        
+        // The emergency quorum is met, but no one covered the project's second region
+        context.advance_time(2 * 86400);
+        assert_eq!(validator.force_finalize_validation(project_id).expect("Force finalize failed"), U256::from(0));
+        assert_eq!(validator.get_validation_status(project_id).unwrap().status, 2); // Rejected
+    }
+
+    #[test]
+    fn test_force_finalize_requires_passed_deadline() {
+        let context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        register(&mut validator, "validator-one", "West Africa").expect("Registration failed");
+        expect_error(validator.force_finalize_validation(project_id), "Validation not pending");
+        
+        // Without a window there is no deadline to force against
+        validator.submit_validation(
+            project_id, U256::from(85), "ipfs://feedback".to_string(), Vec::new()
+        ).expect("Submission failed");
+        expect_error(validator.force_finalize_validation(project_id), "No validation deadline");
+        
+        let second_project = U256::from(2);
+        validator.set_validation_window(U256::from(86400)).expect("Set window failed");
+        validator.submit_validation(
+            second_project, U256::from(85), "ipfs://feedback".to_string(), Vec::new()
+        ).expect("Submission failed");
+        expect_error(validator.force_finalize_validation(second_project), "Validation deadline not reached");
+        
+        expect_error(validator.set_emergency_min_validators(U256::from(0)), "Invalid emergency minimum");
+        expect_error(validator.set_emergency_min_validators(U256::from(4)), "Invalid emergency minimum");
+    }
+
+    fn register_quorum(context: &mut TestContext, validator: &mut CulturalValidator) -> Vec<Address> {
+        let (stake, fee) = validator.registration_requirements();
+        let members = vec![context.validator(), context.admin(), context.backer()];
+        for (i, member) in members.iter().enumerate() {
+            context.set_sender(*member);
+            context.set_value(stake + fee);
+            register(validator, &format!("validator-{}", i), "West Africa").expect("Registration failed");
+        }
+        context.set_value(U256::from(0));
+        members
+    }
+
+    #[test]
+    fn test_rewards_scaled_to_pool_and_debited() {
+        let mut context = TestContext::new();
+        let mut validator = setup_validator(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        // Three small fees cannot cover three 0.01 ETH rewards
+        validator.set_registration_fee(U256::from(3000)).expect("Set fee failed");
+        let members = register_quorum(&mut context, &mut validator);
+        let pool = validator.reward_pool_balance();
+        assert_eq!(pool, U256::from(9000));
+        
+        for member in &members {
+            context.set_sender(*member);
+            validator.submit_validation(
+                project_id, U256::from(80), "ipfs://feedback".to_string(), Vec::new()
+            ).expect("Submission failed");
+        }
+        
+        let credited = members.iter().fold(U256::from(0), |acc, member| {
+            acc + validator.get_accrued_rewards(*member)
+        });
+        assert!(credited > U256::from(0));
+        assert!(credited <= pool);
+        assert_eq!(validator.reward_pool_balance(), pool - credited);
+    }
+}
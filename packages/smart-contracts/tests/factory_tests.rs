@@ -0,0 +1,80 @@
+use alloy_primitives::{Address, U256};
+use afrocreate_contracts::{ProjectFactory, projects::project_factory::ProjectCreateRequest};
+use crate::test_utils::*;
+
+#[cfg(test)]
+mod factory_tests {
+    use super::*;
+
+    fn setup_factory(accounts: &[Address]) -> ProjectFactory {
+        let mut factory = ProjectFactory::default();
+        factory.initialize(
+            accounts[5], // platform contract
+            accounts[6], // funding contract
+            accounts[7], // validator contract
+        ).expect("Factory initialization failed");
+        factory
+    }
+
+    fn music_request() -> ProjectCreateRequest {
+        ProjectCreateRequest {
+            title: "Highlife Revival".to_string(),
+            description: "Recording classic highlife with a new generation".to_string(),
+            cultural_category: "Music".to_string(),
+            funding_target: U256::from(1_000_000_000_000_000_000u64), // 1 ETH
+            duration_days: U256::from(30),
+            funding_model: 0, // AllOrNothing
+            milestones: Vec::new(),
+            metadata_uri: "ipfs://highlife".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_own_project_is_fundable_immediately() {
+        let context = TestContext::new();
+        let mut factory = setup_factory(&context.test_accounts);
+        
+        let project_id = factory.create_project(music_request()).expect("Project creation failed");
+        
+        assert_eq!(factory.get_project_creator(project_id), context.test_accounts[0]);
+        assert!(!factory.is_project_draft(project_id));
+        assert!(factory.is_project_fundable(project_id));
+    }
+
+    #[test]
+    fn test_third_party_project_fundable_after_creator_confirms() {
+        let mut context = TestContext::new();
+        let mut factory = setup_factory(&context.test_accounts);
+        let proposer = context.admin();
+        let creator = context.creator();
+        
+        context.set_sender(proposer);
+        let project_id = factory.create_project_for(creator, music_request())
+            .expect("Draft creation failed");
+        assert_eq!(factory.get_project_creator(project_id), creator);
+        assert!(factory.is_project_draft(project_id));
+        assert!(!factory.is_project_fundable(project_id));
+        
+        // The proposer cannot consent on the creator's behalf
+        expect_error(factory.confirm_project(project_id), "Only named creator");
+        
+        context.set_sender(creator);
+        factory.confirm_project(project_id).expect("Confirmation failed");
+        assert!(!factory.is_project_draft(project_id));
+        assert!(factory.is_project_fundable(project_id));
+        
+        expect_error(factory.confirm_project(project_id), "Project not awaiting confirmation");
+    }
+
+    #[test]
+    fn test_only_named_creator_confirms_project() {
+        let context = TestContext::new();
+        let mut factory = setup_factory(&context.test_accounts);
+        
+        let project_id = factory.create_project_for(context.creator(), music_request())
+            .expect("Draft creation failed");
+        
+        expect_error(factory.confirm_project(project_id), "Only named creator");
+        assert!(!factory.is_project_fundable(project_id));
+    }
+}
@@ -0,0 +1,1740 @@
+use alloy_primitives::{Address, U256};
+use afrocreate_contracts::{ProjectFunding, types::{env, Milestone}};
+use crate::test_utils::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[cfg(test)]
+mod funding_tests {
+    use super::*;
+
+    fn setup_funding(accounts: &[Address]) -> ProjectFunding {
+        let mut funding = ProjectFunding::default();
+        funding.initialize(
+            accounts[5], // platform contract
+            accounts[6], // revenue NFT contract
+            U256::from(300),
+        ).expect("Funding initialization failed");
+        funding
+    }
+
+    fn setup_project(context: &TestContext, funding: &mut ProjectFunding, project_id: U256) {
+        funding.setup_project_funding(
+            project_id,
+            U256::from(10000000000000000000u64), // 10 ETH target
+            U256::from(context.current_timestamp + 30 * 86400),
+            context.creator(),
+            U256::from(0), // AllOrNothing
+            Vec::new(),
+            Address::ZERO,
+            U256::from(0), // no minimum backers
+        ).expect("Project funding setup failed");
+    }
+
+    fn setup_milestone_project(context: &TestContext, funding: &mut ProjectFunding, project_id: U256) {
+        let milestone = Milestone {
+            id: U256::from(0),
+            title: "Studio recording".to_string(),
+            description: "Record the album tracks".to_string(),
+            funding_amount: U256::from(10000000000000000000u64),
+            deadline: U256::from(context.current_timestamp + 10 * 86400),
+            is_completed: false,
+            funds_released: false,
+        };
+        
+        funding.setup_project_funding(
+            project_id,
+            U256::from(10000000000000000000u64),
+            U256::from(context.current_timestamp + 30 * 86400),
+            context.creator(),
+            U256::from(2), // MilestoneBased
+            vec![milestone],
+            Address::ZERO,
+            U256::from(0),
+        ).expect("Milestone project setup failed");
+    }
+
+    #[test]
+    fn test_reentry_from_nft_burn_callback_rejected() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        
+        context.set_sender(context.backer());
+        context.set_value(U256::from(2000000000000000000u64));
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string()).expect("Funding failed");
+        context.set_value(U256::from(0));
+        
+        // A malicious NFT contract calls back into funding while withdraw_contribution holds the lock
+        let reentered = Rc::new(Cell::new(false));
+        let observed = reentered.clone();
+        let burn_selector = stylus_sdk::function_selector!("burnRevenueNFT", U256);
+        env::on_call(context.test_accounts[6], burn_selector, move |storage| {
+            let funding = storage.downcast_mut::<ProjectFunding>().expect("Caller is not ProjectFunding");
+            expect_error(funding.claim_refund(project_id), "Reentrant call");
+            expect_error(funding.withdraw_contribution(project_id), "Reentrant call");
+            observed.set(true);
+            Ok(Vec::new())
+        });
+        
+        funding.withdraw_contribution(project_id).expect("Withdrawal failed");
+        assert!(reentered.get());
+        
+        // The outer call released the lock on the way out
+        expect_error(funding.withdraw_contribution(project_id), "No contribution to withdraw");
+    }
+
+    #[test]
+    fn test_claimed_and_swept_refunds_share_escrow_pro_rata() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        let early = context.backer();
+        let late = context.test_accounts[7];
+        
+        context.set_sender(early);
+        context.set_value(U256::from(3000000000000000000u64));
+        funding.fund_project(project_id, "early.afrocreate.eth".to_string()).expect("Funding failed");
+        context.set_sender(late);
+        context.set_value(U256::from(1000000000000000000u64));
+        funding.fund_project(project_id, "late.afrocreate.eth".to_string()).expect("Funding failed");
+        context.set_value(U256::from(0));
+        
+        // The early backer claims first; the sweep then reaches the late backer on the same terms
+        context.advance_time(31 * 86400);
+        context.set_sender(early);
+        let claimed = funding.claim_refund(project_id).expect("Refund failed");
+        assert_eq!(claimed, U256::from(3000000000000000000u64));
+        assert_eq!(funding.get_token_escrow(project_id, Address::ZERO), U256::from(1000000000000000000u64));
+        
+        funding.process_refunds(project_id).expect("Refund sweep failed");
+        assert_eq!(funding.get_backer_contributions(project_id, late), U256::from(0));
+        assert_eq!(funding.get_token_escrow(project_id, Address::ZERO), U256::from(0));
+        assert_eq!(funding.get_refund_credit(late), U256::from(0));
+    }
+
+    #[test]
+    fn test_claim_refund_requires_contribution() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        
+        // Deadline passes without reaching the target
+        context.advance_time(31 * 86400);
+        expect_error(funding.claim_refund(project_id), "No contribution to refund");
+    }
+
+    #[test]
+    fn test_milestone_within_grace_not_delinquent() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_milestone_project(&context, &mut funding, project_id);
+        
+        funding.set_milestone_grace_period(project_id, U256::from(5 * 86400))
+            .expect("Set grace failed");
+        
+        // Two days past the milestone deadline, inside the five day grace
+        context.advance_time(12 * 86400);
+        assert!(!funding.check_milestone_deadline(project_id, U256::from(0)).unwrap());
+    }
+
+    #[test]
+    fn test_milestone_past_grace_is_delinquent() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_milestone_project(&context, &mut funding, project_id);
+        
+        funding.set_milestone_grace_period(project_id, U256::from(5 * 86400))
+            .expect("Set grace failed");
+        
+        context.advance_time(16 * 86400);
+        assert!(funding.check_milestone_deadline(project_id, U256::from(0)).unwrap());
+    }
+
+    #[test]
+    fn test_milestone_grace_capped_by_admin_maximum() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_milestone_project(&context, &mut funding, project_id);
+        
+        funding.set_max_milestone_grace(U256::from(3 * 86400)).expect("Set max grace failed");
+        expect_error(
+            funding.set_milestone_grace_period(project_id, U256::from(5 * 86400)),
+            "Grace period exceeds maximum"
+        );
+    }
+
+    #[test]
+    fn test_token_denominated_project_rejects_native_eth() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        let usdc = context.test_accounts[9];
+        
+        funding.setup_project_funding(
+            project_id,
+            U256::from(10000000000u64), // 10,000 USDC
+            U256::from(context.current_timestamp + 30 * 86400),
+            context.creator(),
+            U256::from(0),
+            Vec::new(),
+            usdc,
+            U256::from(0),
+        ).expect("Project funding setup failed");
+        
+        assert_eq!(funding.get_funding_stats(project_id).unwrap().funding_token, usdc);
+        expect_error(
+            funding.fund_project(project_id, "backer.afrocreate.eth".to_string()),
+            "Project not funded in native token"
+        );
+    }
+
+    #[test]
+    fn test_token_denominated_project_accepts_matching_token() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        let usdc = context.test_accounts[9];
+        let amount = U256::from(5000000000000000u64);
+        
+        funding.setup_project_funding(
+            project_id,
+            U256::from(10000000000000000u64),
+            U256::from(context.current_timestamp + 30 * 86400),
+            context.creator(),
+            U256::from(0),
+            Vec::new(),
+            usdc,
+            U256::from(0),
+        ).expect("Project funding setup failed");
+        
+        expect_error(
+            funding.fund_project_with_token(project_id, context.test_accounts[8], amount, String::new()),
+            "Funding token mismatch"
+        );
+        
+        funding.fund_project_with_token(project_id, usdc, amount, "backer.afrocreate.eth".to_string())
+            .expect("Token funding failed");
+        assert_eq!(funding.get_funding_stats(project_id).unwrap().raised, amount);
+    }
+
+    #[test]
+    fn test_token_contributions_escrowed_and_refunded_in_token() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        let usdc = context.test_accounts[9];
+        let amount = U256::from(5000000000000000u64);
+        
+        funding.setup_project_funding(
+            project_id,
+            U256::from(10000000000000000u64),
+            U256::from(context.current_timestamp + 30 * 86400),
+            context.creator(),
+            U256::from(0), // AllOrNothing
+            Vec::new(),
+            usdc,
+            U256::from(0),
+        ).expect("Project funding setup failed");
+        funding.fund_project_with_token(project_id, usdc, amount, "backer.afrocreate.eth".to_string())
+            .expect("Token funding failed");
+        
+        // Token escrow is kept apart from the native escrow that ETH backers are refunded from
+        assert_eq!(funding.get_token_escrow(project_id, usdc), amount);
+        assert_eq!(funding.get_token_escrow(project_id, Address::ZERO), U256::from(0));
+        
+        // The project misses its target, so the backer is refunded in the token
+        context.advance_time(31 * 86400);
+        assert_eq!(funding.claim_refund(project_id).expect("Refund failed"), amount);
+        assert_eq!(funding.get_token_escrow(project_id, usdc), U256::from(0));
+        assert_eq!(funding.get_token_escrow(project_id, Address::ZERO), U256::from(0));
+    }
+
+    fn setup_token_project(context: &TestContext, funding: &mut ProjectFunding, project_id: U256, token: Address) {
+        funding.setup_project_funding(
+            project_id,
+            U256::from(10000000000000000u64),
+            U256::from(context.current_timestamp + 30 * 86400),
+            context.creator(),
+            U256::from(0),
+            Vec::new(),
+            token,
+            U256::from(0),
+        ).expect("Project funding setup failed");
+    }
+
+    #[test]
+    fn test_token_contribution_pulled_with_transfer_from() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        let usdc = context.test_accounts[9];
+        let amount = U256::from(5000000000000000u64);
+        env::set_contract_address(context.test_accounts[7]);
+        setup_token_project(&context, &mut funding, project_id, usdc);
+        
+        let selector = stylus_sdk::function_selector!("transferFrom", Address, Address, U256);
+        let mut success = vec![0u8; 32];
+        success[31] = 1;
+        env::mock_call(usdc, selector, Ok(success));
+        context.set_sender(context.backer());
+        funding.fund_project_with_token(project_id, usdc, amount, "backer.afrocreate.eth".to_string())
+            .expect("Token funding failed");
+        context.set_sender(context.test_accounts[0]);
+        
+        // The contribution is pulled from the backer into this contract
+        let mut calldata = selector.to_vec();
+        calldata.extend_from_slice(context.backer().into_word().as_slice());
+        calldata.extend_from_slice(context.test_accounts[7].into_word().as_slice());
+        calldata.extend_from_slice(&amount.to_be_bytes::<32>());
+        assert!(env::recorded_calls().contains(&(usdc, U256::from(0), calldata)));
+        assert_eq!(funding.get_token_escrow(project_id, usdc), amount);
+    }
+
+    #[test]
+    fn test_refused_token_pull_credits_nothing() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        let usdc = context.test_accounts[9];
+        setup_token_project(&context, &mut funding, project_id, usdc);
+        
+        // No allowance or a short balance makes the token revert the pull
+        let selector = stylus_sdk::function_selector!("transferFrom", Address, Address, U256);
+        env::mock_call(usdc, selector, Err(Vec::new()));
+        context.set_sender(context.backer());
+        expect_error(
+            funding.fund_project_with_token(
+                project_id, usdc, U256::from(5000000000000000u64), "backer.afrocreate.eth".to_string()
+            ),
+            "Token transfer failed"
+        );
+        context.set_sender(context.test_accounts[0]);
+        
+        assert_eq!(funding.get_funding_stats(project_id).unwrap().raised, U256::from(0));
+        assert_eq!(funding.get_token_escrow(project_id, usdc), U256::from(0));
+    }
+
+    #[test]
+    fn test_backer_voting_power_follows_platform_total() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let governance = context.test_accounts[8];
+        let project_id = U256::from(1);
+        let amount = U256::from(2000000000000000000u64);
+        setup_project(&context, &mut funding, project_id);
+        funding.set_governance_contract(governance).expect("Set governance failed");
+        
+        context.set_sender(context.backer());
+        context.set_value(amount);
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        context.set_value(U256::from(0));
+        funding.withdraw_contribution(project_id).expect("Withdrawal failed");
+        context.set_sender(context.test_accounts[0]);
+        
+        let power_update = |power: U256| {
+            let mut calldata = stylus_sdk::function_selector!("updateVotingPower", Address, u8, U256).to_vec();
+            calldata.extend_from_slice(context.backer().into_word().as_slice());
+            calldata.extend_from_slice(&U256::from(1).to_be_bytes::<32>()); // VotingRole::Backer
+            calldata.extend_from_slice(&power.to_be_bytes::<32>());
+            (governance, U256::from(0), calldata)
+        };
+        let updates: Vec<_> = env::recorded_calls().into_iter()
+            .filter(|(to, _, calldata)| *to == governance && calldata[..4] == power_update(amount).2[..4])
+            .collect();
+        assert_eq!(updates, vec![power_update(amount), power_update(U256::from(0))]);
+    }
+
+    fn setup_funded_treasury(context: &mut TestContext, funding: &mut ProjectFunding) {
+        let project_id = U256::from(1);
+        setup_project(context, funding, project_id);
+        
+        // The backer covers the full 10 ETH target
+        context.set_sender(context.backer());
+        context.set_value(U256::from(10000000000000000000u64));
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        context.set_value(U256::from(0));
+        context.set_sender(context.test_accounts[0]);
+        funding.finalize_successful_project(project_id).expect("Finalize failed");
+    }
+
+    #[test]
+    fn test_withdraw_part_of_treasury() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        setup_funded_treasury(&mut context, &mut funding);
+        
+        let (_, _, _, treasury) = funding.platform_stats();
+        assert!(treasury > U256::from(0));
+        let amount = treasury / U256::from(2);
+        funding.withdraw_treasury(context.admin(), amount).expect("Treasury withdrawal failed");
+        
+        let (_, _, _, remaining) = funding.platform_stats();
+        assert_eq!(remaining, treasury - amount);
+    }
+
+    #[test]
+    fn test_treasury_over_withdrawal_rejected() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        setup_funded_treasury(&mut context, &mut funding);
+        
+        let (_, _, _, treasury) = funding.platform_stats();
+        expect_error(
+            funding.withdraw_treasury(context.admin(), treasury + U256::from(1)),
+            "Insufficient treasury balance"
+        );
+    }
+
+    #[test]
+    fn test_treasury_withdrawal_unauthorized() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        setup_funded_treasury(&mut context, &mut funding);
+        
+        context.set_sender(context.backer());
+        expect_error(
+            funding.withdraw_treasury(context.backer(), U256::from(1)),
+            "Only owner"
+        );
+    }
+
+    #[test]
+    fn test_backer_portfolio_lists_each_project_once() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_ids = vec![U256::from(1), U256::from(2), U256::from(3)];
+        let contribution = U256::from(1000000000000000000u64);
+        
+        for project_id in &project_ids {
+            setup_project(&context, &mut funding, *project_id);
+        }
+        
+        let backer = context.backer();
+        context.set_sender(backer);
+        context.set_value(contribution);
+        for project_id in &project_ids {
+            funding.fund_project(*project_id, "backer.afrocreate.eth".to_string())
+                .expect("Funding failed");
+        }
+        
+        // A repeat contribution must not add the project twice
+        funding.fund_project(project_ids[0], "backer.afrocreate.eth".to_string())
+            .expect("Repeat funding failed");
+        context.set_value(U256::from(0));
+        
+        let portfolio = funding.get_backer_projects(backer);
+        assert_eq!(portfolio, project_ids);
+        assert_eq!(funding.get_backer_contributions(project_ids[0], backer), contribution * U256::from(2));
+        for project_id in &portfolio[1..] {
+            assert_eq!(funding.get_backer_contributions(*project_id, backer), contribution);
+        }
+        assert!(funding.get_backer_projects(context.test_accounts[0]).is_empty());
+    }
+
+    fn usd(amount: u64) -> U256 {
+        U256::from(amount) * U256::from(10u64).pow(U256::from(18))
+    }
+
+    fn setup_normalized_project(
+        context: &TestContext,
+        funding: &mut ProjectFunding,
+        project_id: U256,
+        tokens: Vec<Address>,
+    ) {
+        funding.setup_project_funding(
+            project_id,
+            usd(1000), // $1,000 target
+            U256::from(context.current_timestamp + 30 * 86400),
+            context.creator(),
+            U256::from(0), // AllOrNothing
+            Vec::new(),
+            Address::ZERO,
+            U256::from(0),
+        ).expect("Project funding setup failed");
+        funding.enable_normalized_funding(project_id, tokens)
+            .expect("Enable normalized funding failed");
+    }
+
+    #[test]
+    fn test_mixed_token_contributions_normalized_toward_target() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        let stablecoin = Address::from([31u8; 20]); // 6 decimals, $1
+        let culture_token = Address::from([32u8; 20]); // 18 decimals, $2
+        
+        funding.sync_token_price(stablecoin, usd(1), U256::from(6)).expect("Price sync failed");
+        funding.sync_token_price(culture_token, usd(2), U256::from(18)).expect("Price sync failed");
+        setup_normalized_project(&context, &mut funding, project_id, vec![stablecoin, culture_token]);
+        
+        // 500 units of each token are worth $500 and $1,000 respectively
+        let stablecoin_amount = U256::from(500_000_000u64);
+        let culture_amount = usd(250);
+        assert_eq!(funding.get_normalized_value(stablecoin, stablecoin_amount).unwrap(), usd(500));
+        assert_eq!(funding.get_normalized_value(culture_token, culture_amount).unwrap(), usd(500));
+        
+        funding.fund_project_with_token(project_id, stablecoin, stablecoin_amount, "backer.afrocreate.eth".to_string())
+            .expect("Stablecoin contribution failed");
+        assert_eq!(funding.get_funding_stats(project_id).unwrap().raised, usd(500));
+        
+        funding.fund_project_with_token(project_id, culture_token, culture_amount, "backer.afrocreate.eth".to_string())
+            .expect("Culture token contribution failed");
+        
+        let stats = funding.get_funding_stats(project_id).unwrap();
+        assert_eq!(stats.raised, usd(1000));
+        assert_eq!(stats.status, 1); // Target reached in normalized terms
+        
+        // Escrow stays in each token's raw units
+        assert_eq!(funding.get_token_escrow(project_id, stablecoin), stablecoin_amount);
+        assert_eq!(funding.get_token_escrow(project_id, culture_token), culture_amount);
+    }
+
+    #[test]
+    fn test_normalized_project_rejects_unlisted_token() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        let stablecoin = Address::from([31u8; 20]);
+        let unlisted = Address::from([33u8; 20]);
+        
+        funding.sync_token_price(stablecoin, usd(1), U256::from(6)).expect("Price sync failed");
+        setup_normalized_project(&context, &mut funding, project_id, vec![stablecoin]);
+        
+        expect_error(
+            funding.fund_project_with_token(project_id, unlisted, U256::from(1000), "backer.afrocreate.eth".to_string()),
+            "Token not accepted for project"
+        );
+    }
+
+    #[test]
+    fn test_normalized_funding_requires_price_feed() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        
+        expect_error(
+            funding.enable_normalized_funding(project_id, vec![Address::from([34u8; 20])]),
+            "No price feed for token"
+        );
+    }
+
+    #[test]
+    fn test_normalized_refund_returned_in_native_units() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        // ETH at $2,000: 0.1 ETH is credited as $200 but refunded as 0.1 ETH
+        funding.sync_token_price(Address::ZERO, usd(2000), U256::from(18)).expect("Price sync failed");
+        setup_normalized_project(&context, &mut funding, project_id, vec![Address::ZERO]);
+        
+        let contribution = U256::from(100000000000000000u64);
+        context.set_value(contribution);
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string()).expect("Funding failed");
+        context.set_value(U256::from(0));
+        assert_eq!(funding.get_funding_stats(project_id).unwrap().raised, usd(200));
+        
+        context.advance_time(31 * 86400);
+        assert_eq!(funding.claim_refund(project_id).expect("Refund failed"), contribution);
+        assert_eq!(funding.get_token_escrow(project_id, Address::ZERO), U256::from(0));
+    }
+
+    #[test]
+    fn test_token_decimals_capped() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let token = Address::from([35u8; 20]);
+        
+        funding.sync_token_price(token, usd(1), U256::from(36)).expect("Price sync failed");
+        expect_error(
+            funding.sync_token_price(token, usd(1), U256::from(77)),
+            "Unsupported token decimals"
+        );
+    }
+
+    fn setup_three_milestone_project(context: &TestContext, funding: &mut ProjectFunding, project_id: U256) {
+        let milestones = ["Research", "Recording", "Release"]
+            .iter()
+            .enumerate()
+            .map(|(i, title)| Milestone {
+                id: U256::from(i),
+                title: title.to_string(),
+                description: format!("{} phase", title),
+                funding_amount: U256::from(1000000000000000000u64),
+                deadline: U256::from(context.current_timestamp + (i as u64 + 1) * 10 * 86400),
+                is_completed: false,
+                funds_released: false,
+            })
+            .collect();
+        
+        funding.setup_project_funding(
+            project_id,
+            U256::from(3000000000000000000u64),
+            U256::from(context.current_timestamp + 30 * 86400),
+            context.creator(),
+            U256::from(2), // MilestoneBased
+            milestones,
+            Address::ZERO,
+            U256::from(0),
+        ).expect("Milestone project setup failed");
+    }
+
+    #[test]
+    fn test_reorder_milestones_before_funding() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_three_milestone_project(&context, &mut funding, project_id);
+        let new_order = vec![U256::from(2), U256::from(0), U256::from(1)];
+        
+        // Only the project's creator may reorder, not the contract owner
+        expect_error(funding.reorder_milestones(project_id, new_order.clone()), "Only project creator");
+        
+        context.set_sender(context.creator());
+        funding.reorder_milestones(project_id, new_order).expect("Reorder failed");
+        
+        let titles: Vec<String> = funding.get_project_milestones(project_id)
+            .into_iter()
+            .map(|milestone| milestone.title)
+            .collect();
+        assert_eq!(titles, vec!["Release", "Research", "Recording"]);
+        
+        let milestones = funding.get_project_milestones(project_id);
+        for (position, milestone) in milestones.iter().enumerate() {
+            assert_eq!(milestone.id, U256::from(position));
+        }
+    }
+
+    #[test]
+    fn test_reorder_milestones_rejects_duplicate_index() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_three_milestone_project(&context, &mut funding, project_id);
+        
+        context.set_sender(context.creator());
+        expect_error(
+            funding.reorder_milestones(project_id, vec![U256::from(0), U256::from(0), U256::from(1)]),
+            "Duplicate milestone index"
+        );
+    }
+
+    #[test]
+    fn test_reorder_milestones_rejected_after_first_contribution() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_three_milestone_project(&context, &mut funding, project_id);
+        
+        context.set_sender(context.backer());
+        context.set_value(U256::from(1000000000000000000u64));
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        context.set_value(U256::from(0));
+        
+        context.set_sender(context.creator());
+        expect_error(
+            funding.reorder_milestones(project_id, vec![U256::from(2), U256::from(1), U256::from(0)]),
+            "Funding already started"
+        );
+    }
+
+    #[test]
+    fn test_platform_backer_total_accumulates_across_projects() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        setup_project(&context, &mut funding, U256::from(1));
+        setup_project(&context, &mut funding, U256::from(2));
+        let one_eth = U256::from(1000000000000000000u64);
+        
+        let backer = context.backer();
+        context.set_sender(backer);
+        context.set_value(one_eth);
+        funding.fund_project(U256::from(1), "backer.afrocreate.eth".to_string())
+            .expect("First funding failed");
+        context.set_value(one_eth * U256::from(2));
+        funding.fund_project(U256::from(2), "backer.afrocreate.eth".to_string())
+            .expect("Second funding failed");
+        context.set_value(U256::from(0));
+        
+        assert_eq!(funding.get_backer_platform_total(backer), one_eth * U256::from(3));
+        assert_eq!(funding.get_backer_platform_total(context.test_accounts[0]), U256::from(0));
+    }
+
+    #[test]
+    fn test_platform_leaderboard_ranks_largest_first() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        setup_project(&context, &mut funding, U256::from(1));
+        setup_project(&context, &mut funding, U256::from(2));
+        let one_eth = U256::from(1000000000000000000u64);
+        let (small, large) = (context.backer(), context.admin());
+        
+        // The small backer spreads 2 ETH over two projects; the large backer puts 3 ETH into one
+        for (backer, project_id, amount) in [
+            (small, U256::from(1), one_eth),
+            (large, U256::from(2), one_eth * U256::from(3)),
+            (small, U256::from(2), one_eth),
+        ] {
+            context.set_sender(backer);
+            context.set_value(amount);
+            funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+                .expect("Funding failed");
+        }
+        context.set_value(U256::from(0));
+        
+        let leaderboard = funding.get_top_platform_backers(U256::from(10));
+        assert_eq!(leaderboard, vec![(large, one_eth * U256::from(3)), (small, one_eth * U256::from(2))]);
+        assert_eq!(funding.get_top_platform_backers(U256::from(1)), vec![(large, one_eth * U256::from(3))]);
+        assert!(funding.get_top_platform_backers(U256::from(0)).is_empty());
+    }
+
+    #[test]
+    fn test_full_leaderboard_backfilled_after_withdrawal() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        let step = U256::from(1000000000000000u64);
+        
+        // 51 backers, each contributing more than the last; the first one misses the top 50
+        let backers = generate_test_accounts(61)[10..].to_vec();
+        for (i, backer) in backers.iter().enumerate() {
+            context.set_sender(*backer);
+            context.set_value(step * U256::from(i + 1));
+            funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+                .expect("Funding failed");
+        }
+        context.set_value(U256::from(0));
+        
+        let leaderboard = funding.get_top_platform_backers(U256::from(100));
+        assert_eq!(leaderboard.len(), 50);
+        assert_eq!(leaderboard[0], (backers[50], step * U256::from(51)));
+        assert_eq!(leaderboard[49], (backers[1], step * U256::from(2)));
+        
+        // The leader withdraws, and the backer just below the leaderboard takes the freed place
+        context.set_sender(backers[50]);
+        funding.withdraw_contribution(project_id).expect("Withdrawal failed");
+        context.set_sender(context.test_accounts[0]);
+        
+        let leaderboard = funding.get_top_platform_backers(U256::from(100));
+        assert_eq!(leaderboard.len(), 50);
+        assert_eq!(leaderboard[0], (backers[49], step * U256::from(50)));
+        assert_eq!(leaderboard[49], (backers[0], step));
+        assert!(!leaderboard.iter().any(|(backer, _)| *backer == backers[50]));
+    }
+
+    #[test]
+    fn test_funding_receipt_matches_minted_nft() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        let contribution = U256::from(2000000000000000000u64);
+        
+        let backer = context.backer();
+        context.set_sender(backer);
+        context.set_value(contribution);
+        let receipt = funding.fund_project_with_receipt(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        
+        assert_eq!(receipt.contribution, contribution);
+        assert_eq!(receipt.contribution, funding.get_backer_contributions(project_id, backer));
+        assert_eq!(receipt.total_raised, funding.get_funding_stats(project_id).unwrap().raised);
+        assert_eq!(
+            receipt.revenue_share_bps,
+            funding.calculate_revenue_share(project_id, receipt.contribution).unwrap()
+        );
+        // Token IDs are derived from the project and contribution at mint time
+        assert_eq!(receipt.token_id, project_id * U256::from(10000) + receipt.contribution);
+    }
+
+    #[test]
+    fn test_funding_pause_blocks_contributions_but_not_refunds() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        let backer = context.backer();
+        let contribution = U256::from(1000000000000000000u64);
+        
+        context.set_sender(backer);
+        context.set_value(contribution);
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        context.set_sender(context.test_accounts[0]);
+        funding.set_funding_paused(true).expect("Funding pause failed");
+        assert!(funding.is_funding_paused());
+        
+        context.set_sender(backer);
+        expect_error(
+            funding.fund_project(project_id, "backer.afrocreate.eth".to_string()),
+            "Funding is paused"
+        );
+        context.set_value(U256::from(0));
+        expect_error(
+            funding.fund_project_with_token(project_id, context.test_accounts[8], U256::from(1000), String::new()),
+            "Funding is paused"
+        );
+        
+        // Refunds are gated only by eligibility, never by the funding pause
+        context.advance_time(31 * 86400);
+        assert_eq!(funding.claim_refund(project_id).expect("Refund failed"), contribution);
+        assert_eq!(funding.get_backer_contributions(project_id, backer), U256::from(0));
+    }
+
+    fn setup_min_backer_project(context: &TestContext, funding: &mut ProjectFunding, project_id: U256, min_backers: u64) {
+        funding.setup_project_funding(
+            project_id,
+            U256::from(1), // any contribution meets the target
+            U256::from(context.current_timestamp + 30 * 86400),
+            context.creator(),
+            U256::from(0), // AllOrNothing
+            Vec::new(),
+            Address::ZERO,
+            U256::from(min_backers),
+        ).expect("Project funding setup failed");
+    }
+
+    #[test]
+    fn test_target_met_without_min_backers_stays_active() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_min_backer_project(&context, &mut funding, project_id, 3);
+        
+        // A single whale covers the target many times over
+        context.set_sender(context.backer());
+        context.set_value(U256::from(5000000000000000000u64));
+        funding.fund_project(project_id, "whale.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        context.set_value(U256::from(0));
+        
+        let stats = funding.get_funding_stats(project_id).unwrap();
+        assert!(stats.raised >= stats.target);
+        assert_eq!(stats.backer_count, U256::from(1));
+        assert_eq!(stats.status, 0); // Still active
+    }
+
+    #[test]
+    fn test_target_and_min_backers_met_succeeds() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_min_backer_project(&context, &mut funding, project_id, 1);
+        
+        context.set_sender(context.backer());
+        context.set_value(U256::from(1000));
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        context.set_value(U256::from(0));
+        
+        let stats = funding.get_funding_stats(project_id).unwrap();
+        assert_eq!(stats.backer_count, U256::from(1));
+        assert_eq!(stats.status, 1); // Successful
+    }
+
+    #[test]
+    fn test_reinvestment_credits_backer_on_target() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let target_project_id = U256::from(2);
+        setup_project(&context, &mut funding, target_project_id);
+        
+        let backer = context.backer();
+        let before = funding.get_backer_contributions(target_project_id, backer);
+        
+        // The revenue NFT contract forwards the holder's reinvested revenue
+        context.set_sender(context.test_accounts[6]);
+        context.set_value(U256::from(2500));
+        let receipt = funding.reinvest_contribution(target_project_id, backer).expect("Reinvestment failed");
+        context.set_value(U256::from(0));
+        
+        assert_eq!(receipt.contribution, U256::from(2500));
+        assert_eq!(funding.get_backer_contributions(target_project_id, backer), before + U256::from(2500));
+        assert_eq!(funding.get_backer_contributions(target_project_id, context.test_accounts[6]), U256::from(0));
+        assert!(funding.get_backer_projects(backer).contains(&target_project_id));
+    }
+
+    #[test]
+    fn test_reinvestment_requires_existing_target() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        
+        expect_error(
+            funding.reinvest_contribution(U256::from(99), context.backer()),
+            "Project not found"
+        );
+    }
+
+    #[test]
+    fn test_refund_sweep_completes_and_credits_failed_transfers() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        let eoa_backer = context.backer();
+        let contract_backer = context.test_accounts[7];
+        let contribution = U256::from(1000000000000000000u64);
+        
+        for (backer, ens) in [(eoa_backer, "backer.afrocreate.eth"), (contract_backer, "vault.afrocreate.eth")] {
+            context.set_sender(backer);
+            context.set_value(contribution);
+            funding.fund_project(project_id, ens.to_string()).expect("Funding failed");
+        }
+        context.set_value(U256::from(0));
+        
+        // The contract backer's receive() reverts, which must not block the sweep
+        context.set_rejects_value(contract_backer, true);
+        context.advance_time(31 * 86400);
+        funding.process_refunds(project_id).expect("Refund sweep failed");
+        
+        assert_eq!(funding.get_funding_stats(project_id).unwrap().status, 2);
+        assert_eq!(funding.get_backer_contributions(project_id, eoa_backer), U256::from(0));
+        assert_eq!(funding.get_backer_contributions(project_id, contract_backer), U256::from(0));
+        
+        // The EOA was paid directly; the failed transfer became a withdrawable credit
+        assert_eq!(funding.get_refund_credit(eoa_backer), U256::from(0));
+        assert_eq!(funding.get_refund_credit(contract_backer), contribution);
+        context.set_sender(eoa_backer);
+        expect_error(funding.withdraw_refund_credit(), "No refund credit");
+    }
+
+    #[test]
+    fn test_refund_credit_withdrawn_once_receiver_accepts() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        let contract_backer = context.test_accounts[7];
+        let contribution = U256::from(1000000000000000000u64);
+        
+        context.set_sender(contract_backer);
+        context.set_value(contribution);
+        funding.fund_project(project_id, "vault.afrocreate.eth".to_string()).expect("Funding failed");
+        context.set_value(U256::from(0));
+        
+        context.set_rejects_value(contract_backer, true);
+        context.advance_time(31 * 86400);
+        funding.process_refunds(project_id).expect("Refund sweep failed");
+        
+        // Still rejecting: the pull reverts and the credit survives
+        assert!(funding.withdraw_refund_credit().is_err());
+        assert_eq!(funding.get_refund_credit(contract_backer), contribution);
+        
+        context.set_rejects_value(contract_backer, false);
+        let withdrawn = funding.withdraw_refund_credit().expect("Credit withdrawal failed");
+        assert_eq!(withdrawn, contribution);
+        assert_eq!(funding.get_refund_credit(contract_backer), U256::from(0));
+        expect_error(funding.withdraw_refund_credit(), "No refund credit");
+    }
+
+    #[test]
+    fn test_large_project_fee_hits_absolute_cap() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let one_eth = U256::from(1_000_000_000_000_000_000u64);
+        
+        funding.set_max_platform_fee_absolute(one_eth).expect("Set fee cap failed");
+        
+        // 3% of 100 ETH would be 3 ETH; the cap holds it at 1 ETH and the rest goes to the creator
+        let raised = one_eth * U256::from(100);
+        let (fee, creator_amount) = funding.preview_platform_fee(raised);
+        assert_eq!(fee, one_eth);
+        assert_eq!(creator_amount, raised - one_eth);
+    }
+
+    #[test]
+    fn test_small_project_uses_bps_fee_under_cap() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let one_eth = U256::from(1_000_000_000_000_000_000u64);
+        
+        funding.set_max_platform_fee_absolute(one_eth).expect("Set fee cap failed");
+        
+        let raised = one_eth * U256::from(10);
+        let (fee, creator_amount) = funding.preview_platform_fee(raised);
+        assert_eq!(fee, raised * U256::from(300) / U256::from(10000));
+        assert_eq!(fee + creator_amount, raised);
+    }
+
+    #[test]
+    fn test_minority_dispute_flags_do_not_freeze_releases() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_milestone_project(&context, &mut funding, project_id);
+        
+        let flagger = context.backer();
+        let other_backer = context.test_accounts[7];
+        context.set_sender(flagger);
+        context.set_value(U256::from(1000));
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string()).expect("Funding failed");
+        context.set_sender(other_backer);
+        context.set_value(U256::from(2000));
+        funding.fund_project(project_id, "other.afrocreate.eth".to_string()).expect("Funding failed");
+        context.set_value(U256::from(0));
+        
+        // The flagging backer holds a third of contributions
+        context.set_sender(flagger);
+        let frozen = funding.flag_creator_dispute(project_id, "ipfs://dispute".to_string())
+            .expect("Flag failed");
+        assert!(!frozen);
+        assert!(funding.has_flagged_dispute(project_id, flagger));
+        
+        let (flagged_total, frozen) = funding.get_dispute_status(project_id);
+        assert_eq!(flagged_total, funding.get_backer_contributions(project_id, flagger));
+        assert!(!frozen);
+        
+        expect_error(
+            funding.flag_creator_dispute(project_id, "ipfs://dispute".to_string()),
+            "Dispute already flagged"
+        );
+    }
+
+    #[test]
+    fn test_majority_dispute_flags_freeze_releases() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_milestone_project(&context, &mut funding, project_id);
+        
+        let flagger = context.backer();
+        context.set_sender(flagger);
+        context.set_value(U256::from(2000));
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string()).expect("Funding failed");
+        context.set_sender(context.test_accounts[7]);
+        context.set_value(U256::from(1000));
+        funding.fund_project(project_id, "other.afrocreate.eth".to_string()).expect("Funding failed");
+        context.set_value(U256::from(0));
+        
+        // The flagging backer holds two thirds of contributions
+        context.set_sender(flagger);
+        let frozen = funding.flag_creator_dispute(project_id, "ipfs://dispute".to_string())
+            .expect("Flag failed");
+        assert!(frozen);
+        context.set_sender(context.test_accounts[0]);
+        expect_error(
+            funding.release_milestone_funds(project_id, U256::from(0)),
+            "Milestone releases frozen by dispute"
+        );
+        
+        // Review lifts the freeze and clears the flags
+        funding.resolve_creator_dispute(project_id).expect("Resolve failed");
+        assert_eq!(funding.get_dispute_status(project_id), (U256::from(0), false));
+        assert!(!funding.has_flagged_dispute(project_id, flagger));
+    }
+
+    #[test]
+    fn test_milestone_release_reported_to_platform() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_milestone_project(&context, &mut funding, project_id);
+        
+        context.set_sender(context.backer());
+        context.set_value(U256::from(10000000000000000000u64));
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string()).expect("Funding failed");
+        context.set_value(U256::from(0));
+        context.set_sender(context.test_accounts[0]);
+        
+        funding.mark_milestone_completed(project_id, U256::from(0)).expect("Completion failed");
+        funding.release_milestone_funds(project_id, U256::from(0)).expect("Release failed");
+        
+        // The platform credits the creator's reputation for the delivered milestone
+        let mut calldata = stylus_sdk::function_selector!("recordMilestoneCompleted", U256, U256).to_vec();
+        calldata.extend_from_slice(&project_id.to_be_bytes::<32>());
+        calldata.extend_from_slice(&U256::from(0).to_be_bytes::<32>());
+        assert!(env::recorded_calls().contains(&(context.test_accounts[5], U256::from(0), calldata)));
+        expect_error(
+            funding.mark_milestone_completed(project_id, U256::from(0)),
+            "Milestone already completed"
+        );
+    }
+
+    #[test]
+    fn test_milestone_release_fails_when_platform_rejects_report() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_milestone_project(&context, &mut funding, project_id);
+        
+        context.set_sender(context.backer());
+        context.set_value(U256::from(10000000000000000000u64));
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string()).expect("Funding failed");
+        context.set_value(U256::from(0));
+        context.set_sender(context.test_accounts[0]);
+        funding.mark_milestone_completed(project_id, U256::from(0)).expect("Completion failed");
+        
+        let selector = stylus_sdk::function_selector!("recordMilestoneCompleted", U256, U256);
+        env::mock_call(context.test_accounts[5], selector, Err(Vec::new()));
+        expect_error(
+            funding.release_milestone_funds(project_id, U256::from(0)),
+            "Platform milestone update failed"
+        );
+    }
+
+    #[test]
+    fn test_dispute_flag_requires_contribution() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_milestone_project(&context, &mut funding, project_id);
+        
+        expect_error(
+            funding.flag_creator_dispute(project_id, "ipfs://dispute".to_string()),
+            "Only backers can flag disputes"
+        );
+    }
+
+    fn setup_funded_token_project(context: &TestContext, funding: &mut ProjectFunding, project_id: U256, token: Address) -> U256 {
+        let target = U256::from(10000000000000000u64);
+        funding.setup_project_funding(
+            project_id,
+            target,
+            U256::from(context.current_timestamp + 30 * 86400),
+            context.creator(),
+            U256::from(0), // AllOrNothing
+            Vec::new(),
+            token,
+            U256::from(0),
+        ).expect("Project funding setup failed");
+        
+        funding.fund_project_with_token(project_id, token, target, "backer.afrocreate.eth".to_string())
+            .expect("Token funding failed");
+        target
+    }
+
+    #[test]
+    fn test_discounted_token_project_pays_reduced_fee() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let usdc = context.test_accounts[9];
+        
+        funding.set_token_fee_bps(usdc, U256::from(100)).expect("Set token fee failed");
+        assert_eq!(funding.get_token_fee_bps(usdc), U256::from(100));
+        
+        let raised = setup_funded_token_project(&context, &mut funding, U256::from(1), usdc);
+        funding.finalize_successful_project(U256::from(1)).expect("Finalize failed");
+        
+        assert_eq!(funding.get_token_treasury(usdc), raised * U256::from(100) / U256::from(10000));
+    }
+
+    #[test]
+    fn test_default_token_project_pays_standard_fee() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let usdc = context.test_accounts[9];
+        let dai = context.test_accounts[8];
+        
+        funding.set_token_fee_bps(usdc, U256::from(100)).expect("Set token fee failed");
+        
+        let raised = setup_funded_token_project(&context, &mut funding, U256::from(1), dai);
+        funding.finalize_successful_project(U256::from(1)).expect("Finalize failed");
+        
+        assert_eq!(funding.get_token_fee_bps(dai), U256::from(300));
+        assert_eq!(funding.get_token_treasury(dai), raised * U256::from(300) / U256::from(10000));
+    }
+
+    #[test]
+    fn test_emergency_withdraw_halts_project_and_records_claims() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        let backer = context.test_accounts[0];
+        setup_project(&context, &mut funding, project_id);
+        
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string()).expect("Funding failed");
+        let contribution = funding.get_backer_contributions(project_id, backer);
+        
+        funding.emergency_withdraw(project_id).expect("Emergency withdraw failed");
+        
+        assert_eq!(funding.get_funding_stats(project_id).unwrap().status, 4); // EmergencyHalted
+        assert_eq!(funding.get_emergency_withdrawn(project_id), contribution);
+        // Contributions stay on record as the backer's claim
+        assert_eq!(funding.get_backer_contributions(project_id, backer), contribution);
+        assert_eq!(funding.get_emergency_claim(project_id, backer), contribution);
+    }
+
+    fn setup_halted_project(context: &TestContext, funding: &mut ProjectFunding, project_id: U256) {
+        setup_project(context, funding, project_id);
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string()).expect("Funding failed");
+        funding.emergency_withdraw(project_id).expect("Emergency withdraw failed");
+    }
+
+    #[test]
+    fn test_halted_project_blocks_funding() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_halted_project(&context, &mut funding, project_id);
+        
+        expect_error(funding.emergency_withdraw(project_id), "Project emergency halted");
+        expect_error(
+            funding.fund_project(project_id, "backer.afrocreate.eth".to_string()),
+            "Project emergency halted"
+        );
+    }
+
+    #[test]
+    fn test_halted_project_blocks_refunds() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_halted_project(&context, &mut funding, project_id);
+        
+        expect_error(funding.claim_refund(project_id), "Project emergency halted");
+    }
+
+    #[test]
+    fn test_halted_project_blocks_refund_sweep() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_halted_project(&context, &mut funding, project_id);
+        
+        expect_error(funding.process_refunds(project_id), "Project emergency halted");
+    }
+
+    #[test]
+    fn test_milestone_project_target_must_match_milestones() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_milestone_project(&context, &mut funding, project_id);
+        
+        expect_error(
+            funding.adjust_funding_target(project_id, U256::from(20000000000000000000u128)),
+            "Milestone total must match target"
+        );
+        
+        let standard_project_id = U256::from(2);
+        setup_project(&context, &mut funding, standard_project_id);
+        funding.adjust_funding_target(standard_project_id, U256::from(20000000000000000000u128))
+            .expect("Adjustment failed");
+        assert_eq!(
+            funding.get_funding_stats(standard_project_id).unwrap().target,
+            U256::from(20000000000000000000u128)
+        );
+    }
+
+    #[test]
+    fn test_finalization_tops_up_cultural_fund() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        
+        let governance = context.test_accounts[8];
+        funding.set_governance_contract(governance).expect("Set governance failed");
+        context.set_sender(governance);
+        funding.set_cultural_fund_auto_bps(U256::from(2500)).expect("Set cultural share failed");
+        context.set_sender(context.test_accounts[0]);
+        setup_funded_treasury(&mut context, &mut funding);
+        
+        let raised = funding.get_funding_stats(U256::from(1)).unwrap().raised;
+        let (platform_fee, _) = funding.preview_platform_fee(raised);
+        let cultural_share = platform_fee * U256::from(2500) / U256::from(10000);
+        
+        let (share_bps, contributed) = funding.get_cultural_fund_auto_contribution();
+        assert_eq!(share_bps, U256::from(2500));
+        assert_eq!(contributed, cultural_share);
+        let (_, _, _, treasury) = funding.platform_stats();
+        assert_eq!(treasury, platform_fee - cultural_share);
+    }
+
+    #[test]
+    fn test_cultural_share_stays_in_treasury_without_governance() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        
+        let governance = context.test_accounts[8];
+        funding.set_governance_contract(governance).expect("Set governance failed");
+        context.set_sender(governance);
+        funding.set_cultural_fund_auto_bps(U256::from(2500)).expect("Set cultural share failed");
+        context.set_sender(context.test_accounts[0]);
+        funding.set_governance_contract(Address::ZERO).expect("Clear governance failed");
+        setup_funded_treasury(&mut context, &mut funding);
+        
+        // Nothing is forwarded, so the whole fee is kept by the treasury
+        let raised = funding.get_funding_stats(U256::from(1)).unwrap().raised;
+        let (platform_fee, _) = funding.preview_platform_fee(raised);
+        let (_, contributed) = funding.get_cultural_fund_auto_contribution();
+        assert_eq!(contributed, U256::from(0));
+        let (_, _, _, treasury) = funding.platform_stats();
+        assert_eq!(treasury, platform_fee);
+    }
+
+    #[test]
+    fn test_cultural_fund_share_gated_to_governance() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let governance = context.test_accounts[8];
+        
+        expect_error(funding.set_cultural_fund_auto_bps(U256::from(2500)), "Only governance");
+        
+        // Configuring governance does not let the owner set the share itself
+        funding.set_governance_contract(governance).expect("Set governance failed");
+        expect_error(funding.set_cultural_fund_auto_bps(U256::from(2500)), "Only governance");
+        
+        context.set_sender(governance);
+        expect_error(funding.set_cultural_fund_auto_bps(U256::from(10001)), "Invalid cultural fund share");
+    }
+
+    #[test]
+    fn test_voluntary_withdrawal_pays_refund_fee() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        let backer = context.backer();
+        setup_min_backer_project(&context, &mut funding, project_id, 2);
+        
+        funding.set_refund_fee_bps(U256::from(200)).expect("Set refund fee failed");
+        context.set_sender(backer);
+        context.set_value(U256::from(1000000000000000000u64));
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        context.set_value(U256::from(0));
+        let contribution = funding.get_backer_contributions(project_id, backer);
+        assert_eq!(contribution, U256::from(1000000000000000000u64));
+        
+        let net = funding.withdraw_contribution(project_id).expect("Withdrawal failed");
+        let fee = contribution * U256::from(200) / U256::from(10000);
+        assert_eq!(net, contribution - fee);
+        
+        // Every platform-wide counter the contribution raised is unwound
+        let (_, total_raised, total_backers, treasury) = funding.platform_stats();
+        assert_eq!(treasury, fee);
+        assert_eq!(total_raised, U256::from(0));
+        assert_eq!(total_backers, U256::from(0));
+        assert_eq!(funding.get_backer_platform_total(backer), U256::from(0));
+        assert!(funding.get_top_platform_backers(U256::from(10)).is_empty());
+        let stats = funding.get_funding_stats(project_id).unwrap();
+        assert_eq!(stats.raised, U256::from(0));
+        assert_eq!(stats.backer_count, U256::from(0));
+        assert!(funding.get_project_backers(project_id).is_empty());
+    }
+
+    #[test]
+    fn test_withdrawal_revokes_revenue_share_and_dispute_flag() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        let backer = context.backer();
+        setup_min_backer_project(&context, &mut funding, project_id, 2);
+        
+        context.set_sender(backer);
+        context.set_value(U256::from(2000000000000000000u64));
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string()).expect("Funding failed");
+        context.set_value(U256::from(0));
+        let token_ids = funding.get_backer_revenue_nfts(project_id, backer);
+        assert_eq!(token_ids.len(), 1);
+        
+        funding.flag_creator_dispute(project_id, "ipfs://dispute".to_string()).expect("Flag failed");
+        funding.withdraw_contribution(project_id).expect("Withdrawal failed");
+        
+        // The NFT is burned on the revenue NFT contract, so its share stops earning
+        let mut burn_calldata = stylus_sdk::function_selector!("burnRevenueNFT", U256).to_vec();
+        burn_calldata.extend_from_slice(&token_ids[0].to_be_bytes::<32>());
+        assert!(env::recorded_calls().contains(&(context.test_accounts[6], U256::from(0), burn_calldata)));
+        
+        // The NFT share and the dispute weight leave with the contribution
+        assert!(funding.get_backer_revenue_nfts(project_id, backer).is_empty());
+        assert!(!funding.has_flagged_dispute(project_id, backer));
+        assert_eq!(funding.get_dispute_status(project_id).0, U256::from(0));
+    }
+
+    #[test]
+    fn test_failed_project_refund_not_charged_refund_fee() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        let backer = context.backer();
+        let contribution = U256::from(1000000000000000000u64);
+        setup_project(&context, &mut funding, project_id);
+        
+        funding.set_refund_fee_bps(U256::from(200)).expect("Set refund fee failed");
+        expect_error(funding.set_refund_fee_bps(U256::from(1001)), "Fee too high");
+        context.set_sender(backer);
+        context.set_value(contribution);
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        context.set_value(U256::from(0));
+        
+        // The deadline passes with 1 ETH of a 10 ETH target raised
+        context.advance_time(31 * 86400);
+        let refund = funding.claim_refund(project_id).expect("Refund failed");
+        assert_eq!(refund, contribution);
+        let (_, _, _, treasury) = funding.platform_stats();
+        assert_eq!(treasury, U256::from(0));
+    }
+
+    #[test]
+    fn test_refund_claims_allowed_while_funding_paused() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        let backer = context.backer();
+        let contribution = U256::from(1000000000000000000u64);
+        setup_project(&context, &mut funding, project_id);
+        
+        context.set_sender(backer);
+        context.set_value(contribution);
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        context.set_sender(context.test_accounts[0]);
+        funding.set_funding_paused(true).expect("Funding pause failed");
+        assert!(funding.claims_allowed_while_paused());
+        
+        context.set_sender(backer);
+        expect_error(
+            funding.fund_project(project_id, "backer.afrocreate.eth".to_string()),
+            "Funding is paused"
+        );
+        context.set_value(U256::from(0));
+        
+        context.advance_time(31 * 86400);
+        assert_eq!(funding.claim_refund(project_id).expect("Refund failed"), contribution);
+    }
+
+    #[test]
+    fn test_refund_claims_blocked_when_toggle_off() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        
+        funding.set_claims_allowed_while_paused(false).expect("Toggle failed");
+        assert!(!funding.claims_allowed_while_paused());
+        
+        // The toggle only bites while funding is paused
+        expect_error(funding.claim_refund(project_id), "No contribution to refund");
+    }
+
+    #[test]
+    fn test_paused_refund_claims_respect_toggle() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        
+        funding.set_claims_allowed_while_paused(false).expect("Toggle failed");
+        funding.set_funding_paused(true).expect("Funding pause failed");
+        expect_error(funding.claim_refund(project_id), "Claims are paused");
+    }
+
+    fn setup_matched_project(context: &mut TestContext, funding: &mut ProjectFunding, project_id: U256) {
+        setup_project(context, funding, project_id);
+        funding.set_governance_contract(context.admin()).expect("Set governance failed");
+        
+        context.set_sender(context.backer());
+        context.set_value(U256::from(2000000000000000000u64));
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        
+        // The governance treasury sends the match from its own account
+        context.set_sender(context.admin());
+        context.set_value(U256::from(1000000000000000000u64));
+        funding.add_matched_funds(project_id).expect("Match failed");
+        
+        context.set_value(U256::from(0));
+        context.set_sender(context.test_accounts[0]);
+    }
+
+    #[test]
+    fn test_failed_matched_project_refunds_backers_and_returns_match() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_matched_project(&mut context, &mut funding, project_id);
+        
+        let matched = funding.get_matched_funds(project_id);
+        let contribution = funding.get_backer_contributions(project_id, context.backer());
+        assert_eq!(matched, U256::from(1000000000000000000u64));
+        assert_eq!(funding.get_token_escrow(project_id, Address::ZERO), contribution + matched);
+        
+        context.advance_time(31 * 86400);
+        funding.process_refunds(project_id).expect("Refund processing failed");
+        
+        // Backers got exactly their contributions; the match went back to the treasury
+        assert_eq!(funding.get_backer_contributions(project_id, context.backer()), U256::from(0));
+        assert_eq!(funding.get_matched_funds(project_id), U256::from(0));
+        assert_eq!(funding.get_token_escrow(project_id, Address::ZERO), U256::from(0));
+    }
+
+    #[test]
+    fn test_individual_refund_leaves_match_for_treasury() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_matched_project(&mut context, &mut funding, project_id);
+        
+        let matched = funding.get_matched_funds(project_id);
+        let contribution = funding.get_backer_contributions(project_id, context.backer());
+        
+        context.advance_time(31 * 86400);
+        context.set_sender(context.backer());
+        let refund = funding.claim_refund(project_id).expect("Refund claim failed");
+        assert_eq!(refund, contribution);
+        assert_eq!(funding.get_token_escrow(project_id, Address::ZERO), matched);
+        
+        assert_eq!(funding.return_matched_funds(project_id).expect("Return failed"), matched);
+        assert_eq!(funding.get_matched_funds(project_id), U256::from(0));
+        assert_eq!(funding.get_token_escrow(project_id, Address::ZERO), U256::from(0));
+    }
+
+    #[test]
+    fn test_matched_funds_need_a_treasury_to_return_to() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_matched_project(&mut context, &mut funding, project_id);
+        
+        funding.set_governance_contract(Address::ZERO).expect("Clear governance failed");
+        context.advance_time(31 * 86400);
+        expect_error(funding.return_matched_funds(project_id), "Treasury not set");
+        assert_eq!(funding.get_matched_funds(project_id), U256::from(1000000000000000000u64));
+    }
+
+    #[test]
+    fn test_matched_funds_only_from_governance() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        
+        expect_error(funding.add_matched_funds(project_id), "Only governance");
+        expect_error(funding.return_matched_funds(project_id), "Refunds not available");
+    }
+
+    fn finalize_with_score(context: &TestContext, funding: &mut ProjectFunding, project_id: U256, score: u64) -> U256 {
+        setup_project(context, funding, project_id);
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        funding.sync_validation_score(project_id, U256::from(score)).expect("Score sync failed");
+        
+        let (_, _, _, before) = funding.platform_stats();
+        funding.finalize_successful_project(project_id).expect("Finalize failed");
+        let (_, _, _, after) = funding.platform_stats();
+        after - before
+    }
+
+    #[test]
+    fn test_high_validation_score_earns_fee_rebate() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        funding.set_fee_rebate(U256::from(90), U256::from(5000)).expect("Set rebate failed");
+        assert_eq!(funding.get_fee_rebate(), (U256::from(90), U256::from(5000)));
+        
+        let standard_fee = finalize_with_score(&context, &mut funding, U256::from(1), 80);
+        let rebated_fee = finalize_with_score(&context, &mut funding, U256::from(2), 95);
+        
+        // Half of the fee goes back to the creator of the highly-validated project
+        assert_eq!(rebated_fee, standard_fee - standard_fee * U256::from(5000) / U256::from(10000));
+    }
+
+    #[test]
+    fn test_fee_rebate_requires_exceeding_threshold() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        funding.set_fee_rebate(U256::from(90), U256::from(5000)).expect("Set rebate failed");
+        
+        let below = finalize_with_score(&context, &mut funding, U256::from(1), 80);
+        let at_threshold = finalize_with_score(&context, &mut funding, U256::from(2), 90);
+        assert_eq!(at_threshold, below);
+        
+        expect_error(funding.set_fee_rebate(U256::from(101), U256::from(5000)), "Invalid score threshold");
+        expect_error(funding.set_fee_rebate(U256::from(90), U256::from(10001)), "Invalid rebate share");
+    }
+
+    #[test]
+    fn test_validation_reserve_deducted_from_creator_payout() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let reserved = U256::from(1);
+        let plain = U256::from(2);
+        setup_project(&context, &mut funding, reserved);
+        setup_project(&context, &mut funding, plain);
+        
+        funding.set_validator_contract(context.validator()).expect("Set validator failed");
+        context.set_sender(context.creator());
+        funding.set_validation_reward_reserve(reserved, U256::from(200)).expect("Set reserve failed");
+        
+        let mut fees = Vec::new();
+        for project_id in [reserved, plain] {
+            context.set_sender(context.backer());
+            context.set_value(U256::from(10000000000000000000u64));
+            funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+                .expect("Funding failed");
+            context.set_value(U256::from(0));
+            context.set_sender(context.test_accounts[0]);
+            let escrow = funding.get_token_escrow(project_id, Address::ZERO);
+            let (_, _, _, before) = funding.platform_stats();
+            funding.finalize_successful_project(project_id).expect("Finalize failed");
+            let (_, _, _, after) = funding.platform_stats();
+            fees.push((escrow, after - before));
+        }
+        
+        // The reserve comes out of the creator's share; the platform fee is untouched
+        let (escrow, reserved_fee) = fees[0];
+        assert_eq!(reserved_fee, fees[1].1);
+        assert_eq!(
+            funding.get_validation_reward_reserve(reserved),
+            (U256::from(200), escrow * U256::from(200) / U256::from(10000))
+        );
+        assert_eq!(funding.get_validation_reward_reserve(plain), (U256::from(0), U256::from(0)));
+    }
+
+    #[test]
+    fn test_validation_reserve_skipped_without_validator() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        context.set_sender(context.creator());
+        funding.set_validation_reward_reserve(project_id, U256::from(200)).expect("Set reserve failed");
+        
+        context.set_sender(context.backer());
+        context.set_value(U256::from(10000000000000000000u64));
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        context.set_value(U256::from(0));
+        context.set_sender(context.test_accounts[0]);
+        funding.finalize_successful_project(project_id).expect("Finalize failed");
+        
+        // With no validator contract to pay, the creator keeps the reserve
+        assert_eq!(
+            funding.get_validation_reward_reserve(project_id),
+            (U256::from(200), U256::from(0))
+        );
+    }
+
+    #[test]
+    fn test_validation_reserve_returned_when_validation_not_finalized() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        
+        // The validator has no finalized result for this project, e.g. it was auto-approved
+        let validator_contract = context.validator();
+        funding.set_validator_contract(validator_contract).expect("Set validator failed");
+        funding.set_validation_reward_reserve(project_id, U256::from(200)).expect("Set reserve failed");
+        let selector = stylus_sdk::function_selector!("fundProjectValidationRewards", U256);
+        env::mock_call(validator_contract, selector, Err(Vec::new()));
+        
+        context.set_sender(context.backer());
+        context.set_value(U256::from(10000000000000000000u64));
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        context.set_value(U256::from(0));
+        context.set_sender(context.test_accounts[0]);
+        
+        // The reverted reserve payment no longer blocks the creator's payout
+        funding.finalize_successful_project(project_id).expect("Finalize failed");
+        assert_eq!(
+            funding.get_validation_reward_reserve(project_id),
+            (U256::from(200), U256::from(0))
+        );
+        assert_eq!(funding.get_token_escrow(project_id, Address::ZERO), U256::from(0));
+    }
+
+    #[test]
+    fn test_validation_reserve_bounds() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        
+        expect_error(
+            funding.set_validation_reward_reserve(project_id, U256::from(501)),
+            "Validation reserve too high"
+        );
+        
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        expect_error(
+            funding.set_validation_reward_reserve(project_id, U256::from(100)),
+            "Funding already started"
+        );
+    }
+
+    #[test]
+    fn test_finalization_dust_tracked_apart_from_fee() {
+        let context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        let escrow = funding.get_token_escrow(project_id, Address::ZERO);
+        funding.finalize_successful_project(project_id).expect("Finalize failed");
+        
+        // The fee rounds down; whatever neither share claimed waits for the sweep
+        let (_, _, _, treasury) = funding.platform_stats();
+        let (recipient, dust) = funding.get_accumulated_dust();
+        assert_eq!(recipient, Address::ZERO);
+        assert_eq!(treasury, escrow * U256::from(300) / U256::from(10000));
+        assert_eq!(
+            dust,
+            escrow - treasury - escrow * U256::from(9700) / U256::from(10000)
+        );
+        
+        if dust > U256::from(0) {
+            funding.sweep_dust().expect("Sweep failed");
+            let (_, _, _, swept_treasury) = funding.platform_stats();
+            assert_eq!(swept_treasury, treasury + dust);
+        } else {
+            expect_error(funding.sweep_dust(), "No dust to sweep");
+        }
+    }
+
+    #[test]
+    fn test_refunded_project_funding_reopens_for_relisting() {
+        let mut context = TestContext::new();
+        let mut funding = setup_funding(&context.test_accounts);
+        let project_id = U256::from(1);
+        setup_project(&context, &mut funding, project_id);
+        
+        context.set_sender(context.backer());
+        context.set_value(U256::from(2000000000000000000u64));
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding failed");
+        context.set_value(U256::from(0));
+        context.set_sender(context.test_accounts[0]);
+        
+        let new_deadline = U256::from(context.current_timestamp + 60 * 86400);
+        expect_error(
+            funding.reopen_project_funding(project_id, U256::from(8000), new_deadline),
+            "Project not refunded"
+        );
+        
+        context.advance_time(31 * 86400);
+        funding.process_refunds(project_id).expect("Refund processing failed");
+        funding.reopen_project_funding(project_id, U256::from(8000), new_deadline)
+            .expect("Reopen failed");
+        
+        let stats = funding.get_funding_stats(project_id).unwrap();
+        assert_eq!(stats.status, 0);
+        assert_eq!(stats.raised, U256::from(0));
+        assert_eq!(stats.backer_count, U256::from(0));
+        assert_eq!(stats.target, U256::from(8000));
+        assert_eq!(stats.deadline, new_deadline);
+        
+        // The same backer can fund the new round and is listed once
+        context.set_sender(context.backer());
+        context.set_value(U256::from(1000000000000000000u64));
+        funding.fund_project(project_id, "backer.afrocreate.eth".to_string())
+            .expect("Funding the relisted project failed");
+        assert_eq!(funding.get_funding_stats(project_id).unwrap().backer_count, U256::from(1));
+    }
+}
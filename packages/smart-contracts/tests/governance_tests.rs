@@ -0,0 +1,415 @@
+use alloy_primitives::{Address, U256};
+use afrocreate_contracts::{PlatformGovernance, types::{ProposalType, VotingRole}};
+use crate::test_utils::*;
+
+#[cfg(test)]
+mod governance_tests {
+    use super::*;
+
+    fn setup_governance(accounts: &[Address]) -> PlatformGovernance {
+        let mut governance = PlatformGovernance::default();
+        governance.initialize(
+            accounts[5], // platform contract
+            accounts[6], // validator contract
+            accounts[7], // funding contract
+        ).expect("Governance initialization failed");
+        governance
+    }
+
+    fn create_allocation(context: &mut TestContext, governance: &mut PlatformGovernance, amount: U256) -> U256 {
+        context.set_value(amount);
+        governance.fund_cultural_fund().expect("Funding cultural fund failed");
+        context.set_value(U256::from(0));
+        governance.allocate_cultural_fund(
+            context.creator(),
+            amount,
+            "Griot archive digitisation".to_string(),
+            "West Africa".to_string(),
+        ).expect("Allocation failed")
+    }
+
+    #[test]
+    fn test_reclaim_expired_allocation_restores_balance() {
+        let mut context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        let amount = U256::from(5000);
+        
+        let allocation_id = create_allocation(&mut context, &mut governance, amount);
+        let balance_after_allocation = governance.governance_stats().cultural_fund_balance;
+        
+        expect_error(governance.reclaim_expired_allocation(allocation_id), "Allocation not expired");
+        
+        context.advance_time(91 * 24 * 3600);
+        let reclaimed = governance.reclaim_expired_allocation(allocation_id).expect("Reclaim failed");
+        
+        assert_eq!(reclaimed, amount);
+        assert_eq!(governance.governance_stats().cultural_fund_balance, balance_after_allocation + amount);
+        assert!(governance.get_cultural_fund_allocation(allocation_id).unwrap().voided);
+        expect_error(governance.disburse_cultural_fund(allocation_id), "Allocation voided");
+    }
+
+    #[test]
+    fn test_disbursed_allocation_cannot_be_reclaimed() {
+        let mut context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        
+        let allocation_id = create_allocation(&mut context, &mut governance, U256::from(5000));
+        let recipient = context.creator();
+        approve_recipient(&mut context, &mut governance, recipient);
+        governance.disburse_cultural_fund(allocation_id).expect("Disbursement failed");
+        
+        context.advance_time(91 * 24 * 3600);
+        expect_error(governance.reclaim_expired_allocation(allocation_id), "Already disbursed");
+    }
+
+    #[test]
+    fn test_treasury_spend_requires_higher_quorum_than_text() {
+        let context = TestContext::new();
+        let governance = setup_governance(&context.test_accounts);
+        
+        let text_quorum = governance.quorum_required(ProposalType::Text as u8);
+        let treasury_quorum = governance.quorum_required(ProposalType::TreasurySpend as u8);
+        assert!(treasury_quorum > text_quorum);
+        
+        // A turnout between the two quorums passes a text proposal but not a treasury spend
+        let turnout = (text_quorum + treasury_quorum) / U256::from(2);
+        assert!(turnout >= text_quorum);
+        assert!(turnout < treasury_quorum);
+    }
+
+    #[test]
+    fn test_proposal_stores_type_and_rejects_short_treasury_turnout() {
+        let mut context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        let proposer = context.creator();
+        let voter = context.backer();
+        
+        // The voter's 150_000 weighted power clears the text quorum but not the treasury quorum
+        governance.update_voting_power(proposer, VotingRole::Creator as u8, U256::from(1_000_000_000_000_000_000u64))
+            .expect("Power update failed");
+        governance.update_voting_power(voter, VotingRole::Backer as u8, U256::from(300_000))
+            .expect("Power update failed");
+        context.advance_time(governance.min_holding_period().to::<u64>() + 1);
+        
+        context.set_sender(proposer);
+        context.set_value(U256::from(10_000_000_000_000_000u64)); // 0.01 ETH deposit
+        let proposal_id = governance.create_proposal(
+            "Fund regional archive".to_string(),
+            "Spend 10 ETH from the treasury".to_string(),
+            ProposalType::TreasurySpend as u8,
+            Vec::new(),
+        ).expect("Proposal creation failed");
+        context.set_value(U256::from(0));
+        
+        let proposal = governance.get_proposal(proposal_id).unwrap();
+        assert_eq!(proposal.proposal_type, ProposalType::TreasurySpend as u8);
+        
+        context.set_sender(voter);
+        governance.vote(proposal_id, true).expect("Vote failed");
+        let turnout = governance.calculate_eligible_voting_power(voter).unwrap();
+        assert!(turnout >= governance.quorum_required(ProposalType::Text as u8));
+        assert!(turnout < governance.quorum_required(ProposalType::TreasurySpend as u8));
+        
+        context.advance_time(9 * 24 * 3600);
+        expect_error(governance.execute_proposal(proposal_id), "Quorum not reached");
+    }
+
+    #[test]
+    fn test_invalid_proposal_type_rejected() {
+        let context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        
+        expect_error(
+            governance.set_proposal_type_parameters(9, U256::from(1000), U256::from(0)),
+            "Invalid proposal type"
+        );
+    }
+
+    #[test]
+    fn test_batch_voting_powers_match_individual_calls() {
+        let context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        
+        governance.update_voting_power(context.creator(), VotingRole::Creator as u8, U256::from(1000))
+            .expect("Power update failed");
+        governance.update_voting_power(context.backer(), VotingRole::Backer as u8, U256::from(1000))
+            .expect("Power update failed");
+        governance.update_voting_power(context.validator(), VotingRole::Validator as u8, U256::from(1000))
+            .expect("Power update failed");
+        
+        // The admin holds no power and must come back as zero in its slot
+        let users = vec![context.creator(), context.backer(), context.validator(), context.admin()];
+        let powers = governance.get_voting_powers(users.clone()).expect("Batch query failed");
+        
+        assert_eq!(powers, vec![U256::from(1000), U256::from(500), U256::from(1500), U256::from(0)]);
+        for (user, power) in users.iter().zip(powers.iter()) {
+            assert_eq!(*power, governance.calculate_voting_power(*user).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_batch_voting_powers_capped() {
+        let context = TestContext::new();
+        let governance = setup_governance(&context.test_accounts);
+        
+        let users = vec![context.creator(); 101];
+        expect_error(governance.get_voting_powers(users), "Too many users");
+    }
+
+    #[test]
+    fn test_freshly_acquired_power_excluded_from_voting() {
+        let context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        let voter = context.test_accounts[0];
+        
+        governance.update_voting_power(voter, VotingRole::Backer as u8, U256::from(1000))
+            .expect("Power update failed");
+        
+        // Acquired in this block: visible in total power but not usable for votes
+        assert_eq!(governance.calculate_voting_power(voter).unwrap(), U256::from(500));
+        assert_eq!(governance.calculate_eligible_voting_power(voter).unwrap(), U256::from(0));
+        expect_error(governance.vote(U256::from(1), true), "No voting power");
+    }
+
+    #[test]
+    fn test_aged_power_counts_toward_voting() {
+        let mut context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        let voter = context.test_accounts[0];
+        
+        governance.update_voting_power(voter, VotingRole::Validator as u8, U256::from(1000))
+            .expect("Power update failed");
+        context.advance_time(governance.min_holding_period().to::<u64>() + 1);
+        
+        let eligible = governance.calculate_eligible_voting_power(voter).unwrap();
+        assert_eq!(eligible, governance.calculate_voting_power(voter).unwrap());
+        assert_eq!(eligible, U256::from(1500));
+    }
+
+    #[test]
+    fn test_power_increase_keeps_aged_power_eligible() {
+        let mut context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        let voter = context.test_accounts[0];
+        
+        governance.update_voting_power(voter, VotingRole::Validator as u8, U256::from(1000))
+            .expect("Power update failed");
+        context.advance_time(governance.min_holding_period().to::<u64>() + 1);
+        
+        // Only the 400 increment waits; the aged 1000 keeps counting
+        governance.update_voting_power(voter, VotingRole::Validator as u8, U256::from(1400))
+            .expect("Power update failed");
+        assert_eq!(governance.calculate_eligible_voting_power(voter).unwrap(), U256::from(1500));
+        assert_eq!(governance.calculate_voting_power(voter).unwrap(), U256::from(2100));
+        
+        // A decrease comes out of the newest power first
+        governance.update_voting_power(voter, VotingRole::Validator as u8, U256::from(1200))
+            .expect("Power update failed");
+        assert_eq!(governance.calculate_eligible_voting_power(voter).unwrap(), U256::from(1500));
+        governance.update_voting_power(voter, VotingRole::Validator as u8, U256::from(800))
+            .expect("Power update failed");
+        assert_eq!(governance.calculate_eligible_voting_power(voter).unwrap(), U256::from(1200));
+        
+        context.advance_time(governance.min_holding_period().to::<u64>() + 1);
+        governance.update_voting_power(voter, VotingRole::Validator as u8, U256::from(1000))
+            .expect("Power update failed");
+        context.advance_time(governance.min_holding_period().to::<u64>() + 1);
+        assert_eq!(governance.calculate_eligible_voting_power(voter).unwrap(), U256::from(1500));
+    }
+
+    #[test]
+    fn test_voting_power_update_rejects_unknown_role() {
+        let context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        
+        expect_error(
+            governance.update_voting_power(context.backer(), 7, U256::from(1000)),
+            "Invalid voting role"
+        );
+    }
+
+    fn setup_proposal(
+        context: &mut TestContext,
+        governance: &mut PlatformGovernance,
+        quorum_bps: u64,
+    ) -> U256 {
+        let proposer = context.creator();
+        governance.update_platform_parameters(vec![
+            ("proposal_deposit".to_string(), U256::from(1000)),
+            ("min_holding_period".to_string(), U256::from(0)),
+            ("quorum_threshold".to_string(), U256::from(quorum_bps)),
+        ]).expect("Parameter update failed");
+        governance.update_voting_power(proposer, VotingRole::Creator as u8, U256::from(1_000_000_000_000_000_000u64))
+            .expect("Power update failed");
+        
+        context.set_sender(proposer);
+        context.set_value(U256::from(1000));
+        let proposal_id = governance.create_proposal(
+            "Extend griot archive".to_string(),
+            "Digitise a second collection".to_string(),
+            ProposalType::Text as u8,
+            Vec::new(),
+        ).expect("Proposal creation failed");
+        
+        context.set_sender(context.test_accounts[0]);
+        context.set_value(U256::from(0));
+        proposal_id
+    }
+
+    #[test]
+    fn test_quorum_reaching_proposal_refunds_deposit() {
+        let mut context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        
+        // Zero quorum: any turnout counts as a genuine proposal
+        let proposal_id = setup_proposal(&mut context, &mut governance, 0);
+        assert_eq!(governance.get_proposal(proposal_id).unwrap().deposit, U256::from(1000));
+        let treasury_before = governance.governance_stats().treasury_balance;
+        
+        context.advance_time(8 * 24 * 3600);
+        let refunded = governance.settle_proposal_deposit(proposal_id).expect("Settlement failed");
+        
+        assert!(refunded);
+        assert_eq!(governance.get_proposal(proposal_id).unwrap().deposit, U256::from(0));
+        assert_eq!(governance.governance_stats().treasury_balance, treasury_before);
+    }
+
+    #[test]
+    fn test_low_turnout_proposal_forfeits_deposit() {
+        let mut context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        
+        // 10% quorum with nobody voting
+        let proposal_id = setup_proposal(&mut context, &mut governance, 1000);
+        let deposit = governance.get_proposal(proposal_id).unwrap().deposit;
+        let treasury_before = governance.governance_stats().treasury_balance;
+        assert_eq!(deposit, U256::from(1000));
+        
+        expect_error(governance.settle_proposal_deposit(proposal_id), "Voting period not ended");
+        
+        context.advance_time(8 * 24 * 3600);
+        let refunded = governance.settle_proposal_deposit(proposal_id).expect("Settlement failed");
+        
+        assert!(!refunded);
+        assert_eq!(governance.governance_stats().treasury_balance, treasury_before + deposit);
+        expect_error(governance.settle_proposal_deposit(proposal_id), "No deposit to settle");
+    }
+
+    #[test]
+    fn test_regional_coordinator_claims_earmarked_inflows() {
+        let mut context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        let region = "West Africa".to_string();
+        let coordinator = context.admin();
+        
+        governance.set_regional_coordinator(region.clone(), coordinator).expect("Set coordinator failed");
+        governance.set_regional_earmark(region.clone(), U256::from(2000)).expect("Set earmark failed");
+        context.set_value(U256::from(10000));
+        governance.fund_cultural_fund().expect("Funding cultural fund failed");
+        context.set_value(U256::from(0));
+        
+        // 20% of the inflow is earmarked; the rest joins the general fund
+        let (_, share_bps, earmarked) = governance.get_regional_earmark(region.clone());
+        let general = governance.governance_stats().cultural_fund_balance;
+        assert_eq!(share_bps, U256::from(2000));
+        assert_eq!(earmarked, U256::from(2000));
+        assert_eq!(general, U256::from(8000));
+        
+        // Only the coordinator, not the owner who assigned it, may claim
+        expect_error(governance.claim_regional_earmark(region.clone()), "Only regional coordinator");
+        
+        // The coordinator is paid from the treasury, so they must be on the recipient allowlist
+        context.set_sender(coordinator);
+        expect_error(governance.claim_regional_earmark(region.clone()), "Recipient not approved");
+        context.set_sender(context.test_accounts[0]);
+        approve_recipient(&mut context, &mut governance, coordinator);
+        
+        context.set_sender(coordinator);
+        let claimed = governance.claim_regional_earmark(region.clone()).expect("Claim failed");
+        assert_eq!(claimed, earmarked);
+        assert_eq!(governance.get_regional_earmark(region).2, U256::from(0));
+        assert_eq!(governance.governance_stats().cultural_fund_balance, general);
+    }
+
+    #[test]
+    fn test_regional_earmark_requires_coordinator() {
+        let context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        let region = "East Africa".to_string();
+        
+        // Without a coordinator nothing is earmarked and nobody can claim
+        governance.set_regional_earmark(region.clone(), U256::from(2000)).expect("Set earmark failed");
+        governance.fund_cultural_fund().expect("Funding cultural fund failed");
+        assert_eq!(governance.get_regional_earmark(region.clone()).2, U256::from(0));
+        expect_error(governance.claim_regional_earmark(region), "Only regional coordinator");
+        
+        expect_error(
+            governance.set_regional_earmark("West Africa".to_string(), U256::from(9000)),
+            "Regional earmarks exceed 100%"
+        );
+    }
+
+    fn security_guardian(context: &TestContext) -> Address {
+        context.test_accounts[8]
+    }
+
+    fn approve_recipient(context: &mut TestContext, governance: &mut PlatformGovernance, recipient: Address) {
+        let guardian = security_guardian(context);
+        governance.set_security_guardian(guardian, true).expect("Set guardian failed");
+        context.set_sender(guardian);
+        governance.add_approved_recipient(recipient).expect("Approve recipient failed");
+        context.set_sender(context.test_accounts[0]);
+    }
+
+    #[test]
+    fn test_disbursement_to_unapproved_recipient_reverts() {
+        let mut context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        
+        let allocation_id = create_allocation(&mut context, &mut governance, U256::from(5000));
+        expect_error(governance.disburse_cultural_fund(allocation_id), "Recipient not approved");
+        
+        let recipient = context.creator();
+        approve_recipient(&mut context, &mut governance, recipient);
+        assert!(governance.is_approved_recipient(recipient));
+        governance.disburse_cultural_fund(allocation_id).expect("Disbursement failed");
+        assert!(governance.get_cultural_fund_allocation(allocation_id).unwrap().disbursed);
+        
+        // A recipient removed from the allowlist can no longer be paid
+        context.set_sender(security_guardian(&context));
+        governance.remove_approved_recipient(recipient).expect("Remove recipient failed");
+        assert!(!governance.is_approved_recipient(recipient));
+    }
+
+    #[test]
+    fn test_treasury_withdrawal_requires_approved_recipient() {
+        let mut context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        let vendor = context.test_accounts[3];
+        
+        expect_error(governance.withdraw_to(vendor, U256::from(1)), "Recipient not approved");
+        
+        // Once approved the allowlist passes and only the treasury balance limits the payout
+        approve_recipient(&mut context, &mut governance, vendor);
+        expect_error(governance.withdraw_to(vendor, U256::from(1)), "Insufficient treasury balance");
+    }
+
+    #[test]
+    fn test_allowlist_managed_only_by_security_guardian() {
+        let mut context = TestContext::new();
+        let mut governance = setup_governance(&context.test_accounts);
+        let vendor = context.test_accounts[3];
+        
+        // Owning governance is not enough to edit the allowlist
+        expect_error(governance.add_approved_recipient(vendor), "Only security guardian");
+        expect_error(governance.remove_approved_recipient(vendor), "Only security guardian");
+        
+        approve_recipient(&mut context, &mut governance, vendor);
+        assert!(governance.is_security_guardian(security_guardian(&context)));
+        assert!(!governance.is_security_guardian(context.test_accounts[0]));
+        assert!(governance.is_approved_recipient(vendor));
+        
+        context.set_sender(security_guardian(&context));
+        expect_error(governance.add_approved_recipient(Address::ZERO), "Invalid recipient");
+    }
+}
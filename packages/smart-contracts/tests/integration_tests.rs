@@ -1,5 +1,5 @@
 use alloy_primitives::{Address, U256};
-use afrocreate_contracts::{AfroCreatePlatform, types::*};
+use afrocreate_contracts::{AfroCreatePlatform, CulturalValidator, RevenueDistributor, types::*};
 use crate::test_utils::*;
 
 #[cfg(test)]
@@ -483,4 +483,152 @@ mod integration_tests {
             assert_eq!(project.funding_raised, U256::from((i + 1) as u64 * 1000));
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_funding_reconciliation_reports_gap() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        // Platform records less than ProjectFunding actually holds
+        context.platform.update_project_funding(project_id, U256::from(3000))
+            .expect("Funding update failed");
+        context.platform.report_funding_ledger(project_id, U256::from(5000), U256::from(5000))
+            .expect("Ledger report failed");
+        
+        let reconciliation = context.platform.reconcile_project_funding(project_id)
+            .expect("Reconciliation failed");
+        assert_eq!(reconciliation.platform_raised, U256::from(3000));
+        assert_eq!(reconciliation.ledger_raised, U256::from(5000));
+        assert_eq!(reconciliation.discrepancy, U256::from(2000));
+        assert!(!reconciliation.platform_ahead);
+        
+        let fixed = context.platform.resolve_funding_discrepancy(project_id)
+            .expect("Resolving discrepancy failed");
+        assert_eq!(fixed, U256::from(2000));
+        
+        let reconciliation = context.platform.reconcile_project_funding(project_id).unwrap();
+        assert_eq!(reconciliation.discrepancy, U256::from(0));
+        expect_error(
+            context.platform.resolve_funding_discrepancy(project_id),
+            "Funding already in sync"
+        );
+    }
+
+    #[test]
+    fn test_unreported_ledger_never_overwrites_platform_funding() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        context.platform.update_project_funding(project_id, U256::from(3000))
+            .expect("Funding update failed");
+        
+        // No report yet: no discrepancy, and nothing to resolve against
+        let reconciliation = context.platform.reconcile_project_funding(project_id).unwrap();
+        assert!(!reconciliation.ledger_reported);
+        assert_eq!(reconciliation.discrepancy, U256::from(0));
+        expect_error(
+            context.platform.resolve_funding_discrepancy(project_id),
+            "Funding ledger not reported"
+        );
+        assert_eq!(context.platform.get_project_info(project_id).unwrap().funding_raised, U256::from(3000));
+        
+        // A reported zero is a real report and is reconciled like any other
+        context.platform.report_funding_ledger(project_id, U256::from(0), U256::from(0))
+            .expect("Ledger report failed");
+        let reconciliation = context.platform.reconcile_project_funding(project_id).unwrap();
+        assert!(reconciliation.ledger_reported);
+        assert_eq!(reconciliation.discrepancy, U256::from(3000));
+        assert!(reconciliation.platform_ahead);
+    }
+
+    // Stands in for the deployed CulturalValidator's address
+    fn validator_contract_address(accounts: &[Address]) -> Address {
+        accounts[8]
+    }
+
+    fn setup_appeal_distributor(accounts: &[Address], project_id: U256) -> RevenueDistributor {
+        let mut distributor = RevenueDistributor::default();
+        distributor.initialize(accounts[5], accounts[6], accounts[7])
+            .expect("Distributor initialization failed");
+        distributor.set_validator_contract(validator_contract_address(accounts))
+            .expect("Set validator contract failed");
+        distributor.add_revenue_source(
+            project_id, "soundcloud".to_string(), U256::from(1_000_000_000_000_000_000u64), "ipfs://proof".to_string()
+        ).expect("Adding revenue failed");
+        distributor
+    }
+
+    fn finalize_with_quorum(context: &mut TestContext, validator: &mut CulturalValidator, project_id: U256) {
+        let (stake, fee) = validator.registration_requirements();
+        let members = [context.validator(), context.admin(), context.backer()];
+        for (i, member) in members.iter().enumerate() {
+            context.set_sender(*member);
+            context.set_value(stake + fee);
+            validator.register_validator(
+                format!("validator-{}", i),
+                vec!["West Africa".to_string()],
+                "ipfs://credentials".to_string(),
+            ).expect("Registration failed");
+            context.set_value(U256::from(0));
+            validator.submit_validation(
+                project_id, U256::from(90), "ipfs://feedback".to_string(), Vec::new()
+            ).expect("Submission failed");
+        }
+        // The third submission reaches quorum and finalizes the project
+        context.set_sender(context.test_accounts[0]);
+    }
+
+    #[test]
+    fn test_open_appeal_freezes_revenue_distribution() {
+        let mut context = TestContext::new();
+        let project_id = U256::from(1);
+        let validator_contract = validator_contract_address(&context.test_accounts);
+        let mut validator = CulturalValidator::default();
+        validator.initialize(context.test_accounts[5]).expect("Validator initialization failed");
+        let mut distributor = setup_appeal_distributor(&context.test_accounts, project_id);
+        
+        finalize_with_quorum(&mut context, &mut validator, project_id);
+        context.set_sender(context.creator());
+        let appeal_id = validator.challenge_validation(
+            project_id, "Misattributed heritage".to_string(), "ipfs://evidence".to_string()
+        ).expect("Appeal failed");
+        assert!(validator.has_open_appeal(project_id));
+        
+        // The validator contract reports the open appeal; an unrelated account cannot
+        expect_error(distributor.report_appeal_status(project_id, true), "Not validator contract");
+        context.set_sender(validator_contract);
+        distributor.report_appeal_status(project_id, validator.has_open_appeal(project_id))
+            .expect("Report failed");
+        
+        context.set_sender(context.test_accounts[0]);
+        assert!(distributor.is_revenue_frozen(project_id));
+        expect_error(distributor.distribute_revenue(project_id), "Revenue frozen during appeal");
+        expect_error(distributor.claim_creator_revenue(project_id), "Revenue frozen during appeal");
+        
+        // Resolving the appeal lets distribution resume
+        validator.resolve_appeal(appeal_id, false, false, "Validation stands".to_string())
+            .expect("Resolving appeal failed");
+        assert!(!validator.has_open_appeal(project_id));
+        context.set_sender(validator_contract);
+        distributor.report_appeal_status(project_id, validator.has_open_appeal(project_id))
+            .expect("Report failed");
+        
+        context.set_sender(context.test_accounts[0]);
+        assert!(!distributor.is_revenue_frozen(project_id));
+        distributor.distribute_revenue(project_id).expect("Distribution after resolution failed");
+    }
+
+    #[test]
+    fn test_appeal_freeze_can_be_disabled_per_project() {
+        let context = TestContext::new();
+        let project_id = U256::from(1);
+        let mut distributor = setup_appeal_distributor(&context.test_accounts, project_id);
+        
+        distributor.report_appeal_status(project_id, true).expect("Report failed");
+        distributor.set_appeal_freeze(project_id, false).expect("Disable freeze failed");
+        
+        assert!(!distributor.is_revenue_frozen(project_id));
+        distributor.distribute_revenue(project_id).expect("Distribution failed");
+    }
+}
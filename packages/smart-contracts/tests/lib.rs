@@ -5,5 +5,12 @@ mod validation_tests;
 mod security_tests;
 mod gas_optimization_tests;
 mod integration_tests;
+mod revenue_tests;
+mod cultural_validator_tests;
+mod governance_tests;
+mod funding_tests;
+mod math_tests;
+mod nft_tests;
+mod factory_tests;
 
 pub mod test_utils;
\ No newline at end of file
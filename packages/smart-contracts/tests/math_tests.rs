@@ -0,0 +1,86 @@
+use alloy_primitives::U256;
+use afrocreate_contracts::types::math::{mul_bps, split_bps, split_bps_with_dust, split_fee, BPS_DENOMINATOR};
+
+#[cfg(test)]
+mod math_tests {
+    use super::*;
+
+    // Deterministic pseudo-random sequence so fuzzed cases are reproducible
+    fn next_random(seed: &mut u64) -> u64 {
+        *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        *seed >> 11
+    }
+
+    fn sum(parts: &[U256]) -> U256 {
+        parts.iter().fold(U256::from(0), |acc, part| acc + *part)
+    }
+
+    #[test]
+    fn test_mul_bps_rounds_down() {
+        assert_eq!(mul_bps(U256::from(999), U256::from(250)), U256::from(24));
+        assert_eq!(mul_bps(U256::from(10000), U256::from(BPS_DENOMINATOR)), U256::from(10000));
+        assert_eq!(mul_bps(U256::from(1), U256::from(9999)), U256::from(0));
+    }
+
+    #[test]
+    fn test_split_assigns_dust_to_remainder_bucket() {
+        let parts = split_bps(
+            U256::from(101),
+            &[U256::from(250), U256::from(7000), U256::from(2750)],
+            2,
+        );
+        
+        assert_eq!(parts[0], U256::from(2));
+        assert_eq!(parts[1], U256::from(70));
+        assert_eq!(parts[2], U256::from(29)); // 27 plus 2 wei of dust
+        assert_eq!(sum(&parts), U256::from(101));
+    }
+
+    #[test]
+    fn test_split_components_resum_to_total_fuzzed() {
+        let mut seed = 0x5eed_u64;
+        
+        for _ in 0..1000 {
+            let amount = U256::from(next_random(&mut seed));
+            let fee_bps = U256::from(next_random(&mut seed) % 1001);
+            let creator_bps = U256::from(next_random(&mut seed) % 9001);
+            let community_bps = U256::from(BPS_DENOMINATOR)
+                .saturating_sub(fee_bps)
+                .saturating_sub(creator_bps);
+            
+            let parts = split_bps(amount, &[fee_bps, creator_bps, community_bps], 2);
+            assert_eq!(sum(&parts), amount);
+            
+            let (fee, net) = split_fee(amount, fee_bps);
+            assert_eq!(fee + net, amount);
+        }
+    }
+
+    #[test]
+    fn test_over_allocated_shares_never_exceed_total_fuzzed() {
+        let mut seed = 0xbeef_u64;
+        
+        for _ in 0..1000 {
+            let amount = U256::from(next_random(&mut seed));
+            let shares: Vec<U256> = (0..4)
+                .map(|_| U256::from(next_random(&mut seed) % 8000))
+                .collect();
+            
+            let parts = split_bps(amount, &shares, 0);
+            assert_eq!(parts.len(), shares.len());
+            assert_eq!(sum(&parts), amount);
+        }
+    }
+
+    #[test]
+    fn test_split_with_dust_returns_remainder_separately() {
+        let (parts, dust) = split_bps_with_dust(
+            U256::from(101),
+            &[U256::from(250), U256::from(7000), U256::from(2750)],
+        );
+        
+        assert_eq!(parts, vec![U256::from(2), U256::from(70), U256::from(27)]);
+        assert_eq!(dust, U256::from(2));
+        assert_eq!(sum(&parts) + dust, U256::from(101));
+    }
+}
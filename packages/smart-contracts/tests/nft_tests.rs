@@ -0,0 +1,264 @@
+use alloy_primitives::{Address, FixedBytes, U256};
+use afrocreate_contracts::{CulturalBadgeNFT, RevenueShareNFT, types::interfaces::*};
+use crate::test_utils::*;
+
+#[cfg(test)]
+mod nft_tests {
+    use super::*;
+
+    fn setup_revenue_nft(accounts: &[Address]) -> RevenueShareNFT {
+        let mut nft = RevenueShareNFT::default();
+        nft.initialize(
+            "AfroCreate Revenue Share".to_string(),
+            "AFRS".to_string(),
+            accounts[5], // platform contract
+            accounts[6], // funding contract
+            "ipfs://afrocreate".to_string(),
+        ).expect("NFT initialization failed");
+        nft
+    }
+
+    fn setup_badge_nft(accounts: &[Address]) -> CulturalBadgeNFT {
+        let mut badges = CulturalBadgeNFT::default();
+        badges.initialize(
+            "AfroCreate Cultural Badge".to_string(),
+            "AFCB".to_string(),
+            "ipfs://badges".to_string(),
+            accounts[7], // cultural validator
+            accounts[5], // platform contract
+        ).expect("Badge initialization failed");
+        badges
+    }
+
+    #[test]
+    fn test_revenue_nft_supports_erc721_interfaces() {
+        let context = TestContext::new();
+        let nft = setup_revenue_nft(&context.test_accounts);
+        
+        assert!(nft.supports_interface(ERC165_INTERFACE_ID));
+        assert!(nft.supports_interface(ERC721_INTERFACE_ID));
+        assert!(nft.supports_interface(ERC721_METADATA_INTERFACE_ID));
+        assert!(!nft.supports_interface(FixedBytes([0xff, 0xff, 0xff, 0xff])));
+    }
+
+    #[test]
+    fn test_badge_nft_supports_erc721_interfaces() {
+        let context = TestContext::new();
+        let badges = setup_badge_nft(&context.test_accounts);
+        
+        assert!(badges.supports_interface(ERC165_INTERFACE_ID));
+        assert!(badges.supports_interface(ERC721_INTERFACE_ID));
+        assert!(badges.supports_interface(ERC721_METADATA_INTERFACE_ID));
+        assert!(!badges.supports_interface(FixedBytes([0x12, 0x34, 0x56, 0x78])));
+    }
+
+    #[test]
+    fn test_unimplemented_extensions_not_advertised() {
+        let context = TestContext::new();
+        let nft = setup_revenue_nft(&context.test_accounts);
+        
+        assert!(!nft.supports_interface(ERC721_ENUMERABLE_INTERFACE_ID));
+        assert!(!nft.supports_interface(ERC2981_INTERFACE_ID));
+    }
+
+    fn setup_two_project_catalog(context: &TestContext, nft: &mut RevenueShareNFT) -> U256 {
+        // Project tokens so each project already has direct holders
+        for project_id in [1u64, 2] {
+            nft.set_project_creator(U256::from(project_id), context.creator()).expect("Set creator failed");
+            nft.mint_revenue_nft(
+                context.test_accounts[8], U256::from(project_id), U256::from(1000), U256::from(5000), String::new()
+            ).expect("Mint failed");
+        }
+        
+        let catalog_id = nft.create_catalog(
+            context.creator(),
+            vec![U256::from(1), U256::from(2)],
+            vec![U256::from(6000), U256::from(4000)],
+        ).expect("Catalog creation failed");
+        
+        nft.mint_catalog_share(
+            context.backer(), catalog_id, U256::from(1000), U256::from(1000), String::new()
+        ).expect("Catalog mint failed")
+    }
+
+    #[test]
+    fn test_catalog_share_earns_from_every_basket_project() {
+        let context = TestContext::new();
+        let mut nft = setup_revenue_nft(&context.test_accounts);
+        let one_eth = U256::from(1_000_000_000_000_000_000u64);
+        let token_id = setup_two_project_catalog(&context, &mut nft);
+        
+        assert_eq!(nft.calculate_claimable_revenue(token_id).unwrap(), U256::from(0));
+        
+        // 10% share of the 60% weight on project 1
+        nft.batch_distribute_revenue(U256::from(1), one_eth).expect("Distribution failed");
+        let after_first = nft.calculate_claimable_revenue(token_id).unwrap();
+        assert_eq!(after_first, one_eth * U256::from(6000) / U256::from(10000) * U256::from(1000) / U256::from(10000));
+        
+        // 10% share of the 40% weight on project 2
+        nft.batch_distribute_revenue(U256::from(2), one_eth).expect("Distribution failed");
+        let after_second = nft.calculate_claimable_revenue(token_id).unwrap();
+        assert_eq!(after_second - after_first, one_eth * U256::from(4000) / U256::from(10000) * U256::from(1000) / U256::from(10000));
+    }
+
+    #[test]
+    fn test_catalog_weights_must_total_full_basket() {
+        let context = TestContext::new();
+        let mut nft = setup_revenue_nft(&context.test_accounts);
+        for project_id in [1u64, 2] {
+            nft.set_project_creator(U256::from(project_id), context.creator()).expect("Set creator failed");
+        }
+        
+        expect_error(
+            nft.create_catalog(
+                context.creator(),
+                vec![U256::from(1), U256::from(2)],
+                vec![U256::from(6000), U256::from(3000)],
+            ),
+            "Catalog weights must total 10000"
+        );
+        expect_error(
+            nft.mint_catalog_share(context.backer(), U256::from(1), U256::from(1000), U256::from(1000), String::new()),
+            "Catalog not found"
+        );
+    }
+
+    #[test]
+    fn test_catalog_needs_the_creators_own_projects() {
+        let context = TestContext::new();
+        let mut nft = setup_revenue_nft(&context.test_accounts);
+        nft.set_project_creator(U256::from(1), context.creator()).expect("Set creator failed");
+        nft.set_project_creator(U256::from(2), context.backer()).expect("Set creator failed");
+        
+        expect_error(
+            nft.create_catalog(
+                context.creator(),
+                vec![U256::from(1), U256::from(2)],
+                vec![U256::from(6000), U256::from(4000)],
+            ),
+            "Creator does not own project"
+        );
+    }
+
+    #[test]
+    fn test_catalog_share_counts_against_project_caps() {
+        let context = TestContext::new();
+        let mut nft = setup_revenue_nft(&context.test_accounts);
+        let token_id = setup_two_project_catalog(&context, &mut nft);
+        let catalog_id = nft.get_token_catalog(token_id);
+        
+        // 5000 direct plus 10% of the 60% / 40% weights
+        assert_eq!(nft.get_project_allocated_share(U256::from(1)), U256::from(5600));
+        assert_eq!(nft.get_project_allocated_share(U256::from(2)), U256::from(5400));
+        
+        // A full catalog share would push project 1 past 100%
+        expect_error(
+            nft.mint_catalog_share(context.backer(), catalog_id, U256::from(1000), U256::from(10000), String::new()),
+            "Project revenue share exhausted"
+        );
+        expect_error(
+            nft.mint_revenue_nft(context.backer(), U256::from(1), U256::from(1000), U256::from(4401), String::new()),
+            "Project revenue share exhausted"
+        );
+    }
+
+    #[test]
+    fn test_catalog_lists_weighted_basket() {
+        let context = TestContext::new();
+        let mut nft = setup_revenue_nft(&context.test_accounts);
+        let token_id = setup_two_project_catalog(&context, &mut nft);
+        
+        let catalog_id = nft.get_token_catalog(token_id);
+        let (creator, basket) = nft.get_catalog(catalog_id).expect("Catalog missing");
+        assert_eq!(creator, context.creator());
+        assert_eq!(basket, vec![(U256::from(1), U256::from(6000)), (U256::from(2), U256::from(4000))]);
+    }
+
+    #[test]
+    fn test_breakeven_revenue_for_known_share() {
+        let context = TestContext::new();
+        let mut nft = setup_revenue_nft(&context.test_accounts);
+        
+        // 1000 wei for a 5% share breaks even at 20,000 wei of project revenue
+        let token_id = nft.mint_revenue_nft(
+            context.backer(), U256::from(1), U256::from(1000), U256::from(500), String::new()
+        ).expect("Mint failed");
+        assert_eq!(nft.get_breakeven_revenue(token_id).expect("Breakeven failed"), U256::from(20000));
+        
+        let zero_share = nft.mint_revenue_nft(
+            context.backer(), U256::from(1), U256::from(1000), U256::from(0), String::new()
+        ).expect("Mint failed");
+        assert_eq!(nft.get_breakeven_revenue(zero_share).expect("Breakeven failed"), U256::MAX);
+        
+        expect_error(nft.get_breakeven_revenue(U256::from(999)), "Token does not exist");
+    }
+
+    #[test]
+    fn test_transfer_fee_feeds_project_revenue() {
+        let mut context = TestContext::new();
+        let mut nft = setup_revenue_nft(&context.test_accounts);
+        let project_id = U256::from(1);
+        let seller = context.test_accounts[0];
+        
+        let token_id = nft.mint_revenue_nft(
+            seller, project_id, U256::from(1000), U256::from(500), String::new()
+        ).expect("Mint failed");
+        nft.remove_transfer_restriction(token_id).expect("Unrestrict failed");
+        nft.set_transfer_fee_bps(U256::from(500)).expect("Set transfer fee failed");
+        assert_eq!(nft.get_transfer_fee_bps(), U256::from(500));
+        
+        // 5% of a 10,000 wei sale goes to the project pool, the rest to the seller
+        let sale_price = U256::from(10000);
+        let (fee, proceeds) = nft.quote_transfer_fee(token_id, sale_price).expect("Quote failed");
+        assert_eq!(fee, U256::from(500));
+        assert_eq!(proceeds, U256::from(9500));
+        
+        let revenue_before = nft.get_project_revenue_stats(project_id).total_revenue_generated;
+        
+        // The seller approves the buyer, who settles the sale by attaching the price
+        let buyer = context.backer();
+        nft.approve(buyer, token_id).expect("Approve failed");
+        context.set_sender(buyer);
+        context.set_value(sale_price);
+        nft.transfer_from(seller, buyer, token_id).expect("Transfer failed");
+        context.set_value(U256::from(0));
+        assert_eq!(nft.owner_of(token_id).unwrap(), buyer);
+        
+        let revenue_after = nft.get_project_revenue_stats(project_id).total_revenue_generated;
+        assert_eq!(revenue_after, revenue_before + fee);
+        assert_eq!(nft.calculate_claimable_revenue(token_id).unwrap(), fee * U256::from(500) / U256::from(10000));
+    }
+
+    #[test]
+    fn test_transfer_fee_capped_and_skips_catalog_shares() {
+        let context = TestContext::new();
+        let mut nft = setup_revenue_nft(&context.test_accounts);
+        
+        expect_error(nft.set_transfer_fee_bps(U256::from(1001)), "Transfer fee too high");
+        nft.set_transfer_fee_bps(U256::from(1000)).expect("Set transfer fee failed");
+        
+        let share = setup_two_project_catalog(&context, &mut nft);
+        let (fee, proceeds) = nft.quote_transfer_fee(share, U256::from(10000)).expect("Quote failed");
+        assert_eq!(fee, U256::from(0));
+        assert_eq!(proceeds, U256::from(10000));
+        
+        expect_error(nft.quote_transfer_fee(U256::from(999), U256::from(1)), "Token does not exist");
+    }
+
+    #[test]
+    fn test_burned_revenue_nft_loses_its_share() {
+        let context = TestContext::new();
+        let mut nft = setup_revenue_nft(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        let token_id = nft.mint_revenue_nft(
+            context.backer(), project_id, U256::from(1000), U256::from(500), String::new()
+        ).expect("Mint failed");
+        nft.burn_revenue_nft(token_id).expect("Burn failed");
+        
+        assert!(nft.get_project_holders(project_id).is_empty());
+        assert_eq!(nft.balance_of(context.backer()).unwrap(), U256::from(0));
+        expect_error(nft.owner_of(token_id), "Token does not exist");
+        expect_error(nft.burn_revenue_nft(token_id), "Token does not exist");
+    }
+}
@@ -1,5 +1,6 @@
 use alloy_primitives::{Address, U256};
 use afrocreate_contracts::{AfroCreatePlatform, types::*};
+use alloy_sol_types::SolValue;
 use crate::test_utils::*;
 
 #[cfg(test)]
@@ -565,4 +566,686 @@ mod project_tests {
         assert_eq!(perfect_score_project.validation_score, U256::from(100));
         assert_eq!(perfect_score_project.validation_status, 1); // Approved
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_co_creator_invite_and_accept() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        let collaborator = context.test_accounts[5];
+        
+        context.platform.invite_co_creator(project_id, collaborator, U256::from(2500))
+            .expect("Invitation failed");
+        assert_eq!(context.platform.get_pending_co_creator_invite(project_id, collaborator), U256::from(2500));
+        assert_eq!(context.platform.get_co_creator_share(project_id, collaborator), U256::from(0));
+        
+        context.set_sender(collaborator);
+        context.platform.accept_co_creator_invite(project_id).expect("Accept failed");
+        context.set_sender(context.test_accounts[0]);
+        
+        assert_eq!(context.platform.get_co_creator_share(project_id, collaborator), U256::from(2500));
+        assert_eq!(context.platform.get_project_co_creators(project_id), vec![collaborator]);
+    }
+
+    #[test]
+    fn test_co_creator_invite_decline_leaves_no_share() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        let collaborator = context.test_accounts[5];
+        
+        context.platform.invite_co_creator(project_id, collaborator, U256::from(2500))
+            .expect("Invitation failed");
+        context.set_sender(collaborator);
+        context.platform.decline_co_creator_invite(project_id).expect("Decline failed");
+        context.set_sender(context.test_accounts[0]);
+        
+        assert_eq!(context.platform.get_co_creator_share(project_id, collaborator), U256::from(0));
+        assert_eq!(context.platform.get_pending_co_creator_invite(project_id, collaborator), U256::from(0));
+        assert!(context.platform.get_project_co_creators(project_id).is_empty());
+    }
+
+    #[test]
+    fn test_co_creator_accept_without_invite_fails() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        expect_error(
+            context.platform.accept_co_creator_invite(project_id),
+            "No pending invitation"
+        );
+    }
+
+    #[test]
+    fn test_relist_failed_project_preserves_validation() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        context.platform.set_project_validation(project_id, U256::from(85), true)
+            .expect("Validation failed");
+        context.platform.update_project_funding(project_id, U256::from(4000))
+            .expect("Funding update failed");
+        
+        context.advance_time(31 * 86400);
+        context.platform.mark_project_failed(project_id).expect("Mark failed failed");
+        context.platform.mark_refunds_processed(project_id).expect("Mark refunds failed");
+        
+        let new_deadline = U256::from(context.current_timestamp + 30 * 86400);
+        context.platform.relist_project(project_id, new_deadline, U256::from(8000))
+            .expect("Relist failed");
+        
+        let project = context.platform.get_project_info(project_id).expect("Get project failed");
+        assert_eq!(project.status, 0); // Active again
+        assert_eq!(project.funding_raised, U256::from(0));
+        assert_eq!(project.funding_target, U256::from(8000));
+        assert_eq!(project.deadline, new_deadline);
+        assert_eq!(project.validation_status, 1); // Approved carried over
+        assert_eq!(project.validation_score, U256::from(85));
+    }
+
+    #[test]
+    fn test_relist_rejected_while_refunds_pending() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        context.platform.update_project_funding(project_id, U256::from(4000))
+            .expect("Funding update failed");
+        context.advance_time(31 * 86400);
+        context.platform.mark_project_failed(project_id).expect("Mark failed failed");
+        
+        let new_deadline = U256::from(context.current_timestamp + 30 * 86400);
+        expect_error(
+            context.platform.relist_project(project_id, new_deadline, U256::from(8000)),
+            "Refunds pending"
+        );
+    }
+
+    #[test]
+    fn test_refund_completion_reported_before_failure_is_kept() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        let funding_contract = context.test_accounts[5];
+        context.platform.set_contract_addresses(
+            funding_contract,
+            context.test_accounts[6],
+            context.test_accounts[7],
+            context.test_accounts[8],
+        ).expect("Set contract addresses failed");
+        context.platform.update_project_funding(project_id, U256::from(4000))
+            .expect("Funding update failed");
+        context.advance_time(31 * 86400);
+        
+        // The funding contract finishes its refund sweep first
+        context.set_sender(funding_contract);
+        context.platform.mark_refunds_processed(project_id).expect("Mark refunds failed");
+        context.set_sender(context.test_accounts[0]);
+        context.platform.mark_project_failed(project_id).expect("Mark failed failed");
+        
+        let new_deadline = U256::from(context.current_timestamp + 30 * 86400);
+        context.platform.relist_project(project_id, new_deadline, U256::from(8000))
+            .expect("Relist failed");
+        
+        context.set_sender(context.backer());
+        expect_error(context.platform.mark_refunds_processed(project_id), "Not authorized");
+    }
+
+    #[test]
+    fn test_relist_respects_active_project_limit() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        context.advance_time(31 * 86400);
+        context.platform.mark_project_failed(project_id).expect("Mark failed failed");
+        
+        // Both slots are taken while the failed project waits to be relisted
+        context.platform.set_max_active_projects(U256::from(2), U256::from(5))
+            .expect("Set active project limit failed");
+        context.create_test_project().expect("Second project failed");
+        context.create_test_project().expect("Third project failed");
+        
+        let new_deadline = U256::from(context.current_timestamp + 30 * 86400);
+        expect_error(
+            context.platform.relist_project(project_id, new_deadline, U256::from(8000)),
+            "Too many active projects"
+        );
+    }
+
+    #[test]
+    fn test_film_project_requires_category_minimum() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        
+        context.platform.set_category_min_funding("Film & Video".to_string(), U256::from(50000))
+            .expect("Set category minimum failed");
+        
+        // Above the global minimum but below the film minimum
+        expect_error(
+            context.platform.create_project(
+                "Short Film".to_string(),
+                "A documentary on Lagos street markets".to_string(),
+                "Film & Video".to_string(),
+                U256::from(10000),
+                U256::from(30),
+                "QmFilmHash".to_string()
+            ),
+            "Funding target too low"
+        );
+        
+        let film_project = context.platform.create_project(
+            "Short Film".to_string(),
+            "A documentary on Lagos street markets".to_string(),
+            "Film & Video".to_string(),
+            U256::from(50000),
+            U256::from(30),
+            "QmFilmHash".to_string()
+        );
+        assert!(film_project.is_ok(), "Film project at category minimum should work");
+    }
+
+    #[test]
+    fn test_literature_project_uses_global_minimum() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        
+        context.platform.set_category_min_funding("Film & Video".to_string(), U256::from(50000))
+            .expect("Set category minimum failed");
+        assert_eq!(
+            context.platform.category_min_funding("Literature".to_string()),
+            U256::from(1000)
+        );
+        
+        let zine = context.platform.create_project(
+            "Poetry Zine".to_string(),
+            "A zine of Yoruba poetry".to_string(),
+            "Literature".to_string(),
+            U256::from(1000),
+            U256::from(30),
+            "QmZineHash".to_string()
+        );
+        assert!(zine.is_ok(), "Literature project at global minimum should work");
+    }
+
+    #[test]
+    fn test_active_project_limit_enforced() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        
+        context.platform.set_max_active_projects(U256::from(2), U256::from(5))
+            .expect("Set active project limit failed");
+        
+        context.create_test_project().expect("First project failed");
+        context.create_test_project().expect("Second project failed");
+        expect_error(context.create_test_project(), "Too many active projects");
+    }
+
+    #[test]
+    fn test_finished_project_frees_active_slot() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        
+        context.platform.set_max_active_projects(U256::from(2), U256::from(5))
+            .expect("Set active project limit failed");
+        
+        let first_project = context.create_test_project().expect("First project failed");
+        context.create_test_project().expect("Second project failed");
+        
+        // Reaching the target moves the first project out of Active
+        context.platform.update_project_funding(first_project, U256::from(10000))
+            .expect("Funding update failed");
+        
+        let third_project = context.create_test_project();
+        assert!(third_project.is_ok(), "Finished project should free a slot");
+    }
+
+    #[test]
+    fn test_zero_valued_project_still_exists() {
+        let context = TestContext::new();
+        let mut platform = AfroCreatePlatform::default();
+        platform.initialize(
+            context.ens_registry,
+            U256::from(0), // no minimum, so a zero-target project is legitimate
+            U256::from(90),
+        ).expect("Platform initialization failed");
+        
+        platform.register_creator("zerocreator".to_string(), "Ghanaian".to_string())
+            .expect("Creator registration failed");
+        let project_id = platform.create_project(
+            "Community Archive".to_string(),
+            "Volunteer-run archive with no funding goal".to_string(),
+            "Literature".to_string(),
+            U256::from(0),
+            U256::from(0),
+            "QmZeroHash".to_string()
+        ).expect("Zero-target project creation failed");
+        
+        assert!(platform.is_existing_project(project_id));
+        let project = platform.get_project_info(project_id).expect("Zero-target project not found");
+        assert_eq!(project.funding_target, U256::from(0));
+        assert_eq!(project.funding_raised, U256::from(0));
+        
+        assert!(!platform.is_existing_project(U256::from(999)));
+        expect_error(platform.get_project_info(U256::from(999)), "Project not found");
+    }
+
+    #[test]
+    fn test_category_stats_match_manual_sums() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        
+        let first = context.create_test_project().expect("Project creation failed");
+        let second = context.create_test_project().expect("Project creation failed");
+        let art = context.platform.create_project(
+            "Art Exhibition".to_string(),
+            "Contemporary African art exhibition".to_string(),
+            "Visual Arts".to_string(),
+            U256::from(7000),
+            U256::from(20),
+            "QmArtHash".to_string()
+        ).expect("Art project creation failed");
+        
+        // First music project funded in two steps up to its target, second partially
+        context.platform.update_project_funding(first, U256::from(4000)).expect("Funding update failed");
+        context.platform.update_project_funding(first, U256::from(10000)).expect("Funding update failed");
+        context.platform.update_project_funding(second, U256::from(2500)).expect("Funding update failed");
+        context.platform.update_project_funding(art, U256::from(7000)).expect("Funding update failed");
+        
+        // Re-validation replaces the earlier score
+        context.platform.set_project_validation(first, U256::from(60), true).expect("Validation failed");
+        context.platform.set_project_validation(first, U256::from(90), true).expect("Validation failed");
+        context.platform.set_project_validation(second, U256::from(70), true).expect("Validation failed");
+        
+        let (project_count, total_raised, successful, average_score) =
+            context.platform.get_category_stats("Music".to_string());
+        assert_eq!(project_count, U256::from(2));
+        assert_eq!(total_raised, U256::from(10000 + 2500));
+        assert_eq!(successful, U256::from(1));
+        assert_eq!(average_score, U256::from((90 + 70) / 2));
+        
+        let (art_count, art_raised, art_successful, art_average) =
+            context.platform.get_category_stats("Visual Arts".to_string());
+        assert_eq!((art_count, art_raised, art_successful), (U256::from(1), U256::from(7000), U256::from(1)));
+        assert_eq!(art_average, U256::from(0)); // Not validated yet
+    }
+
+    #[test]
+    fn test_effective_status_of_expired_under_target_project() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        context.platform.update_project_funding(project_id, U256::from(4000))
+            .expect("Funding update failed");
+        assert_eq!(context.platform.get_effective_status(project_id).unwrap(), 0);
+        
+        context.advance_time(31 * 86400);
+        
+        // Nobody has marked it failed, so storage still says Active
+        let project = context.platform.get_project_info(project_id).expect("Get project failed");
+        assert_eq!(project.status, 0);
+        assert_eq!(context.platform.get_effective_status(project_id).unwrap(), 2); // Failed
+    }
+
+    #[test]
+    fn test_effective_status_requires_existing_project() {
+        let context = TestContext::new();
+        expect_error(context.platform.get_effective_status(U256::from(99)), "Project not found");
+    }
+
+    #[test]
+    fn test_adjust_funding_target_before_backing() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        // A 10% change keeps the existing validation
+        context.platform.set_project_validation(project_id, U256::from(85), true)
+            .expect("Validation failed");
+        let reset = context.platform.adjust_funding_target(project_id, U256::from(11000))
+            .expect("Adjustment failed");
+        assert!(!reset);
+        
+        let project = context.platform.get_project_info(project_id).expect("Get project failed");
+        assert_eq!(project.funding_target, U256::from(11000));
+        assert_eq!(project.validation_status, 1);
+        
+        // Doubling the target is significant and sends the project back to validation
+        let reset = context.platform.adjust_funding_target(project_id, U256::from(22000))
+            .expect("Adjustment failed");
+        assert!(reset);
+        
+        let project = context.platform.get_project_info(project_id).expect("Get project failed");
+        assert_eq!(project.validation_status, 0);
+        assert_eq!(project.validation_score, U256::from(0));
+        let (_, _, _, avg_score) = context.platform.get_category_stats("Music".to_string());
+        assert_eq!(avg_score, U256::from(0));
+    }
+
+    #[test]
+    fn test_adjust_funding_target_updates_funding_contract() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        let funding_contract = context.test_accounts[5];
+        context.platform.set_contract_addresses(
+            funding_contract,
+            context.test_accounts[6],
+            context.test_accounts[7],
+            context.test_accounts[8],
+        ).expect("Set contract addresses failed");
+        
+        context.platform.adjust_funding_target(project_id, U256::from(11000))
+            .expect("Adjustment failed");
+        
+        let selector = stylus_sdk::function_selector!("adjustFundingTarget", U256, U256);
+        let mut calldata = selector.to_vec();
+        calldata.extend_from_slice(&project_id.to_be_bytes::<32>());
+        calldata.extend_from_slice(&U256::from(11000).to_be_bytes::<32>());
+        assert!(env::recorded_calls().contains(&(funding_contract, U256::from(0), calldata)));
+        
+        // A milestone plan that no longer sums to the target makes funding refuse the change
+        env::mock_call(funding_contract, selector, Err(Vec::new()));
+        expect_error(
+            context.platform.adjust_funding_target(project_id, U256::from(12000)),
+            "Funding target not adjusted"
+        );
+    }
+
+    #[test]
+    fn test_project_regions_stored_and_forwarded_to_validator() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        let validator_contract = context.test_accounts[7];
+        context.platform.set_contract_addresses(
+            context.test_accounts[5],
+            context.test_accounts[6],
+            validator_contract,
+            context.test_accounts[8],
+        ).expect("Set contract addresses failed");
+        
+        let regions = vec!["West Africa".to_string(), "Diaspora".to_string()];
+        context.platform.set_project_regions(project_id, regions.clone()).expect("Set regions failed");
+        
+        let project = context.platform.get_project_info(project_id).expect("Get project failed");
+        assert_eq!(project.regions, regions);
+        let mut calldata = stylus_sdk::function_selector!("setProjectRegions", U256, Vec<String>).to_vec();
+        calldata.extend_from_slice(&(project_id, regions).abi_encode_params());
+        assert!(env::recorded_calls().contains(&(validator_contract, U256::from(0), calldata)));
+        
+        // Regions are fixed once validators have scored the project
+        context.platform.set_project_validation(project_id, U256::from(85), true).expect("Validation failed");
+        expect_error(
+            context.platform.set_project_regions(project_id, vec!["East Africa".to_string()]),
+            "Project already validated"
+        );
+    }
+
+    #[test]
+    fn test_adjust_funding_target_rejected_after_first_contribution() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        context.platform.update_project_funding(project_id, U256::from(1000))
+            .expect("Funding update failed");
+        
+        expect_error(
+            context.platform.adjust_funding_target(project_id, U256::from(20000)),
+            "Project already has backers"
+        );
+    }
+
+    #[test]
+    fn test_adjust_funding_target_respects_category_minimum() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        expect_error(
+            context.platform.adjust_funding_target(project_id, U256::from(500)),
+            "Funding target too low"
+        );
+    }
+
+    #[test]
+    fn test_trusted_creator_small_project_auto_approved() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        
+        context.platform.set_creator_verified(context.creator(), true).expect("Verification failed");
+        context.platform.set_auto_validation_thresholds(U256::from(100), U256::from(20000))
+            .expect("Set thresholds failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        let project = context.platform.get_project_info(project_id).unwrap();
+        assert_eq!(project.validation_status, 1); // Approved
+        assert_eq!(project.validation_score, U256::from(VALIDATION_THRESHOLD));
+        assert!(context.platform.is_auto_validated(project_id));
+        assert_eq!(context.platform.get_auto_validated_projects(), vec![project_id]);
+    }
+
+    #[test]
+    fn test_ineligible_projects_still_require_validation() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        context.platform.set_auto_validation_thresholds(U256::from(100), U256::from(20000))
+            .expect("Set thresholds failed");
+        
+        // Unverified creator
+        let unverified_project = context.create_test_project().expect("Project creation failed");
+        assert_eq!(context.platform.get_project_info(unverified_project).unwrap().validation_status, 0);
+        
+        // Verified creator, but the project is above the funding size
+        context.platform.set_creator_verified(context.creator(), true).expect("Verification failed");
+        let large_project = context.platform.create_project(
+            "Large Album".to_string(),
+            "A large production".to_string(),
+            "Music".to_string(),
+            U256::from(50000),
+            U256::from(30),
+            "QmLargeHash".to_string(),
+        ).expect("Project creation failed");
+        assert_eq!(context.platform.get_project_info(large_project).unwrap().validation_status, 0);
+        
+        assert!(!context.platform.is_auto_validated(unverified_project));
+        assert!(context.platform.get_auto_validated_projects().is_empty());
+    }
+
+    #[test]
+    fn test_completed_milestones_raise_creator_reputation() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        let creator = context.platform.get_project_info(project_id).unwrap().creator;
+        let starting = context.platform.get_creator_profile(creator).unwrap().reputation_score;
+        
+        let funding_contract = context.test_accounts[5];
+        context.platform.set_contract_addresses(
+            funding_contract,
+            context.test_accounts[6],
+            context.test_accounts[7],
+            context.test_accounts[8],
+        ).expect("Set contract addresses failed");
+        context.platform.set_milestone_reputation_points(U256::from(25)).expect("Set points failed");
+        
+        // The funding contract reports each milestone as it releases funds
+        context.set_sender(funding_contract);
+        context.platform.record_milestone_completed(project_id, U256::from(0)).expect("First milestone failed");
+        context.platform.record_milestone_completed(project_id, U256::from(1)).expect("Second milestone failed");
+        
+        let reputation = context.platform.get_creator_profile(creator).unwrap().reputation_score;
+        assert_eq!(reputation, starting + U256::from(50));
+        expect_error(
+            context.platform.record_milestone_completed(project_id, U256::from(1)),
+            "Milestone already rewarded"
+        );
+        
+        context.set_sender(context.creator());
+        expect_error(
+            context.platform.record_milestone_completed(project_id, U256::from(2)),
+            "Not authorized"
+        );
+        context.set_sender(context.test_accounts[0]);
+    }
+
+    #[test]
+    fn test_milestone_reputation_clamped_to_cap() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        let creator = context.platform.get_project_info(project_id).unwrap().creator;
+        
+        context.platform.set_milestone_reputation_points(U256::from(5000)).expect("Set points failed");
+        let new_score = context.platform.record_milestone_completed(project_id, U256::from(0))
+            .expect("Milestone failed");
+        
+        assert_eq!(new_score, U256::from(MAX_CREATOR_REPUTATION));
+        assert_eq!(context.platform.get_creator_profile(creator).unwrap().reputation_score, new_score);
+    }
+
+    #[test]
+    fn test_removed_category_projects_move_to_fallback() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let first = context.create_test_project().expect("Project creation failed");
+        let second = context.create_test_project().expect("Project creation failed");
+        
+        let reassigned = context.platform.remove_approved_category("Music".to_string())
+            .expect("Category removal failed");
+        assert_eq!(reassigned, U256::from(2));
+        
+        let fallback = context.platform.get_fallback_category();
+        assert_eq!(fallback, "Uncategorized");
+        assert_eq!(context.platform.get_category_projects(fallback.clone()).unwrap(), vec![first, second]);
+        assert!(context.platform.get_category_projects("Music".to_string()).unwrap().is_empty());
+        assert_eq!(context.platform.get_project_info(first).unwrap().cultural_category, fallback);
+        
+        let (project_count, _, _, _) = context.platform.get_category_stats(fallback);
+        assert_eq!(project_count, U256::from(2));
+        
+        // The removed category no longer accepts new projects
+        expect_error(context.create_test_project(), "Cultural category not approved");
+    }
+
+    #[test]
+    fn test_fallback_category_cannot_be_removed() {
+        let context = TestContext::new();
+        let mut platform = context.platform;
+        
+        platform.set_fallback_category("Music".to_string()).expect("Set fallback failed");
+        expect_error(platform.remove_approved_category("Music".to_string()), "Cannot remove fallback category");
+        expect_error(platform.remove_approved_category("Opera".to_string()), "Cultural category not approved");
+    }
+
+    #[test]
+    fn test_project_creation_record_matches_event_fields() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        // ProjectCreated carries the same values recorded here; logs can't be captured in unit tests
+        let project = context.platform.get_project_info(project_id).expect("Get project info failed");
+        let (_block_number, created_at) = context.platform.get_project_creation_context(project_id)
+            .expect("Creation context missing");
+        
+        assert_eq!(project.creator, context.creator());
+        assert_eq!(project.cultural_category, "Music");
+        assert_eq!(project.funding_target, U256::from(10000));
+        assert_eq!(project.deadline, created_at + U256::from(30 * 86400));
+        
+        expect_error(context.platform.get_project_creation_context(U256::from(999)), "Project not found");
+    }
+
+    fn create_high_value_project(context: &mut TestContext) -> Result<U256, String> {
+        context.platform.create_project(
+            "Feature Film".to_string(),
+            "A full-length cultural documentary".to_string(),
+            "Music".to_string(),
+            U256::from(50000),
+            U256::from(30),
+            "QmFilmHash".to_string(),
+        ).map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_low_reputation_creator_blocked_from_high_value_project() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        
+        // New creators start at 100 reputation
+        context.platform.set_high_value_gate(U256::from(20000), U256::from(150), true)
+            .expect("Set gate failed");
+        assert_eq!(
+            context.platform.get_high_value_gate(),
+            (U256::from(20000), U256::from(150), true)
+        );
+        
+        expect_error(create_high_value_project(&mut context), "Reputation too low for high-value project");
+        // Targets at or below the threshold are unaffected
+        context.create_test_project().expect("Small project creation failed");
+    }
+
+    #[test]
+    fn test_reputable_or_verified_creator_can_create_high_value_project() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        let first_project = context.create_test_project().expect("Project creation failed");
+        
+        // One delivered milestone lifts the creator from 100 to 110
+        context.platform.record_milestone_completed(first_project, U256::from(0))
+            .expect("Milestone reward failed");
+        context.platform.set_high_value_gate(U256::from(20000), U256::from(110), false)
+            .expect("Set gate failed");
+        create_high_value_project(&mut context).expect("High-value project creation failed");
+        
+        // Verified creators bypass a higher bar when exempt
+        context.platform.set_high_value_gate(U256::from(20000), U256::from(500), true)
+            .expect("Set gate failed");
+        context.platform.set_creator_verified(context.creator(), true).expect("Verification failed");
+        create_high_value_project(&mut context).expect("Verified creator project creation failed");
+        
+        expect_error(
+            context.platform.set_high_value_gate(U256::from(20000), U256::from(1001), true),
+            "Reputation bar above maximum"
+        );
+    }
+
+    #[test]
+    fn test_project_statuses_for_mixed_batch() {
+        let mut context = TestContext::new();
+        context.register_test_creator().expect("Creator registration failed");
+        
+        let successful = context.create_test_project().expect("Project creation failed");
+        let expired = context.create_test_project().expect("Project creation failed");
+        context.platform.update_project_funding(successful, U256::from(10000))
+            .expect("Funding update failed");
+        context.platform.update_project_funding(expired, U256::from(4000))
+            .expect("Funding update failed");
+        
+        context.advance_time(31 * 86400);
+        let active = context.create_test_project().expect("Project creation failed");
+        
+        let statuses = context.platform.get_project_statuses(
+            vec![active, successful, expired, U256::from(99)]
+        ).expect("Status query failed");
+        assert_eq!(statuses, vec![0, 1, 2, 255]);
+        
+        // Each entry agrees with the single-project query
+        assert_eq!(statuses[2], context.platform.get_effective_status(expired).unwrap());
+    }
+
+    #[test]
+    fn test_project_statuses_batch_capped() {
+        let context = TestContext::new();
+        assert!(context.platform.get_project_statuses(Vec::new()).unwrap().is_empty());
+        expect_error(
+            context.platform.get_project_statuses(vec![U256::from(1); 101]),
+            "Too many projects"
+        );
+    }
+}
@@ -0,0 +1,1066 @@
+use alloy_primitives::{Address, U256};
+use afrocreate_contracts::{types::env, OracleManager, RevenueDistributor, RevenueShareNFT};
+use crate::test_utils::*;
+
+#[cfg(test)]
+mod revenue_tests {
+    use super::*;
+
+    fn setup_distributor(accounts: &[Address]) -> RevenueDistributor {
+        let mut distributor = RevenueDistributor::default();
+        distributor.initialize(
+            accounts[5], // platform contract
+            accounts[6], // nft contract
+            accounts[7], // superfluid host
+        ).expect("Distributor initialization failed");
+        distributor
+    }
+
+    fn setup_nft(accounts: &[Address]) -> RevenueShareNFT {
+        let mut nft = RevenueShareNFT::default();
+        nft.initialize(
+            "AfroCreate Revenue Share".to_string(),
+            "AFRS".to_string(),
+            accounts[5], // platform contract
+            accounts[6], // funding contract
+            "ipfs://afrocreate".to_string(),
+        ).expect("NFT initialization failed");
+        nft
+    }
+
+    #[test]
+    fn test_stream_settlement_accrues_flow() {
+        let mut context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let token = context.test_accounts[8];
+        let project_id = U256::from(1);
+        
+        distributor.add_accepted_token(token).expect("Add token failed");
+        distributor.setup_superfluid_stream(project_id, token, 1000).expect("Stream setup failed");
+        
+        // One hour of streaming at 1000 wei per second
+        context.advance_time(3600);
+        let settled = distributor.settle_and_distribute_stream(project_id, token)
+            .expect("Settlement failed");
+        
+        assert_eq!(settled, U256::from(1000 * 3600));
+        let stream = distributor.get_streaming_revenue(project_id, token).expect("Stream missing");
+        assert_eq!(stream.total_streamed, settled);
+    }
+
+    #[test]
+    fn test_stream_settlement_increases_holder_claimable() {
+        let mut context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let mut nft = setup_nft(&context.test_accounts);
+        let token = context.test_accounts[8];
+        let project_id = U256::from(1);
+        
+        nft.set_project_revenue_token(project_id, token).expect("Set revenue token failed");
+        let token_a = nft.mint_revenue_nft(
+            context.backer(), project_id, U256::from(5000), U256::from(5000), String::new()
+        ).expect("Mint failed");
+        let token_b = nft.mint_revenue_nft(
+            context.test_accounts[9], project_id, U256::from(5000), U256::from(5000), String::new()
+        ).expect("Mint failed");
+        
+        distributor.add_accepted_token(token).expect("Add token failed");
+        distributor.setup_superfluid_stream(project_id, token, 1000).expect("Stream setup failed");
+        context.advance_time(3600);
+        
+        let settled = distributor.settle_and_distribute_stream(project_id, token)
+            .expect("Settlement failed");
+        assert_eq!(settled, U256::from(1000 * 3600));
+        
+        let analytics = distributor.get_revenue_analytics();
+        assert_eq!(analytics.total_revenue_distributed, settled);
+        assert_eq!(analytics.total_revenue_pending, U256::from(0));
+        
+        // Settlement forwards exactly the settled amount to batchDistributeRevenue
+        nft.batch_distribute_revenue(project_id, settled).expect("Distribution failed");
+        
+        assert_eq!(nft.calculate_claimable_revenue(token_a).unwrap(), settled / U256::from(2));
+        assert_eq!(nft.calculate_claimable_revenue(token_b).unwrap(), settled / U256::from(2));
+        
+        // A second settlement with no elapsed time has nothing left to forward
+        expect_error(
+            distributor.settle_and_distribute_stream(project_id, token),
+            "Nothing accrued to settle"
+        );
+    }
+
+    #[test]
+    fn test_stream_settlement_requires_active_stream() {
+        let context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        
+        expect_error(
+            distributor.settle_and_distribute_stream(U256::from(1), context.test_accounts[8]),
+            "No active stream for this token"
+        );
+    }
+
+    #[test]
+    fn test_zero_holder_distribution_is_held_unallocated() {
+        let context = TestContext::new();
+        let mut nft = setup_nft(&context.test_accounts);
+        let project_id = U256::from(1);
+        let amount = U256::from(10000);
+        
+        // No NFTs minted for this project yet
+        nft.batch_distribute_revenue(project_id, amount).expect("Distribution failed");
+        
+        assert_eq!(nft.get_unallocated_revenue(project_id), amount);
+        assert_eq!(nft.get_project_revenue_stats(project_id).total_revenue_generated, U256::from(0));
+    }
+
+    #[test]
+    fn test_unallocated_revenue_released_once_holders_exist() {
+        let context = TestContext::new();
+        let mut nft = setup_nft(&context.test_accounts);
+        let project_id = U256::from(1);
+        let amount = U256::from(10000);
+        
+        nft.batch_distribute_revenue(project_id, amount).expect("Distribution failed");
+        expect_error(nft.release_unallocated_revenue(project_id), "Project has no holders");
+        
+        let token_id = nft.mint_revenue_nft(
+            context.backer(), project_id, U256::from(5000), U256::from(10000), String::new()
+        ).expect("Mint failed");
+        
+        let released = nft.release_unallocated_revenue(project_id).expect("Release failed");
+        assert_eq!(released, amount);
+        assert_eq!(nft.get_unallocated_revenue(project_id), U256::from(0));
+        assert_eq!(nft.calculate_claimable_revenue(token_id).unwrap(), amount);
+    }
+
+    #[test]
+    fn test_next_distribution_sweeps_unallocated_revenue_to_holders() {
+        let context = TestContext::new();
+        let mut nft = setup_nft(&context.test_accounts);
+        let project_id = U256::from(1);
+        let parked = U256::from(10000);
+        let fresh = U256::from(4000);
+        
+        nft.batch_distribute_revenue(project_id, parked).expect("Distribution failed");
+        let token_id = nft.mint_revenue_nft(
+            context.backer(), project_id, U256::from(5000), U256::from(10000), String::new()
+        ).expect("Mint failed");
+        
+        // No explicit release: the regular distribution path carries the parked amount along
+        nft.batch_distribute_revenue(project_id, fresh).expect("Distribution failed");
+        
+        assert_eq!(nft.get_unallocated_revenue(project_id), U256::from(0));
+        assert_eq!(nft.calculate_claimable_revenue(token_id).unwrap(), parked + fresh);
+        assert_eq!(nft.get_project_revenue_stats(project_id).total_revenue_generated, parked + fresh);
+    }
+
+    #[test]
+    fn test_supported_sources_defaults() {
+        let context = TestContext::new();
+        let distributor = setup_distributor(&context.test_accounts);
+        
+        let sources = distributor.get_supported_sources();
+        assert_eq!(sources.len(), 10);
+        
+        let verified: Vec<&str> = sources.iter()
+            .filter(|(_, _, verification_required)| *verification_required)
+            .map(|(name, _, _)| name.as_str())
+            .collect();
+        assert_eq!(verified, vec!["spotify", "apple_music", "youtube", "licensing", "nft_sales"]);
+        assert!(sources.iter().all(|(_, is_active, _)| *is_active));
+    }
+
+    #[test]
+    fn test_supported_sources_pagination() {
+        let context = TestContext::new();
+        let distributor = setup_distributor(&context.test_accounts);
+        
+        let page = distributor.get_supported_sources_paginated(U256::from(8), U256::from(5));
+        assert_eq!(page.len(), 2);
+        assert_eq!(page[0].0, "nft_sales");
+        assert!(distributor.get_supported_sources_paginated(U256::from(20), U256::from(5)).is_empty());
+    }
+
+    #[test]
+    fn test_revenue_analytics_average_across_projects() {
+        let context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        
+        let amounts = [(1u64, 3000u64), (2, 7000), (3, 5000)];
+        for (project_id, amount) in amounts {
+            distributor.add_revenue_source(
+                U256::from(project_id),
+                "soundcloud".to_string(),
+                U256::from(amount),
+                "ipfs://proof".to_string(),
+            ).expect("Adding revenue failed");
+        }
+        // Second report for an existing project must not count it twice
+        distributor.add_revenue_source(
+            U256::from(1), "bandcamp".to_string(), U256::from(3000), "ipfs://proof".to_string()
+        ).expect("Adding revenue failed");
+        
+        let analytics = distributor.get_revenue_analytics();
+        assert_eq!(analytics.total_revenue_processed, U256::from(18000));
+        assert_eq!(analytics.active_revenue_projects, U256::from(3));
+        assert_eq!(analytics.average_project_revenue, U256::from(18000 / 3));
+        assert_eq!(analytics.total_revenue_distributed, U256::from(0));
+        assert_eq!(analytics.total_revenue_pending, U256::from(18000));
+        
+        let (processed, projects, average) = distributor.platform_revenue_stats();
+        assert_eq!((processed, projects, average), (U256::from(18000), U256::from(3), U256::from(6000)));
+    }
+
+    #[test]
+    fn test_revenue_source_shares_sum_to_total() {
+        let context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        for (source, amount) in [("soundcloud", 1000u64), ("bandcamp", 1000), ("merchandise", 1000)] {
+            distributor.add_revenue_source(
+                project_id, source.to_string(), U256::from(amount), "ipfs://proof".to_string()
+            ).expect("Adding revenue failed");
+        }
+        
+        let shares = distributor.get_project_revenue_source_shares(project_id);
+        assert_eq!(shares.len(), 3);
+        
+        let total_bps = shares.iter().fold(U256::from(0), |acc, (_, bps)| acc + *bps);
+        assert!(total_bps <= U256::from(10000));
+        assert!(total_bps >= U256::from(10000 - 3)); // One bps of rounding per source
+    }
+
+    #[test]
+    fn test_revenue_source_shares_empty_without_revenue() {
+        let context = TestContext::new();
+        let distributor = setup_distributor(&context.test_accounts);
+        
+        assert!(distributor.get_project_revenue_source_shares(U256::from(42)).is_empty());
+    }
+
+    #[test]
+    fn test_stream_rate_change_preserves_prior_accrual() {
+        let mut context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let token = context.test_accounts[8];
+        let project_id = U256::from(1);
+        
+        distributor.add_accepted_token(token).expect("Add token failed");
+        distributor.setup_superfluid_stream(project_id, token, 1000).expect("Stream setup failed");
+        
+        // 100 seconds at the old rate, then the rate doubles
+        context.advance_time(100);
+        distributor.update_stream_flow_rate(project_id, token, 2000).expect("Rate update failed");
+        
+        let stream = distributor.get_streaming_revenue(project_id, token).expect("Stream missing");
+        assert_eq!(stream.total_streamed, U256::from(100_000));
+        assert_eq!(stream.flow_rate, 2000);
+        
+        // 50 seconds at the new rate, settled together with the banked accrual
+        context.advance_time(50);
+        let settled = distributor.settle_and_distribute_stream(project_id, token)
+            .expect("Settlement failed");
+        assert_eq!(settled, U256::from(100_000 + 100_000));
+        
+        let stream = distributor.get_streaming_revenue(project_id, token).expect("Stream missing");
+        assert_eq!(stream.total_streamed, U256::from(200_000));
+    }
+
+    #[test]
+    fn test_stream_rate_update_rejects_invalid_input() {
+        let context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let token = context.test_accounts[8];
+        let project_id = U256::from(1);
+        
+        expect_error(
+            distributor.update_stream_flow_rate(project_id, token, 500),
+            "No active stream for this token"
+        );
+        
+        distributor.add_accepted_token(token).expect("Add token failed");
+        distributor.setup_superfluid_stream(project_id, token, 1000).expect("Stream setup failed");
+        expect_error(
+            distributor.update_stream_flow_rate(project_id, token, 0),
+            "Flow rate must be positive"
+        );
+    }
+
+    #[test]
+    fn test_claim_fee_partially_burned() {
+        let mut context = TestContext::new();
+        let mut nft = setup_nft(&context.test_accounts);
+        let project_id = U256::from(1);
+        let holder = context.backer();
+        
+        let token_id = nft.mint_revenue_nft(
+            holder, project_id, U256::from(5000), U256::from(10000), String::new()
+        ).expect("Mint failed");
+        nft.batch_distribute_revenue(project_id, U256::from(1_000_000)).expect("Distribution failed");
+        
+        // 1% claim fee on 1,000,000 is 10,000; a quarter of it is burned
+        nft.set_min_claim_amount(U256::from(0)).expect("Set minimum claim failed");
+        nft.set_burn_bps(U256::from(2500)).expect("Set burn share failed");
+        expect_error(nft.claim_revenue(token_id), "Not token owner");
+        context.set_sender(holder);
+        let net = nft.claim_revenue(token_id).expect("Claim failed");
+        context.set_sender(context.test_accounts[0]);
+        
+        assert_eq!(net, U256::from(990_000));
+        assert_eq!(nft.total_burned_fees(), U256::from(2_500));
+        assert_eq!(nft.collected_fees(), U256::from(7_500));
+        
+        expect_error(
+            nft.withdraw_fees(context.admin(), U256::from(7_501)),
+            "Insufficient collected fees"
+        );
+    }
+
+    #[test]
+    fn test_burn_share_capped() {
+        let context = TestContext::new();
+        let mut nft = setup_nft(&context.test_accounts);
+        
+        expect_error(nft.set_burn_bps(U256::from(10001)), "Burn share too high");
+    }
+
+    fn setup_distributed_revenue(
+        context: &mut TestContext,
+        payout_address: Address,
+    ) -> (RevenueDistributor, U256) {
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        distributor.set_project_creator(project_id, context.creator()).expect("Set creator failed");
+        context.set_sender(context.creator());
+        distributor.set_auto_payout(true, payout_address).expect("Enable auto-payout failed");
+        context.set_sender(context.test_accounts[0]);
+        distributor.add_revenue_source(
+            project_id, "soundcloud".to_string(), U256::from(1_000_000_000_000_000_000u64), "ipfs://proof".to_string()
+        ).expect("Adding revenue failed");
+        
+        distributor.distribute_revenue(project_id).expect("Distribution failed");
+        (distributor, project_id)
+    }
+
+    #[test]
+    fn test_auto_payout_pushes_creator_share() {
+        let mut context = TestContext::new();
+        let payout_address = context.test_accounts[9];
+        let (distributor, project_id) = setup_distributed_revenue(&mut context, payout_address);
+        
+        assert_eq!(distributor.get_auto_payout(context.creator()), (true, payout_address));
+        assert!(!distributor.get_auto_payout(context.test_accounts[0]).0);
+        // Pushed during distribution, so nothing is left to pull
+        assert_eq!(distributor.get_pending_creator_payout(project_id), U256::from(0));
+    }
+
+    #[test]
+    fn test_auto_payout_falls_back_to_claimable() {
+        let mut context = TestContext::new();
+        // The payout address rejects value, so the push reverts
+        let payout_address = context.test_accounts[9];
+        context.set_rejects_value(payout_address, true);
+        let (mut distributor, project_id) = setup_distributed_revenue(&mut context, payout_address);
+        
+        // 30% default creator share of 1 ETH
+        let creator_share = U256::from(300_000_000_000_000_000u64);
+        assert_eq!(distributor.get_pending_creator_payout(project_id), creator_share);
+        
+        context.set_sender(context.creator());
+        let claimed = distributor.claim_creator_revenue(project_id).expect("Claim failed");
+        assert_eq!(claimed, creator_share);
+        assert_eq!(distributor.get_pending_creator_payout(project_id), U256::from(0));
+    }
+
+    #[test]
+    fn test_revenue_denomination_recorded_on_first_revenue() {
+        let context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let usdc = context.test_accounts[8];
+        let project_id = U256::from(1);
+        
+        distributor.add_accepted_token(usdc).expect("Add token failed");
+        distributor.add_token_revenue_source(
+            project_id, "soundcloud".to_string(), U256::from(5_000_000), "ipfs://proof".to_string(), usdc, U256::from(6)
+        ).expect("Adding revenue failed");
+        
+        let breakdown = distributor.get_revenue_breakdown(project_id).expect("Breakdown failed");
+        assert_eq!(breakdown.denomination_token, usdc);
+        assert_eq!(breakdown.denomination_decimals, U256::from(6));
+        
+        // The NFT learns the token so holders claim in USDC rather than ETH
+        let mut calldata = stylus_sdk::function_selector!("setProjectRevenueToken", U256, Address).to_vec();
+        calldata.extend_from_slice(&project_id.to_be_bytes::<32>());
+        calldata.extend_from_slice(usdc.into_word().as_slice());
+        assert_eq!(env::recorded_calls(), vec![(context.test_accounts[6], U256::from(0), calldata)]);
+        
+        // Later reports must use the same unit
+        expect_error(
+            distributor.add_revenue_source(
+                project_id, "bandcamp".to_string(), U256::from(1000), "ipfs://proof".to_string()
+            ),
+            "Revenue denomination mismatch"
+        );
+    }
+
+    #[test]
+    fn test_token_revenue_paid_out_in_denomination_token() {
+        let context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let usdc = context.test_accounts[8];
+        let nft = context.test_accounts[6];
+        let project_id = U256::from(1);
+        
+        distributor.add_accepted_token(usdc).expect("Add token failed");
+        distributor.set_project_creator(project_id, context.test_accounts[0]).expect("Set creator failed");
+        distributor.add_token_revenue_source(
+            project_id, "soundcloud".to_string(), U256::from(1_000_000_000), "ipfs://proof".to_string(), usdc, U256::from(6)
+        ).expect("Adding revenue failed");
+        distributor.distribute_revenue(project_id).expect("Distribution failed");
+        let creator_share = distributor.get_pending_creator_payout(project_id);
+        distributor.claim_creator_revenue(project_id).expect("Claim failed");
+        
+        let transfer_call = |recipient: Address, amount: U256| {
+            let mut calldata = stylus_sdk::function_selector!("transfer", Address, U256).to_vec();
+            calldata.extend_from_slice(recipient.into_word().as_slice());
+            calldata.extend_from_slice(&amount.to_be_bytes::<32>());
+            (usdc, U256::from(0), calldata)
+        };
+        
+        // The community share reaches the NFT as USDC and the call to it carries no ETH
+        let community_share = U256::from(670_000_000);
+        let calls = env::recorded_calls();
+        assert!(calls.contains(&transfer_call(nft, community_share)));
+        assert!(calls.contains(&transfer_call(context.test_accounts[0], creator_share)));
+        assert!(calls.iter().all(|(_, value, _)| *value == U256::from(0)));
+    }
+
+    #[test]
+    fn test_token_revenue_claim_fails_when_token_transfer_returns_false() {
+        let context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let usdc = context.test_accounts[8];
+        let project_id = U256::from(1);
+        
+        distributor.add_accepted_token(usdc).expect("Add token failed");
+        distributor.set_project_creator(project_id, context.test_accounts[0]).expect("Set creator failed");
+        distributor.add_token_revenue_source(
+            project_id, "soundcloud".to_string(), U256::from(1_000_000_000), "ipfs://proof".to_string(), usdc, U256::from(6)
+        ).expect("Adding revenue failed");
+        distributor.distribute_revenue(project_id).expect("Distribution failed");
+        
+        let selector = stylus_sdk::function_selector!("transfer", Address, U256);
+        env::mock_call(usdc, selector, Ok(vec![0u8; 32]));
+        expect_error(distributor.claim_creator_revenue(project_id), "Token transfer failed");
+    }
+
+    #[test]
+    fn test_native_revenue_denomination_in_breakdown() {
+        let context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let project_id = U256::from(2);
+        
+        distributor.add_revenue_source(
+            project_id, "soundcloud".to_string(), U256::from(3000), "ipfs://proof".to_string()
+        ).expect("Adding revenue failed");
+        
+        let breakdown = distributor.get_revenue_breakdown(project_id).expect("Breakdown failed");
+        assert_eq!(breakdown.denomination_token, Address::ZERO);
+        assert_eq!(breakdown.denomination_decimals, U256::from(18));
+        assert_eq!(breakdown.total_revenue, U256::from(3000));
+    }
+
+    #[test]
+    fn test_reinvesting_clears_source_claimable() {
+        let mut context = TestContext::new();
+        let mut nft = setup_nft(&context.test_accounts);
+        let project_id = U256::from(1);
+        let target_project_id = U256::from(2);
+        let holder = context.backer();
+        
+        let token_id = nft.mint_revenue_nft(
+            holder, project_id, U256::from(5000), U256::from(10000), String::new()
+        ).expect("Mint failed");
+        nft.batch_distribute_revenue(project_id, U256::from(1_000_000)).expect("Distribution failed");
+        
+        expect_error(nft.reinvest_revenue(token_id, target_project_id), "Not token owner");
+        context.set_sender(holder);
+        let reinvested = nft.reinvest_revenue(token_id, target_project_id).expect("Reinvest failed");
+        
+        // The full claimable amount moves over; no claim fee is taken
+        assert_eq!(reinvested, U256::from(1_000_000));
+        assert_eq!(nft.calculate_claimable_revenue(token_id).unwrap(), U256::from(0));
+        assert_eq!(nft.collected_fees(), U256::from(0));
+        expect_error(nft.reinvest_revenue(token_id, target_project_id), "No claimable revenue");
+        context.set_sender(context.test_accounts[0]);
+    }
+
+    #[test]
+    fn test_reinvestment_target_validated() {
+        let context = TestContext::new();
+        let mut nft = setup_nft(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        let token_id = nft.mint_revenue_nft(
+            context.test_accounts[0], project_id, U256::from(5000), U256::from(10000), String::new()
+        ).expect("Mint failed");
+        nft.batch_distribute_revenue(project_id, U256::from(1_000_000)).expect("Distribution failed");
+        
+        expect_error(nft.reinvest_revenue(token_id, U256::from(0)), "Invalid target project");
+        
+        // Token-denominated revenue cannot be attached to a native contribution
+        nft.set_project_revenue_token(project_id, context.test_accounts[9]).expect("Set revenue token failed");
+        expect_error(nft.reinvest_revenue(token_id, U256::from(2)), "Only native revenue can be reinvested");
+        assert_eq!(nft.calculate_claimable_revenue(token_id).unwrap(), U256::from(1_000_000));
+    }
+
+    #[test]
+    fn test_approved_operator_can_transfer() {
+        let mut context = TestContext::new();
+        let mut nft = setup_nft(&context.test_accounts);
+        let holder = context.backer();
+        let operator = context.admin();
+        let recipient = context.creator();
+        
+        let token_id = nft.mint_revenue_nft(
+            holder, U256::from(1), U256::from(1000), U256::from(5000), String::new()
+        ).expect("Mint failed");
+        nft.remove_transfer_restriction(token_id).expect("Remove restriction failed");
+        
+        context.set_sender(holder);
+        nft.set_approval_for_all(operator, true).expect("Approval failed");
+        assert!(nft.is_approved_for_all(holder, operator));
+        assert!(!nft.is_approved_for_all(context.test_accounts[0], operator));
+        
+        context.set_sender(operator);
+        nft.transfer_from(holder, recipient, token_id).expect("Operator transfer failed");
+        assert_eq!(nft.owner_of(token_id).unwrap(), recipient);
+        context.set_sender(context.test_accounts[0]);
+    }
+
+    #[test]
+    fn test_revoked_operator_cannot_transfer() {
+        let context = TestContext::new();
+        let mut nft = setup_nft(&context.test_accounts);
+        let operator = context.admin();
+        
+        nft.set_approval_for_all(operator, true).expect("Approval failed");
+        nft.set_approval_for_all(operator, false).expect("Revoke failed");
+        assert!(!nft.is_approved_for_all(context.test_accounts[0], operator));
+        
+        // A caller that is neither owner, approved, nor operator is rejected
+        let token_id = nft.mint_revenue_nft(
+            context.backer(), U256::from(1), U256::from(1000), U256::from(5000), String::new()
+        ).expect("Mint failed");
+        nft.remove_transfer_restriction(token_id).expect("Remove restriction failed");
+        expect_error(
+            nft.transfer_from(context.backer(), context.admin(), token_id),
+            "Not authorized"
+        );
+    }
+
+    #[test]
+    fn test_operator_approval_rejects_self() {
+        let context = TestContext::new();
+        let mut nft = setup_nft(&context.test_accounts);
+        
+        expect_error(
+            nft.set_approval_for_all(context.test_accounts[0], true),
+            "Approval to caller"
+        );
+    }
+
+    fn setup_vesting_revenue(context: &TestContext, period: u64) -> (RevenueDistributor, U256) {
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        distributor.set_project_creator(project_id, context.test_accounts[0]).expect("Set creator failed");
+        distributor.set_creator_vesting(project_id, U256::from(period)).expect("Set vesting failed");
+        distributor.add_revenue_source(
+            project_id, "soundcloud".to_string(), U256::from(1_000_000_000_000_000_000u64), "ipfs://proof".to_string()
+        ).expect("Adding revenue failed");
+        
+        distributor.distribute_revenue(project_id).expect("Distribution failed");
+        (distributor, project_id)
+    }
+
+    #[test]
+    fn test_creator_revenue_vests_linearly() {
+        let mut context = TestContext::new();
+        let (distributor, project_id) = setup_vesting_revenue(&context, 100 * 86400);
+        
+        // 30% default creator share of 1 ETH is locked rather than paid out
+        let (period, _, pool, claimed) = distributor.get_creator_vesting(project_id);
+        assert_eq!(period, U256::from(100 * 86400));
+        assert_eq!(pool, U256::from(300_000_000_000_000_000u64));
+        assert_eq!(claimed, U256::from(0));
+        
+        context.advance_time(25 * 86400);
+        assert_eq!(distributor.get_claimable_vested_revenue(project_id), pool / U256::from(4));
+    }
+
+    #[test]
+    fn test_creator_revenue_fully_vested_after_period() {
+        let mut context = TestContext::new();
+        let (mut distributor, project_id) = setup_vesting_revenue(&context, 100 * 86400);
+        let (_, _, pool, _) = distributor.get_creator_vesting(project_id);
+        
+        // A quarter in, only a quarter of the pool can be claimed
+        context.advance_time(25 * 86400);
+        let first = distributor.claim_creator_revenue(project_id).expect("Partial claim failed");
+        assert_eq!(first, pool / U256::from(4));
+        
+        context.advance_time(75 * 86400);
+        assert_eq!(distributor.get_claimable_vested_revenue(project_id), pool - first);
+        let rest = distributor.claim_creator_revenue(project_id).expect("Claim failed");
+        assert_eq!(first + rest, pool);
+        
+        let (_, _, _, claimed) = distributor.get_creator_vesting(project_id);
+        assert_eq!(claimed, pool);
+        assert_eq!(distributor.get_claimable_vested_revenue(project_id), U256::from(0));
+        expect_error(distributor.claim_creator_revenue(project_id), "No claimable revenue");
+    }
+
+    #[test]
+    fn test_creator_claims_only_distributed_share_once() {
+        let context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        distributor.set_project_creator(project_id, context.test_accounts[0]).expect("Set creator failed");
+        distributor.add_revenue_source(
+            project_id, "soundcloud".to_string(), U256::from(1_000_000_000_000_000_000u64), "ipfs://proof".to_string()
+        ).expect("Adding revenue failed");
+        
+        // Nothing is allocated to the creator until revenue is distributed
+        expect_error(distributor.claim_creator_revenue(project_id), "No claimable revenue");
+        
+        distributor.distribute_revenue(project_id).expect("Distribution failed");
+        let creator_share = U256::from(300_000_000_000_000_000u64);
+        assert_eq!(distributor.get_pending_creator_payout(project_id), creator_share);
+        assert_eq!(distributor.claim_creator_revenue(project_id).expect("Claim failed"), creator_share);
+        expect_error(distributor.claim_creator_revenue(project_id), "No claimable revenue");
+    }
+
+    #[test]
+    fn test_creator_vesting_fixed_once_started() {
+        let context = TestContext::new();
+        let (mut distributor, project_id) = setup_vesting_revenue(&context, 100 * 86400);
+        
+        expect_error(
+            distributor.set_creator_vesting(project_id, U256::from(0)),
+            "Vesting already started"
+        );
+    }
+
+    #[test]
+    fn test_first_distribution_exempt_from_frequency() {
+        let context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        distributor.set_distribution_frequency(U256::from(365 * 86400)).expect("Set frequency failed");
+        distributor.add_revenue_source(
+            project_id, "soundcloud".to_string(), U256::from(1_000_000_000_000_000_000u64), "ipfs://proof".to_string()
+        ).expect("Adding revenue failed");
+        
+        let distributed = distributor.distribute_revenue(project_id).expect("First distribution failed");
+        assert_eq!(distributed, U256::from(1_000_000_000_000_000_000u64));
+    }
+
+    #[test]
+    fn test_sub_threshold_revenue_held_without_splitting() {
+        let context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        distributor.set_min_revenue_for_split(U256::from(5000)).expect("Set threshold failed");
+        for amount in [1000u64, 2000] {
+            distributor.add_revenue_source(
+                project_id, "soundcloud".to_string(), U256::from(amount), "ipfs://proof".to_string()
+            ).expect("Adding revenue failed");
+        }
+        
+        assert_eq!(distributor.get_held_revenue(project_id), U256::from(3000));
+        expect_error(distributor.get_revenue_breakdown(project_id), "Project has no revenue");
+        assert_eq!(distributor.get_revenue_analytics().active_revenue_projects, U256::from(1));
+        expect_error(distributor.distribute_revenue(project_id), "No revenue to distribute");
+    }
+
+    #[test]
+    fn test_crossing_split_threshold_releases_held_revenue() {
+        let context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let project_id = U256::from(1);
+        
+        distributor.set_min_revenue_for_split(U256::from(5000)).expect("Set threshold failed");
+        for amount in [3000u64, 2500] {
+            distributor.add_revenue_source(
+                project_id, "soundcloud".to_string(), U256::from(amount), "ipfs://proof".to_string()
+            ).expect("Adding revenue failed");
+        }
+        
+        assert_eq!(distributor.get_held_revenue(project_id), U256::from(0));
+        let breakdown = distributor.get_revenue_breakdown(project_id).expect("Breakdown failed");
+        assert_eq!(breakdown.total_revenue, U256::from(5500));
+    }
+
+    #[test]
+    fn test_distribution_batch_skips_projects_not_due() {
+        let context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let one_eth = U256::from(1_000_000_000_000_000_000u64);
+        
+        distributor.set_distribution_frequency(U256::from(365 * 86400)).expect("Set frequency failed");
+        for project_id in [1u64, 2] {
+            distributor.add_revenue_source(
+                U256::from(project_id), "soundcloud".to_string(), one_eth, "ipfs://proof".to_string()
+            ).expect("Adding revenue failed");
+        }
+        
+        // Project 1 was just distributed, so new revenue isn't due until the frequency passes
+        distributor.distribute_revenue(U256::from(1)).expect("Distribution failed");
+        distributor.add_revenue_source(
+            U256::from(1), "soundcloud".to_string(), one_eth, "ipfs://proof".to_string()
+        ).expect("Adding revenue failed");
+        
+        // Project 3 has no revenue at all
+        let distributed = distributor.distribute_revenue_batch(vec![U256::from(1), U256::from(2), U256::from(3)])
+            .expect("Batch distribution failed");
+        
+        assert_eq!(distributed, vec![U256::from(2)]);
+        assert_eq!(distributor.get_revenue_analytics().total_revenue_distributed, one_eth * U256::from(2));
+    }
+
+    fn setup_claimable_token(nft: &mut RevenueShareNFT, holder: Address, project_id: U256, revenue: u64) -> U256 {
+        let token_id = nft.mint_revenue_nft(
+            holder, project_id, U256::from(5000), U256::from(10000), String::new()
+        ).expect("Mint failed");
+        nft.batch_distribute_revenue(project_id, U256::from(revenue)).expect("Distribution failed");
+        token_id
+    }
+
+    #[test]
+    fn test_usdc_claim_uses_token_minimum() {
+        let mut context = TestContext::new();
+        let mut nft = setup_nft(&context.test_accounts);
+        let usdc = context.test_accounts[9];
+        let project_id = U256::from(1);
+        
+        // 0.1 USDC in 6 decimals, far below the 0.001 ETH global minimum in wei
+        nft.set_min_claim_amount_for_token(usdc, U256::from(100_000)).expect("Set token minimum failed");
+        nft.set_project_revenue_token(project_id, usdc).expect("Set revenue token failed");
+        assert_eq!(nft.get_min_claim_amount(usdc), U256::from(100_000));
+        assert_eq!(nft.get_min_claim_amount(Address::ZERO), U256::from(1_000_000_000_000_000u64));
+        
+        let token_id = setup_claimable_token(&mut nft, context.backer(), project_id, 500_000);
+        context.set_sender(context.backer());
+        let net = nft.claim_revenue(token_id).expect("USDC claim failed");
+        context.set_sender(context.test_accounts[0]);
+        assert_eq!(net, U256::from(495_000));
+        
+        // The retained fee is kept in USDC, apart from the native fee balance
+        assert_eq!(nft.collected_fees(), U256::from(0));
+        let usdc_fees = nft.collected_token_fees(usdc);
+        assert_eq!(usdc_fees, U256::from(5_000));
+        nft.withdraw_token_fees(usdc, context.admin(), usdc_fees).expect("Token fee withdrawal failed");
+        assert_eq!(nft.collected_token_fees(usdc), U256::from(0));
+    }
+
+    #[test]
+    fn test_native_claim_keeps_global_minimum() {
+        let mut context = TestContext::new();
+        let mut nft = setup_nft(&context.test_accounts);
+        let usdc = context.test_accounts[9];
+        
+        nft.set_min_claim_amount_for_token(usdc, U256::from(100_000)).expect("Set token minimum failed");
+        
+        // The same raw amount on an ETH project is dust against the global minimum
+        let token_id = setup_claimable_token(&mut nft, context.backer(), U256::from(1), 500_000);
+        context.set_sender(context.backer());
+        expect_error(nft.claim_revenue(token_id), "Below minimum claim amount");
+        context.set_sender(context.test_accounts[0]);
+    }
+
+    #[test]
+    fn test_token_price_pushed_to_funding() {
+        let context = TestContext::new();
+        let mut manager = OracleManager::default();
+        manager.initialize(context.test_accounts[5], context.test_accounts[6]).expect("Oracle manager initialization failed");
+        let funding = context.test_accounts[7];
+        let usdc = context.test_accounts[8];
+        let price = U256::from(1_000_000_000_000_000_000u64);
+        
+        manager.set_funding_contract(funding).expect("Set funding failed");
+        manager.set_token_price(usdc, price, U256::from(6)).expect("Set price failed");
+        
+        let mut calldata = stylus_sdk::function_selector!("syncTokenPrice", Address, U256, U256).to_vec();
+        calldata.extend_from_slice(usdc.into_word().as_slice());
+        calldata.extend_from_slice(&price.to_be_bytes::<32>());
+        calldata.extend_from_slice(&U256::from(6).to_be_bytes::<32>());
+        assert_eq!(env::recorded_calls(), vec![(funding, U256::from(0), calldata)]);
+    }
+
+    #[test]
+    fn test_token_price_update_reverts_when_funding_rejects_it() {
+        let context = TestContext::new();
+        let mut manager = OracleManager::default();
+        manager.initialize(context.test_accounts[5], context.test_accounts[6]).expect("Oracle manager initialization failed");
+        let funding = context.test_accounts[7];
+        let usdc = context.test_accounts[8];
+        
+        // Funding only accepts prices from its configured oracle manager
+        manager.set_funding_contract(funding).expect("Set funding failed");
+        let selector = stylus_sdk::function_selector!("syncTokenPrice", Address, U256, U256);
+        env::mock_call(funding, selector, Err(Vec::new()));
+        expect_error(
+            manager.set_token_price(usdc, U256::from(1000), U256::from(6)),
+            "Funding price sync failed"
+        );
+    }
+
+    fn setup_disputed_oracle(accounts: &[Address]) -> OracleManager {
+        let mut manager = OracleManager::default();
+        manager.initialize(accounts[5], accounts[6]).expect("Oracle manager initialization failed");
+        
+        // The owner doubles as the reporting oracle
+        manager.register_oracle(accounts[0], "spotify".to_string(), U256::from(3600))
+            .expect("Oracle registration failed");
+        manager.submit_revenue_data(U256::from(1), "spotify".to_string(), U256::from(5000), U256::from(0))
+            .expect("Submission failed");
+        manager
+    }
+
+    fn stake_and_dispute(context: &mut TestContext, manager: &mut OracleManager) -> U256 {
+        let oracle = context.test_accounts[0];
+        context.set_value(U256::from(1_000_000_000_000_000_000u64));
+        manager.stake_oracle().expect("Staking failed");
+        
+        let (bond, _, _, _) = manager.get_dispute_parameters();
+        context.set_sender(context.backer());
+        context.set_value(bond);
+        let dispute_id = manager.dispute_oracle_report(oracle, U256::from(1), "spotify".to_string())
+            .expect("Dispute failed");
+        
+        context.set_value(U256::from(0));
+        context.set_sender(oracle);
+        dispute_id
+    }
+
+    #[test]
+    fn test_upheld_oracle_dispute_slashes_and_rewards_disputer() {
+        let mut context = TestContext::new();
+        let mut manager = setup_disputed_oracle(&context.test_accounts);
+        let oracle = context.test_accounts[0];
+        
+        let dispute_id = stake_and_dispute(&mut context, &mut manager);
+        let stake = manager.get_oracle_stake(oracle);
+        assert_eq!(stake, U256::from(1_000_000_000_000_000_000u64));
+        manager.resolve_oracle_dispute(dispute_id, true).expect("Resolve failed");
+        
+        let slashed = stake / U256::from(2);
+        let reward = slashed / U256::from(5);
+        assert_eq!(manager.get_oracle_stake(oracle), stake - slashed);
+        assert_eq!(manager.get_forfeited_funds(), slashed - reward);
+        
+        let dispute = manager.get_oracle_dispute(dispute_id).expect("Dispute missing");
+        assert!(dispute.resolved && dispute.upheld);
+        expect_error(manager.resolve_oracle_dispute(dispute_id, true), "Dispute already resolved");
+        expect_error(manager.resolve_oracle_dispute(U256::from(99), true), "Dispute not found");
+    }
+
+    #[test]
+    fn test_rejected_oracle_dispute_forfeits_bond() {
+        let mut context = TestContext::new();
+        let mut manager = setup_disputed_oracle(&context.test_accounts);
+        let oracle = context.test_accounts[0];
+        
+        let dispute_id = stake_and_dispute(&mut context, &mut manager);
+        let stake = manager.get_oracle_stake(oracle);
+        let bond = manager.get_oracle_dispute(dispute_id).expect("Dispute missing").bond;
+        assert_eq!(bond, U256::from(10u64.pow(16)));
+        manager.resolve_oracle_dispute(dispute_id, false).expect("Resolve failed");
+        
+        assert_eq!(manager.get_oracle_stake(oracle), stake);
+        assert_eq!(manager.get_forfeited_funds(), bond);
+        assert!(!manager.get_oracle_dispute(dispute_id).expect("Dispute missing").upheld);
+        
+        // Disputes need a live submission and the posted bond
+        expect_error(
+            manager.dispute_oracle_report(context.test_accounts[1], U256::from(1), "spotify".to_string()),
+            "Insufficient dispute bond"
+        );
+        manager.set_dispute_parameters(U256::from(0), U256::from(2), U256::from(5000), U256::from(2000))
+            .expect("Set parameters failed");
+        expect_error(
+            manager.dispute_oracle_report(context.test_accounts[1], U256::from(1), "spotify".to_string()),
+            "Oracle report not found"
+        );
+        expect_error(
+            manager.set_dispute_parameters(U256::from(0), U256::from(0), U256::from(5000), U256::from(2000)),
+            "Quorum must be positive"
+        );
+    }
+
+    #[test]
+    fn test_report_can_only_be_disputed_once() {
+        let mut context = TestContext::new();
+        let mut manager = setup_disputed_oracle(&context.test_accounts);
+        let oracle = context.test_accounts[0];
+        let dispute_id = stake_and_dispute(&mut context, &mut manager);
+        manager.resolve_oracle_dispute(dispute_id, true).expect("Resolve failed");
+        
+        let (bond, _, _, _) = manager.get_dispute_parameters();
+        context.set_sender(context.test_accounts[9]);
+        context.set_value(bond);
+        expect_error(
+            manager.dispute_oracle_report(oracle, U256::from(1), "spotify".to_string()),
+            "Report already disputed"
+        );
+    }
+
+    #[test]
+    fn test_oracle_stake_withdrawn_after_unbonding() {
+        let mut context = TestContext::new();
+        let mut manager = setup_disputed_oracle(&context.test_accounts);
+        let oracle = context.test_accounts[0];
+        let dispute_id = stake_and_dispute(&mut context, &mut manager);
+        let stake = manager.get_oracle_stake(oracle);
+        
+        expect_error(manager.withdraw_stake(), "No withdrawal requested");
+        expect_error(manager.request_stake_withdrawal(stake + U256::from(1)), "Insufficient stake");
+        let available_at = manager.request_stake_withdrawal(stake).expect("Withdrawal request failed");
+        assert_eq!(available_at, U256::from(context.current_timestamp + 7 * 86400));
+        expect_error(manager.withdraw_stake(), "Stake still unbonding");
+        
+        // Still slashable while unbonding, and locked while a dispute is open
+        context.advance_time(7 * 86400);
+        expect_error(manager.withdraw_stake(), "Oracle has open disputes");
+        manager.resolve_oracle_dispute(dispute_id, true).expect("Resolve failed");
+        
+        let withdrawn = manager.withdraw_stake().expect("Withdrawal failed");
+        assert_eq!(withdrawn, stake - stake / U256::from(2));
+        assert_eq!(manager.get_oracle_stake(oracle), U256::from(0));
+        assert_eq!(manager.get_stake_unbonding(oracle), (U256::from(0), U256::from(0)));
+    }
+
+    #[test]
+    fn test_unresolved_oracle_dispute_expires_and_unlocks_stake() {
+        let mut context = TestContext::new();
+        let mut manager = setup_disputed_oracle(&context.test_accounts);
+        let oracle = context.test_accounts[0];
+        let dispute_id = stake_and_dispute(&mut context, &mut manager);
+        let stake = manager.get_oracle_stake(oracle);
+        let bond = manager.get_oracle_dispute(dispute_id).expect("Dispute missing").bond;
+        manager.request_stake_withdrawal(stake).expect("Withdrawal request failed");
+        
+        // Nobody confirms or rules on the dispute
+        context.advance_time(47 * 3600);
+        expect_error(manager.expire_oracle_dispute(dispute_id), "Dispute still open");
+        context.advance_time(3600);
+        
+        // Anyone may expire it; the bond is forfeited like a rejected dispute
+        context.set_sender(context.admin());
+        manager.expire_oracle_dispute(dispute_id).expect("Expiry failed");
+        let dispute = manager.get_oracle_dispute(dispute_id).expect("Dispute missing");
+        assert!(dispute.resolved);
+        assert!(!dispute.upheld);
+        assert_eq!(manager.get_forfeited_funds(), bond);
+        assert_eq!(manager.get_oracle_stake(oracle), stake);
+        expect_error(manager.confirm_oracle_dispute(dispute_id), "Dispute already resolved");
+        
+        context.set_sender(oracle);
+        context.advance_time(7 * 86400);
+        assert_eq!(manager.withdraw_stake().expect("Withdrawal failed"), stake);
+    }
+
+    #[test]
+    fn test_forfeited_funds_withdrawable_by_owner() {
+        let mut context = TestContext::new();
+        let mut manager = setup_disputed_oracle(&context.test_accounts);
+        let dispute_id = stake_and_dispute(&mut context, &mut manager);
+        manager.resolve_oracle_dispute(dispute_id, false).expect("Resolve failed");
+        let forfeited = manager.get_forfeited_funds();
+        
+        expect_error(
+            manager.withdraw_forfeited_funds(context.admin(), forfeited + U256::from(1)),
+            "Insufficient forfeited funds"
+        );
+        context.set_sender(context.backer());
+        expect_error(manager.withdraw_forfeited_funds(context.backer(), forfeited), "Only owner");
+        
+        context.set_sender(context.test_accounts[0]);
+        manager.withdraw_forfeited_funds(context.admin(), forfeited).expect("Withdrawal failed");
+        assert_eq!(manager.get_forfeited_funds(), U256::from(0));
+    }
+
+    #[test]
+    fn test_creator_claims_allowed_while_paused() {
+        let mut context = TestContext::new();
+        let payout_address = context.test_accounts[9];
+        context.set_rejects_value(payout_address, true);
+        let (mut distributor, project_id) = setup_distributed_revenue(&mut context, payout_address);
+        
+        distributor.pause().expect("Pause failed");
+        assert!(distributor.claims_allowed_while_paused());
+        expect_error(distributor.distribute_revenue_batch(vec![project_id]), "Contract is paused");
+        
+        context.set_sender(context.creator());
+        let claimed = distributor.claim_creator_revenue(project_id).expect("Claim during pause failed");
+        assert!(claimed > U256::from(0));
+    }
+
+    #[test]
+    fn test_creator_claims_blocked_while_paused_when_toggle_off() {
+        let mut context = TestContext::new();
+        let payout_address = context.test_accounts[9];
+        context.set_rejects_value(payout_address, true);
+        let (mut distributor, project_id) = setup_distributed_revenue(&mut context, payout_address);
+        
+        distributor.set_claims_allowed_while_paused(false).expect("Toggle failed");
+        distributor.pause().expect("Pause failed");
+        context.set_sender(context.creator());
+        expect_error(distributor.claim_creator_revenue(project_id), "Claims are paused");
+        
+        context.set_sender(context.test_accounts[0]);
+        distributor.unpause().expect("Unpause failed");
+        context.set_sender(context.creator());
+        distributor.claim_creator_revenue(project_id).expect("Claim after unpause failed");
+    }
+
+    #[test]
+    fn test_distribution_dust_goes_to_dust_recipient() {
+        let context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        let project_id = U256::from(1);
+        let dust_recipient = context.test_accounts[9];
+        
+        distributor.set_dust_recipient(dust_recipient).expect("Set dust recipient failed");
+        // Auto-payout to the NFT contract fails, so the creator share stays pending and visible
+        distributor.set_project_creator(project_id, context.test_accounts[0]).expect("Set creator failed");
+        distributor.set_auto_payout(true, context.test_accounts[6]).expect("Enable auto-payout failed");
+        
+        // 3% fee, 30% creator and 67% community of 1 ETH + 1 wei each leave a fraction behind
+        let amount = U256::from(1_000_000_000_000_000_001u64);
+        distributor.add_revenue_source(
+            project_id, "soundcloud".to_string(), amount, "ipfs://proof".to_string()
+        ).expect("Adding revenue failed");
+        distributor.distribute_revenue(project_id).expect("Distribution failed");
+        
+        // The creator share is unaffected by the extra wei; it lands in the dust pool instead
+        assert_eq!(distributor.get_pending_creator_payout(project_id), U256::from(300_000_000_000_000_000u64));
+        assert_eq!(distributor.get_accumulated_dust(), (dust_recipient, U256::from(1)));
+    }
+
+    #[test]
+    fn test_dust_recipient_defaults_to_owner() {
+        let context = TestContext::new();
+        let mut distributor = setup_distributor(&context.test_accounts);
+        
+        assert_eq!(distributor.get_accumulated_dust(), (context.test_accounts[0], U256::from(0)));
+        expect_error(distributor.sweep_dust(), "No dust to sweep");
+    }
+}
@@ -241,6 +241,73 @@ mod security_tests {
         );
     }
 
+    #[test]
+    fn test_funding_contract_can_update_project_funding() {
+        let mut context = TestContext::new();
+        
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        let funding_contract = Address::from([21u8; 20]);
+        context.platform.set_contract_addresses(
+            funding_contract,
+            Address::ZERO,
+            Address::ZERO,
+            Address::ZERO,
+        ).expect("Set contract addresses failed");
+        
+        context.set_sender(funding_contract);
+        context.platform.update_project_funding(project_id, U256::from(5000))
+            .expect("Funding contract update failed");
+        context.set_sender(context.test_accounts[0]);
+        
+        let project = context.platform.get_project_info(project_id).unwrap();
+        assert_eq!(project.funding_raised, U256::from(5000));
+    }
+
+    #[test]
+    fn test_validator_contract_can_set_project_validation() {
+        let mut context = TestContext::new();
+        
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        let validator_contract = context.validator();
+        context.platform.set_contract_addresses(
+            Address::ZERO,
+            Address::ZERO,
+            validator_contract,
+            Address::ZERO,
+        ).expect("Set contract addresses failed");
+        
+        context.set_sender(validator_contract);
+        context.platform.set_project_validation(project_id, U256::from(80), true)
+            .expect("Validator contract validation failed");
+        context.set_sender(context.test_accounts[0]);
+        
+        let project = context.platform.get_project_info(project_id).unwrap();
+        assert_eq!(project.validation_status, 1);
+    }
+
+    #[test]
+    fn test_admin_can_set_project_validation() {
+        let mut context = TestContext::new();
+        
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        let admin = context.admin();
+        context.platform.add_admin(admin).expect("Add admin failed");
+        
+        context.set_sender(admin);
+        context.platform.set_project_validation(project_id, U256::from(75), true)
+            .expect("Admin validation failed");
+        context.set_sender(context.test_accounts[0]);
+        
+        let project = context.platform.get_project_info(project_id).unwrap();
+        assert_eq!(project.validation_score, U256::from(75));
+    }
+
     #[test]
     fn test_cultural_category_validation() {
         let mut context = TestContext::new();
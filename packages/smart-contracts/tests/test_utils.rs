@@ -1,5 +1,5 @@
 use alloy_primitives::{Address, U256, FixedBytes};
-use afrocreate_contracts::{AfroCreatePlatform, types::*};
+use afrocreate_contracts::{AfroCreatePlatform, types::{env, *}};
 use std::collections::HashMap;
 
 pub struct TestContext {
@@ -11,9 +11,17 @@ pub struct TestContext {
 
 impl TestContext {
     pub fn new() -> Self {
-        let mut platform = AfroCreatePlatform::default();
         let test_accounts = generate_test_accounts(10);
         let ens_registry = test_accounts[0];
+        let current_timestamp = 1625097600; // July 1, 2021
+        
+        // Every contract reads msg::sender, msg::value, the block time and its external calls
+        // through env, so start from a clean slate; the deployer sends calls by default
+        env::reset();
+        env::set_sender(test_accounts[0]);
+        env::set_timestamp(current_timestamp);
+        
+        let mut platform = AfroCreatePlatform::default();
         
         // Initialize platform
         platform.initialize(
@@ -26,7 +34,7 @@ impl TestContext {
             platform,
             test_accounts,
             ens_registry,
-            current_timestamp: 1625097600, // July 1, 2021
+            current_timestamp,
         }
     }
     
@@ -48,6 +56,20 @@ impl TestContext {
     
     pub fn advance_time(&mut self, seconds: u64) {
         self.current_timestamp += seconds;
+        env::set_timestamp(self.current_timestamp);
+    }
+    
+    pub fn set_sender(&mut self, sender: Address) {
+        env::set_sender(sender);
+    }
+    
+    pub fn set_value(&mut self, value: U256) {
+        env::set_value(value);
+    }
+    
+    // Makes ETH transfers to `account` revert, like a contract without a payable receive
+    pub fn set_rejects_value(&mut self, account: Address, rejects: bool) {
+        env::set_rejects_value(account, rejects);
     }
     
     pub fn register_test_creator(&mut self) -> Result<U256, String> {
@@ -214,5 +236,6 @@ pub fn create_mock_project_info(project_id: U256, creator: Address) -> ProjectIn
         validation_status: 0, // Pending
         validation_score: U256::from(0),
         metadata_uri: "QmMockHash".to_string(),
+        regions: Vec::new(),
     }
 }
\ No newline at end of file
@@ -460,4 +460,76 @@ mod validation_tests {
             assert_eq!(project.validation_status, expected_status);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_batch_validation_updates_multiple_projects() {
+        let mut context = TestContext::new();
+        
+        context.register_test_creator().expect("Creator registration failed");
+        let first_project = context.create_test_project().expect("Project creation failed");
+        let second_project = context.create_test_project().expect("Project creation failed");
+        
+        let validator_contract = context.validator();
+        context.platform.set_contract_addresses(
+            Address::ZERO,
+            Address::ZERO,
+            validator_contract,
+            Address::ZERO,
+        ).expect("Set contract addresses failed");
+        context.set_sender(validator_contract);
+        
+        let updated = context.platform.set_project_validations_batch(vec![
+            (first_project, U256::from(88), true),
+            (second_project, U256::from(40), false),
+        ]).expect("Batch validation failed");
+        assert_eq!(updated, U256::from(2));
+        
+        let first = context.platform.get_project_info(first_project).unwrap();
+        assert_eq!(first.validation_status, 1);
+        assert_eq!(first.validation_score, U256::from(88));
+        
+        let second = context.platform.get_project_info(second_project).unwrap();
+        assert_eq!(second.validation_status, 2);
+        assert_eq!(second.validation_score, U256::from(40));
+    }
+
+    #[test]
+    fn test_batch_validation_skips_nonexistent_project() {
+        let mut context = TestContext::new();
+        
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        let validator_contract = context.validator();
+        context.platform.set_contract_addresses(
+            Address::ZERO,
+            Address::ZERO,
+            validator_contract,
+            Address::ZERO,
+        ).expect("Set contract addresses failed");
+        context.set_sender(validator_contract);
+        
+        let updated = context.platform.set_project_validations_batch(vec![
+            (U256::from(999), U256::from(75), true),
+            (project_id, U256::from(82), true),
+        ]).expect("Batch should not revert on unknown project");
+        assert_eq!(updated, U256::from(1));
+        
+        let project = context.platform.get_project_info(project_id).unwrap();
+        assert_eq!(project.validation_status, 1);
+        assert_eq!(project.validation_score, U256::from(82));
+    }
+
+    #[test]
+    fn test_batch_validation_requires_validator_contract() {
+        let mut context = TestContext::new();
+        
+        context.register_test_creator().expect("Creator registration failed");
+        let project_id = context.create_test_project().expect("Project creation failed");
+        
+        expect_error(
+            context.platform.set_project_validations_batch(vec![(project_id, U256::from(80), true)]),
+            "Only validator contract"
+        );
+    }
+}